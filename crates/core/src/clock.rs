@@ -1,4 +1,4 @@
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, FixedOffset, Local, NaiveDate, Offset, Utc};
 
 use crate::errors::PPMResult;
 
@@ -8,6 +8,16 @@ use crate::errors::PPMResult;
 /// This allows tests to use FixedClock for deterministic behavior.
 pub trait Clock: Send + Sync {
 	fn now(&self) -> PPMResult<DateTime<Utc>>;
+
+	/// Offset of the user's time zone at `time`, for features that follow the local date.
+	fn local_offset(&self, time: DateTime<Utc>) -> FixedOffset {
+		time.with_timezone(&Local).offset().fix()
+	}
+
+	/// Date of `time` in the user's time zone; what "today" means for `now`.
+	fn local_date(&self, time: DateTime<Utc>) -> NaiveDate {
+		time.with_timezone(&self.local_offset(time)).date_naive()
+	}
 }
 
 // --------------------------------------------------------------------------------
@@ -42,17 +52,27 @@ impl Clock for SystemClock {
 /// Fixed clock for testing - allows controlled time progression.
 ///
 /// Note: Not marked with #[cfg(test)] so it's accessible in integration tests.
+/// Its local time zone is UTC unless set with [`FixedClock::with_offset`], so results never
+/// depend on the host's time zone.
 pub struct FixedClock {
 	time: std::sync::Mutex<DateTime<Utc>>,
+	offset: FixedOffset,
 }
 
 impl FixedClock {
 	pub fn new(time: DateTime<Utc>) -> Self {
 		Self {
 			time: std::sync::Mutex::new(time),
+			offset: Utc.fix(),
 		}
 	}
 
+	/// Use `offset` as the local time zone
+	pub fn with_offset(mut self, offset: FixedOffset) -> Self {
+		self.offset = offset;
+		self
+	}
+
 	/// Advance the clock by the specified duration
 	pub fn advance(&self, duration: chrono::Duration) -> PPMResult<()> {
 		let mut time = self.time.lock()?;
@@ -72,4 +92,8 @@ impl Clock for FixedClock {
 	fn now(&self) -> PPMResult<DateTime<Utc>> {
 		Ok(*self.time.lock()?)
 	}
+
+	fn local_offset(&self, _time: DateTime<Utc>) -> FixedOffset {
+		self.offset
+	}
 }
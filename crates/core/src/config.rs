@@ -1,14 +1,55 @@
+use std::fs;
+use std::path::PathBuf;
+
 use serde::{Deserialize, Serialize};
 
 use crate::errors::{PPMError, PPMResult};
+use crate::models::Workflow;
 
+/// User configuration.
+///
+/// Loaded from `~/.config/ppm/config.json`. Every field is optional in the file; missing
+/// fields take their default value.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct Config {
 	pub default_focus_duration_in_minutes: u32,
 	pub session_storage_path: String,
 	pub task_storage_path: String,
 	pub notes_dir: String,
 	pub projects_storage_path: String,
+	pub trash_storage_path: String,
+	/// Directory of note templates, one `{name}.md` file each
+	pub templates_dir: String,
+	/// Days a deleted item stays in the trash before it is purged. 0 keeps items forever.
+	pub trash_retention_days: u32,
+	pub workflow: Workflow,
+	pub project_references: ReferenceMode,
+	pub note_filenames: NoteFilenames,
+}
+
+/// How tasks, sessions and notes referring to an unknown project are handled.
+///
+/// In both modes the user is first offered to create the missing project.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ReferenceMode {
+	/// Refuse the reference.
+	Strict,
+	/// Accept the reference with a warning.
+	#[default]
+	Lenient,
+}
+
+/// How new note files are named inside `notes_dir`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum NoteFilenames {
+	/// `{note_id}.md`
+	#[default]
+	Id,
+	/// `YYYY-MM-DD-slug.md`, with the slug taken from the title or first line.
+	Slug,
 }
 
 impl Config {
@@ -17,7 +58,15 @@ impl Config {
 	}
 
 	pub fn load() -> PPMResult<Self> {
-		Ok(Self::default())
+		let path = Self::config_path();
+		if !path.exists() {
+			return Ok(Self::default());
+		}
+
+		let content = fs::read_to_string(&path)?;
+		serde_json::from_str(&content).map_err(|e| {
+			PPMError::ConfigError(format!("Failed to parse {}: {}", path.display(), e))
+		})
 	}
 
 	pub fn validate(&self) -> PPMResult<()> {
@@ -26,7 +75,17 @@ impl Config {
 				"default_focus_duration must be greater than 0".to_string(),
 			));
 		}
-		Ok(())
+		self.workflow.validate()
+	}
+
+	/// Directory holding the config file and, by default, all data files.
+	pub fn config_dir() -> PathBuf {
+		let home = std::env::var("HOME").unwrap_or_else(|_| String::from("."));
+		PathBuf::from(home).join(".config/ppm")
+	}
+
+	pub fn config_path() -> PathBuf {
+		Self::config_dir().join("config.json")
 	}
 }
 
@@ -37,6 +96,8 @@ impl Default for Config {
 		let task_storage_path = format!("{}/.config/ppm/tasks.json", home);
 		let notes_dir = format!("{}/.config/ppm/notes", home);
 		let projects_storage_path = format!("{}/.config/ppm/projects.json", home);
+		let trash_storage_path = format!("{}/.config/ppm/trash.json", home);
+		let templates_dir = format!("{}/.config/ppm/templates", home);
 
 		Self {
 			default_focus_duration_in_minutes: 60,
@@ -44,6 +105,12 @@ impl Default for Config {
 			task_storage_path,
 			notes_dir,
 			projects_storage_path,
+			trash_storage_path,
+			templates_dir,
+			trash_retention_days: 30,
+			workflow: Workflow::default(),
+			project_references: ReferenceMode::default(),
+			note_filenames: NoteFilenames::default(),
 		}
 	}
 }
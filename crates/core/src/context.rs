@@ -4,11 +4,15 @@ use std::sync::Arc;
 use crate::clock::{Clock, SystemClock};
 use crate::config::Config;
 use crate::editor::{Editor, SystemEditor};
+use crate::input::{InputReader, StdinReader};
 use crate::output::{OutputWriter, stdout_writer};
+use crate::prompt::{Prompt, TerminalPrompt};
 use crate::repositories::note::{LocalNoteRepository, NoteRepository};
 use crate::repositories::project::{LocalProjectRepository, ProjectRepository};
 use crate::repositories::session::{LocalSessionRepository, SessionRepository};
 use crate::repositories::task::{LocalTaskRepository, TaskRepository};
+use crate::repositories::template::{LocalTemplateRepository, TemplateRepository};
+use crate::repositories::trash::{LocalTrashRepository, TrashRepository};
 
 /// Dependency injection container.
 ///
@@ -22,27 +26,41 @@ pub struct PPMContext {
 	pub task_repository: Arc<dyn TaskRepository>,
 	pub note_repository: Arc<dyn NoteRepository>,
 	pub project_repository: Arc<dyn ProjectRepository>,
+	pub trash_repository: Arc<dyn TrashRepository>,
+	pub template_repository: Arc<dyn TemplateRepository>,
 	pub output_writer: Arc<dyn OutputWriter>,
 	pub editor: Arc<dyn Editor>,
+	pub prompt: Arc<dyn Prompt>,
+	pub input_reader: Arc<dyn InputReader>,
 }
 
 impl PPMContext {
 	pub fn new(config: Config) -> Self {
 		let clock = Arc::new(SystemClock::new());
+		let output_writer: Arc<dyn OutputWriter> = Arc::new(stdout_writer());
 		let session_storage_path = PathBuf::from(&config.session_storage_path);
 		let task_storage_path = PathBuf::from(&config.task_storage_path);
 		let notes_dir = PathBuf::from(&config.notes_dir);
+		let note_filenames = config.note_filenames;
 		let projects_storage_path = PathBuf::from(&config.projects_storage_path);
+		let trash_storage_path = PathBuf::from(&config.trash_storage_path);
+		let templates_dir = PathBuf::from(&config.templates_dir);
 
 		Self {
 			config,
 			clock: clock.clone(),
 			session_repository: Arc::new(LocalSessionRepository::new(session_storage_path)),
 			task_repository: Arc::new(LocalTaskRepository::new(task_storage_path)),
-			note_repository: Arc::new(LocalNoteRepository::new(notes_dir)),
+			note_repository: Arc::new(
+				LocalNoteRepository::new(notes_dir).with_filenames(note_filenames, clock.clone()),
+			),
 			project_repository: Arc::new(LocalProjectRepository::new(projects_storage_path)),
-			output_writer: Arc::new(stdout_writer()),
+			trash_repository: Arc::new(LocalTrashRepository::new(trash_storage_path)),
+			template_repository: Arc::new(LocalTemplateRepository::new(templates_dir)),
+			output_writer: output_writer.clone(),
 			editor: Arc::new(SystemEditor::new()),
+			prompt: Arc::new(TerminalPrompt::new(output_writer)),
+			input_reader: Arc::new(StdinReader::new()),
 		}
 	}
 
@@ -63,8 +81,12 @@ pub struct PPMContextBuilder {
 	task_repository: Option<Arc<dyn TaskRepository>>,
 	note_repository: Option<Arc<dyn NoteRepository>>,
 	project_repository: Option<Arc<dyn ProjectRepository>>,
+	trash_repository: Option<Arc<dyn TrashRepository>>,
+	template_repository: Option<Arc<dyn TemplateRepository>>,
 	output_writer: Option<Arc<dyn OutputWriter>>,
 	editor: Option<Arc<dyn Editor>>,
+	prompt: Option<Arc<dyn Prompt>>,
+	input_reader: Option<Arc<dyn InputReader>>,
 }
 
 impl PPMContextBuilder {
@@ -98,6 +120,16 @@ impl PPMContextBuilder {
 		self
 	}
 
+	pub fn trash_repository(mut self, repository: Arc<dyn TrashRepository>) -> Self {
+		self.trash_repository = Some(repository);
+		self
+	}
+
+	pub fn template_repository(mut self, repository: Arc<dyn TemplateRepository>) -> Self {
+		self.template_repository = Some(repository);
+		self
+	}
+
 	pub fn output_writer(mut self, writer: Arc<dyn OutputWriter>) -> Self {
 		self.output_writer = Some(writer);
 		self
@@ -108,13 +140,27 @@ impl PPMContextBuilder {
 		self
 	}
 
+	pub fn prompt(mut self, prompt: Arc<dyn Prompt>) -> Self {
+		self.prompt = Some(prompt);
+		self
+	}
+
+	pub fn input_reader(mut self, input_reader: Arc<dyn InputReader>) -> Self {
+		self.input_reader = Some(input_reader);
+		self
+	}
+
 	pub fn build(self) -> PPMContext {
 		let config = self.config.unwrap_or_default();
 		let clock = self.clock.unwrap_or_else(|| Arc::new(SystemClock::new()));
+		let output_writer = self.output_writer.unwrap_or_else(|| Arc::new(stdout_writer()));
 		let session_storage_path = PathBuf::from(&config.session_storage_path);
 		let task_storage_path = PathBuf::from(&config.task_storage_path);
 		let notes_dir = PathBuf::from(&config.notes_dir);
+		let note_filenames = config.note_filenames;
 		let projects_storage_path = PathBuf::from(&config.projects_storage_path);
+		let trash_storage_path = PathBuf::from(&config.trash_storage_path);
+		let templates_dir = PathBuf::from(&config.templates_dir);
 
 		PPMContext {
 			config,
@@ -125,14 +171,25 @@ impl PPMContextBuilder {
 			task_repository: self
 				.task_repository
 				.unwrap_or_else(|| Arc::new(LocalTaskRepository::new(task_storage_path))),
-			note_repository: self
-				.note_repository
-				.unwrap_or_else(|| Arc::new(LocalNoteRepository::new(notes_dir))),
+			note_repository: self.note_repository.unwrap_or_else(|| {
+				Arc::new(
+					LocalNoteRepository::new(notes_dir)
+						.with_filenames(note_filenames, clock.clone()),
+				)
+			}),
 			project_repository: self
 				.project_repository
 				.unwrap_or_else(|| Arc::new(LocalProjectRepository::new(projects_storage_path))),
-			output_writer: self.output_writer.unwrap_or_else(|| Arc::new(stdout_writer())),
+			trash_repository: self
+				.trash_repository
+				.unwrap_or_else(|| Arc::new(LocalTrashRepository::new(trash_storage_path))),
+			template_repository: self
+				.template_repository
+				.unwrap_or_else(|| Arc::new(LocalTemplateRepository::new(templates_dir))),
+			output_writer: output_writer.clone(),
 			editor: self.editor.unwrap_or_else(|| Arc::new(SystemEditor::new())),
+			prompt: self.prompt.unwrap_or_else(|| Arc::new(TerminalPrompt::new(output_writer))),
+			input_reader: self.input_reader.unwrap_or_else(|| Arc::new(StdinReader::new())),
 		}
 	}
 }
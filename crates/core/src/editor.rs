@@ -1,4 +1,5 @@
 use std::process::Command;
+use std::sync::Mutex;
 use std::{env, fs};
 
 use crate::errors::{PPMError, PPMResult};
@@ -81,3 +82,51 @@ impl Default for SystemEditor {
 		Self::new()
 	}
 }
+
+// --------------------------------------------------------------------------------
+// Test Utilities
+// --------------------------------------------------------------------------------
+
+/// Editor that returns predetermined content and records what it was opened with.
+pub struct FixedEditor {
+	/// `None` returns the initial content unchanged, as if the user saved right away
+	response: Option<String>,
+	opened: Mutex<Vec<String>>,
+}
+
+impl FixedEditor {
+	/// Editor whose user replaces everything with `content`
+	pub fn returning(content: impl Into<String>) -> Self {
+		Self {
+			response: Some(content.into()),
+			opened: Mutex::new(Vec::new()),
+		}
+	}
+
+	/// Editor whose user saves the initial content as is
+	pub fn unchanged() -> Self {
+		Self {
+			response: None,
+			opened: Mutex::new(Vec::new()),
+		}
+	}
+
+	/// Initial contents of every call to [`Editor::open`] so far
+	pub fn opened(&self) -> PPMResult<Vec<String>> {
+		Ok(self.opened.lock()?.clone())
+	}
+}
+
+impl Editor for FixedEditor {
+	fn open(&self, initial_content: Option<&str>) -> PPMResult<Option<String>> {
+		let initial = initial_content.unwrap_or_default().to_string();
+		self.opened.lock()?.push(initial.clone());
+
+		let content = self.response.clone().unwrap_or(initial);
+		if content.trim().is_empty() {
+			Ok(None)
+		} else {
+			Ok(Some(content.trim().to_string()))
+		}
+	}
+}
@@ -20,6 +20,21 @@ pub enum PPMError {
 	#[error("{0}")]
 	AlreadyExists(String),
 
+	#[error("'{0}' is ambiguous, it matches: {candidates}", candidates = .1.join(", "))]
+	AmbiguousId(String, Vec<String>),
+
+	#[error("{0} of {1} item(s) failed")]
+	BulkOperationFailed(usize, usize),
+
+	#[error("Project '{0}' is archived, activate it first")]
+	ProjectArchived(String),
+
+	#[error("{0} was not confirmed")]
+	NotConfirmed(String),
+
+	#[error("Invalid input: {0}")]
+	InvalidInput(String),
+
 	#[error("Configuration error: {0}")]
 	ConfigError(String),
 
@@ -0,0 +1,57 @@
+use std::io::{self, Read};
+
+use crate::errors::PPMResult;
+
+/// Abstraction for reading piped input.
+///
+/// Enables testing by allowing implementations that return predetermined content.
+pub trait InputReader: Send + Sync {
+	/// Reads the whole input.
+	fn read_all(&self) -> PPMResult<String>;
+}
+
+// --------------------------------------------------------------------------------
+// Concrete Implementations
+// --------------------------------------------------------------------------------
+
+/// Input reader that consumes stdin until end of file.
+pub struct StdinReader;
+
+impl StdinReader {
+	pub fn new() -> Self {
+		Self
+	}
+}
+
+impl InputReader for StdinReader {
+	fn read_all(&self) -> PPMResult<String> {
+		let mut input = String::new();
+		io::stdin().read_to_string(&mut input)?;
+		Ok(input)
+	}
+}
+
+impl Default for StdinReader {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+/// Input reader that always returns the same content, for tests and scripts.
+pub struct FixedInput {
+	content: String,
+}
+
+impl FixedInput {
+	pub fn new(content: impl Into<String>) -> Self {
+		Self {
+			content: content.into(),
+		}
+	}
+}
+
+impl InputReader for FixedInput {
+	fn read_all(&self) -> PPMResult<String> {
+		Ok(self.content.clone())
+	}
+}
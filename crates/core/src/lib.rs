@@ -3,7 +3,13 @@ pub mod config;
 pub mod context;
 pub mod editor;
 pub mod errors;
+pub mod input;
 pub mod models;
 pub mod output;
+pub mod prompt;
+pub mod query;
 pub mod repositories;
 pub mod services;
+#[cfg(test)]
+mod test_support;
+pub mod yaml;
@@ -0,0 +1,121 @@
+use std::fmt;
+use std::str::FromStr;
+
+use model_macros::model;
+
+use crate::errors::PPMError;
+
+/// Estimated effort for a task, stored as whole minutes.
+///
+/// Textual form (used by the CLI): `2h`, `45m`, `1h30m`, or a bare number of minutes. A zero
+/// estimate is rejected in every form.
+#[model]
+#[derive(Copy, PartialEq, Eq)]
+#[serde(transparent)]
+pub struct Estimate {
+	minutes: u32,
+}
+
+impl Estimate {
+	pub fn from_minutes(minutes: u32) -> Self {
+		Self {
+			minutes,
+		}
+	}
+
+	pub fn minutes(&self) -> u32 {
+		self.minutes
+	}
+}
+
+impl fmt::Display for Estimate {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let hours = self.minutes / 60;
+		let minutes = self.minutes % 60;
+
+		match (hours, minutes) {
+			(0, m) => write!(f, "{}m", m),
+			(h, 0) => write!(f, "{}h", h),
+			(h, m) => write!(f, "{}h{}m", h, m),
+		}
+	}
+}
+
+impl FromStr for Estimate {
+	type Err = PPMError;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let invalid = || {
+			PPMError::InvalidInput(format!(
+				"Invalid estimate '{}' (expected e.g. 2h, 45m, 1h30m or minutes)",
+				s
+			))
+		};
+
+		let input = s.trim().to_lowercase();
+		if let Ok(minutes) = input.parse::<u32>() {
+			return if minutes == 0 {
+				Err(invalid())
+			} else {
+				Ok(Self::from_minutes(minutes))
+			};
+		}
+
+		let mut total: u32 = 0;
+		let mut number = String::new();
+		for c in input.chars() {
+			match c {
+				'0'..='9' => number.push(c),
+				'h' | 'm' if !number.is_empty() => {
+					let value: u32 = number.parse().map_err(|_| invalid())?;
+					let factor = if c == 'h' {
+						60
+					} else {
+						1
+					};
+					total = value
+						.checked_mul(factor)
+						.and_then(|v| total.checked_add(v))
+						.ok_or_else(invalid)?;
+					number.clear();
+				}
+				_ => return Err(invalid()),
+			}
+		}
+
+		if !number.is_empty() || total == 0 {
+			return Err(invalid());
+		}
+
+		Ok(Self::from_minutes(total))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn parses_hours_and_minutes() {
+		for (text, minutes) in [("2h", 120), ("45m", 45), ("1h30m", 90), ("90", 90), (" 1H5M ", 65)]
+		{
+			assert_eq!(text.parse::<Estimate>().unwrap().minutes(), minutes, "{}", text);
+		}
+		for text in ["", "0", "00", "0m", "0h0m", "h", "1x", "1h30", "2h-5m", "99999999999h"] {
+			assert!(text.parse::<Estimate>().is_err(), "{}", text);
+		}
+	}
+
+	#[test]
+	fn displays_in_the_shortest_form() {
+		assert_eq!(Estimate::from_minutes(45).to_string(), "45m");
+		assert_eq!(Estimate::from_minutes(120).to_string(), "2h");
+		assert_eq!(Estimate::from_minutes(90).to_string(), "1h30m");
+
+		// Every displayed estimate parses back to itself
+		for minutes in [1, 45, 60, 90, 600] {
+			let estimate = Estimate::from_minutes(minutes);
+			assert_eq!(estimate.to_string().parse::<Estimate>().unwrap(), estimate);
+		}
+	}
+}
@@ -0,0 +1,322 @@
+use chrono::{DateTime, NaiveDate, Utc};
+
+use crate::errors::{PPMError, PPMResult};
+use crate::models::{FocusSessionId, Note, NoteId, ProjectName, TaskId};
+use crate::yaml::{self, YamlEntry, YamlValue};
+
+const DELIMITER: &str = "---";
+
+/// Metadata stored at the top of a note's markdown file, between `---` lines.
+///
+/// Every field is optional so that the same type can read both stored files and what the
+/// user left behind in an editor. Keys ppm does not use, such as those added by other
+/// markdown tools, are kept verbatim in `extra` and written back after the known ones.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct NoteFrontMatter {
+	pub id: Option<NoteId>,
+	pub title: Option<String>,
+	pub project_name: Option<ProjectName>,
+	pub task_id: Option<TaskId>,
+	pub session_id: Option<FocusSessionId>,
+	pub created_at: Option<DateTime<Utc>>,
+	pub updated_at: Option<DateTime<Utc>>,
+	pub journal: Option<NaiveDate>,
+	pub extra: Vec<YamlEntry>,
+}
+
+impl NoteFrontMatter {
+	pub fn of(note: &Note) -> Self {
+		Self {
+			id: Some(note.id.clone()),
+			title: note.title.clone(),
+			project_name: note.project_name.clone(),
+			task_id: note.task_id.clone(),
+			session_id: note.session_id.clone(),
+			created_at: Some(note.created_at),
+			updated_at: note.updated_at,
+			journal: note.journal,
+			extra: note.extra_front_matter.clone(),
+		}
+	}
+
+	/// Splits a markdown document into its front matter and trimmed body.
+	///
+	/// A document whose first line is not `---` has no front matter and is all body. The
+	/// front matter ends at the next `---` (or `...`) line, so later `---` rules stay in the
+	/// body.
+	pub fn parse(document: &str) -> PPMResult<(Self, String)> {
+		let document = document.trim_start_matches('\u{feff}').trim_start();
+		let mut lines = document.lines();
+		if lines.next().map(str::trim_end) != Some(DELIMITER) {
+			return Ok((Self::default(), document.trim().to_string()));
+		}
+
+		let mut yaml = Vec::new();
+		let mut closed = false;
+		for line in lines.by_ref() {
+			if matches!(line.trim_end(), DELIMITER | "...") {
+				closed = true;
+				break;
+			}
+			yaml.push(line);
+		}
+		if !closed {
+			return Err(invalid("unterminated front matter"));
+		}
+
+		let mut front_matter = Self::default();
+		let entries = yaml::parse_mapping(&yaml.join("\n"))
+			.map_err(|e| invalid(&format!("front matter: {}", e)))?;
+		for entry in entries {
+			match entry.key.as_str() {
+				"id" => front_matter.id = scalar(&entry)?.map(NoteId::from),
+				"title" => front_matter.title = scalar(&entry)?.map(str::to_string),
+				"project" => front_matter.project_name = scalar(&entry)?.map(ProjectName::from),
+				"task" => front_matter.task_id = scalar(&entry)?.map(TaskId::from),
+				"session" => front_matter.session_id = scalar(&entry)?.map(FocusSessionId::from),
+				"created_at" => {
+					front_matter.created_at = scalar(&entry)?.map(parse_date).transpose()?
+				}
+				"updated_at" => {
+					front_matter.updated_at = scalar(&entry)?.map(parse_date).transpose()?
+				}
+				"journal" => front_matter.journal = scalar(&entry)?.map(parse_day).transpose()?,
+				_ => front_matter.extra.push(entry),
+			}
+		}
+
+		let body: Vec<&str> = lines.collect();
+		Ok((front_matter, body.join("\n").trim().to_string()))
+	}
+
+	/// Renders the front matter followed by `body`.
+	pub fn render(&self, body: &str) -> String {
+		let mut document = String::from("---\n");
+
+		if let Some(ref id) = self.id {
+			document.push_str(&format!("id: {}\n", id));
+		}
+		if let Some(ref title) = self.title {
+			document.push_str(&format!("title: {}\n", yaml::render_scalar(title)));
+		}
+		if let Some(ref created_at) = self.created_at {
+			document.push_str(&format!("created_at: {}\n", created_at.to_rfc3339()));
+		}
+		if let Some(ref updated_at) = self.updated_at {
+			document.push_str(&format!("updated_at: {}\n", updated_at.to_rfc3339()));
+		}
+		if let Some(ref journal) = self.journal {
+			document.push_str(&format!("journal: {}\n", journal));
+		}
+		if let Some(ref project) = self.project_name {
+			document.push_str(&format!("project: {}\n", yaml::render_scalar(project)));
+		}
+		if let Some(ref task_id) = self.task_id {
+			document.push_str(&format!("task: {}\n", task_id));
+		}
+		if let Some(ref session_id) = self.session_id {
+			document.push_str(&format!("session: {}\n", session_id));
+		}
+		for entry in &self.extra {
+			document.push_str(&entry.source);
+			document.push('\n');
+		}
+
+		document.push_str("---\n\n");
+		document.push_str(body);
+
+		document
+	}
+}
+
+impl Note {
+	/// Renders the note as a markdown document with front matter.
+	pub fn to_markdown(&self) -> String {
+		NoteFrontMatter::of(self).render(&self.content)
+	}
+
+	/// Text that note searches match against: the body and the values of the front matter.
+	///
+	/// Key names are left out, so that searching for e.g. `title` does not match every note,
+	/// and so are the ID and timestamps.
+	pub fn searchable_text(&self) -> String {
+		let mut parts: Vec<String> = vec![self.content.clone()];
+		parts.extend(self.title.clone());
+		parts.extend(self.project_name.as_ref().map(|p| p.to_string()));
+		parts.extend(self.task_id.as_ref().map(|t| t.to_string()));
+		parts.extend(self.session_id.as_ref().map(|s| s.to_string()));
+		for entry in &self.extra_front_matter {
+			push_values(&mut parts, &entry.value);
+		}
+		parts.join("\n")
+	}
+
+	/// Reads a note from a markdown document; `id` and `created_at` are required.
+	pub fn from_markdown(document: &str) -> PPMResult<Self> {
+		let (front_matter, content) = NoteFrontMatter::parse(document)?;
+
+		Ok(Note {
+			id: front_matter.id.ok_or_else(|| invalid("missing 'id' in front matter"))?,
+			title: front_matter.title,
+			project_name: front_matter.project_name,
+			task_id: front_matter.task_id,
+			session_id: front_matter.session_id,
+			content,
+			created_at: front_matter
+				.created_at
+				.ok_or_else(|| invalid("missing 'created_at' in front matter"))?,
+			updated_at: front_matter.updated_at,
+			journal: front_matter.journal,
+			extra_front_matter: front_matter.extra,
+		})
+	}
+}
+
+fn push_values(parts: &mut Vec<String>, value: &YamlValue) {
+	match value {
+		YamlValue::Null => {}
+		YamlValue::Scalar(scalar) => parts.push(scalar.clone()),
+		YamlValue::List(items) => parts.extend(items.iter().cloned()),
+		YamlValue::Mapping(entries) => {
+			for entry in entries {
+				push_values(parts, &entry.value);
+			}
+		}
+	}
+}
+
+/// The value of a known key, which must be a single value if present.
+fn scalar(entry: &YamlEntry) -> PPMResult<Option<&str>> {
+	match entry.value {
+		YamlValue::Null => Ok(None),
+		YamlValue::Scalar(ref value) => Ok(Some(value.as_str())),
+		_ => Err(invalid(&format!("expected a single value for '{}'", entry.key))),
+	}
+}
+
+fn parse_date(value: &str) -> PPMResult<DateTime<Utc>> {
+	DateTime::parse_from_rfc3339(value)
+		.map(|date| date.with_timezone(&Utc))
+		.map_err(|e| invalid(&format!("failed to parse date: {}", e)))
+}
+
+fn parse_day(value: &str) -> PPMResult<NaiveDate> {
+	NaiveDate::parse_from_str(value, "%Y-%m-%d")
+		.map_err(|e| invalid(&format!("failed to parse date: {}", e)))
+}
+
+fn invalid(reason: &str) -> PPMError {
+	PPMError::IoError(std::io::Error::other(format!("Invalid note format: {}", reason)))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::yaml::YamlValue;
+
+	const OBSIDIAN_NOTE: &str = "---
+id: note_01JABCDEF
+title: \"Weekly: review\"
+created_at: 2026-01-05T09:00:00+00:00
+project: web
+aliases: [review, \"weekly, notes\"]
+tags:
+  - work
+  # keep this comment
+  - planning
+summary: >
+  Folded
+  text
+\"quoted key\": value
+---
+
+# Weekly review
+
+Intro
+
+---
+
+After the rule";
+
+	#[test]
+	fn round_trips_unknown_keys_verbatim() {
+		let note = Note::from_markdown(OBSIDIAN_NOTE).unwrap();
+
+		assert_eq!(note.id, NoteId::from("note_01JABCDEF"));
+		assert_eq!(note.title.as_deref(), Some("Weekly: review"));
+		assert_eq!(note.project_name, Some(ProjectName::from("web")));
+		let keys: Vec<&str> = note.extra_front_matter.iter().map(|e| e.key.as_str()).collect();
+		assert_eq!(keys, vec!["aliases", "tags", "summary", "quoted key"]);
+		assert_eq!(
+			note.extra_front_matter[1].value,
+			YamlValue::List(vec!["work".to_string(), "planning".to_string()])
+		);
+
+		assert_eq!(note.to_markdown(), OBSIDIAN_NOTE);
+	}
+
+	#[test]
+	fn rules_in_the_body_stay_in_the_body() {
+		let (front_matter, body) = NoteFrontMatter::parse(OBSIDIAN_NOTE).unwrap();
+
+		assert_eq!(front_matter.extra.len(), 4);
+		assert!(body.starts_with("# Weekly review"));
+		assert!(body.ends_with("---\n\nAfter the rule"));
+	}
+
+	#[test]
+	fn documents_without_front_matter_are_all_body() {
+		let (front_matter, body) = NoteFrontMatter::parse("\n# Title\n\n---\ntext\n").unwrap();
+
+		assert_eq!(front_matter, NoteFrontMatter::default());
+		assert_eq!(body, "# Title\n\n---\ntext");
+	}
+
+	#[test]
+	fn accepts_dots_as_closing_delimiter() {
+		let (front_matter, body) = NoteFrontMatter::parse("---\ntitle: t\n...\nbody").unwrap();
+
+		assert_eq!(front_matter.title.as_deref(), Some("t"));
+		assert_eq!(body, "body");
+	}
+
+	#[test]
+	fn links_and_journal_dates_round_trip() {
+		let document = "---
+id: note_1
+created_at: 2026-01-05T09:00:00+00:00
+journal: 2026-01-05
+task: task_2
+session: session_3
+---
+
+text";
+		let note = Note::from_markdown(document).unwrap();
+
+		assert_eq!(note.journal, NaiveDate::from_ymd_opt(2026, 1, 5));
+		assert_eq!(note.task_id, Some(TaskId::from("task_2")));
+		assert_eq!(note.session_id, Some(FocusSessionId::from("session_3")));
+		assert_eq!(note.to_markdown(), document);
+	}
+
+	#[test]
+	fn searchable_text_has_values_but_not_keys() {
+		let text = Note::from_markdown(OBSIDIAN_NOTE).unwrap().searchable_text();
+
+		for value in ["Weekly: review", "web", "weekly, notes", "planning", "Folded text", "value"]
+		{
+			assert!(text.contains(value), "missing {:?}", value);
+		}
+		for key in ["title", "created_at", "aliases", "quoted key", "note_01JABCDEF"] {
+			assert!(!text.contains(key), "contains {:?}", key);
+		}
+	}
+
+	#[test]
+	fn rejects_broken_front_matter() {
+		assert!(NoteFrontMatter::parse("---\ntitle: t\n\nbody").is_err());
+		assert!(NoteFrontMatter::parse("---\ntitle: [a, b]\n---\n").is_err());
+		assert!(NoteFrontMatter::parse("---\ncreated_at: yesterday\n---\n").is_err());
+		assert!(Note::from_markdown("---\ntitle: t\n---\nbody").is_err());
+	}
+}
@@ -0,0 +1,217 @@
+use std::collections::hash_map::RandomState;
+use std::fmt;
+use std::hash::{BuildHasher, Hasher};
+use std::sync::{Mutex, PoisonError};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::errors::{PPMError, PPMResult};
+
+const CROCKFORD_BASE32: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+const RANDOM_BITS: u32 = 80;
+const RANDOM_MASK: u128 = (1 << RANDOM_BITS) - 1;
+
+/// Last (timestamp, random part) handed out, used to keep IDs monotonic within a millisecond.
+static LAST_ULID: Mutex<(u64, u128)> = Mutex::new((0, 0));
+
+/// Generates a ULID: a 48-bit millisecond timestamp followed by 80 random bits, encoded as 26
+/// Crockford base32 characters.
+///
+/// IDs sort lexicographically by creation time. IDs generated within the same millisecond
+/// increment the random part of the previous one, so they never collide within a process and
+/// stay ordered. The random part makes collisions across processes or machines negligible.
+pub fn gen_id() -> String {
+	let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_millis() as u64;
+
+	let mut last = LAST_ULID.lock().unwrap_or_else(PoisonError::into_inner);
+	let (timestamp, random) = if now <= last.0 {
+		// Same millisecond (or the clock went backwards): continue after the previous ID
+		match last.1.checked_add(1).filter(|r| *r <= RANDOM_MASK) {
+			Some(random) => (last.0, random),
+			None => (last.0 + 1, random_bits()),
+		}
+	} else {
+		(now, random_bits())
+	};
+	*last = (timestamp, random);
+
+	encode_ulid((u128::from(timestamp) << RANDOM_BITS) | random)
+}
+
+/// Builds a ULID for `timestamp_ms` whose random part is derived from `seed`, so that the same
+/// inputs always give the same ID. Used to give stable IDs to data stored before it had any.
+pub(crate) fn derived_id(timestamp_ms: u64, seed: &str) -> String {
+	// 64-bit FNV-1a, stable across runs and platforms
+	let hash = seed.bytes().fold(0xcbf29ce484222325_u64, |hash, byte| {
+		(hash ^ u64::from(byte)).wrapping_mul(0x100000001b3)
+	});
+
+	encode_ulid((u128::from(timestamp_ms) << RANDOM_BITS) | u128::from(hash))
+}
+
+fn random_bits() -> u128 {
+	// `RandomState` is seeded from the OS, which is enough entropy for an ID without a
+	// dedicated RNG dependency.
+	let state = RandomState::new();
+	let mut high = state.build_hasher();
+	high.write_u8(0);
+	let mut low = state.build_hasher();
+	low.write_u8(1);
+
+	((u128::from(high.finish()) << 64) | u128::from(low.finish())) & RANDOM_MASK
+}
+
+fn encode_ulid(value: u128) -> String {
+	(0..26)
+		.map(|i| {
+			let shift = 125 - 5 * i;
+			CROCKFORD_BASE32[((value >> shift) & 0x1f) as usize] as char
+		})
+		.collect()
+}
+
+/// Behaviour shared by every ID newtype generated with `#[model_id]`.
+///
+/// Lets commands accept a short form of an ID instead of the full string.
+pub trait ModelId: Clone + PartialEq + fmt::Display + AsRef<str> {
+	/// Prefix every generated ID starts with, e.g. `task_`.
+	const PREFIX: &'static str;
+
+	/// Human readable kind of the ID, derived from the prefix (`task_` -> `task`).
+	fn kind() -> &'static str {
+		Self::PREFIX.trim_end_matches('_')
+	}
+
+	/// 1-based position of this ID in `candidates`, which must be in creation order.
+	fn sequence_number(&self, candidates: &[Self]) -> Option<usize> {
+		candidates.iter().position(|c| c == self).map(|i| i + 1)
+	}
+
+	/// Resolves user input to one of `candidates`, which must be in creation order.
+	///
+	/// Accepted forms, tried in order:
+	/// 1. the full ID
+	/// 2. a sequence number with a `#` (`#3`)
+	/// 3. any unique prefix or suffix, with or without the kind prefix, ignoring case
+	///
+	/// A bare number (`3`) is also taken as a sequence number, but only when it is not a prefix
+	/// or suffix of any ID; otherwise it is reported as ambiguous. Every ULID starts with `0`
+	/// or `01`, so a bare number must never silently pick one of several IDs.
+	fn resolve(input: &str, candidates: &[Self]) -> PPMResult<Self> {
+		let input = input.trim();
+		let not_found = || PPMError::NotFound(format!("No {} matches '{}'", Self::kind(), input));
+
+		if let Some(exact) = candidates.iter().find(|c| c.as_ref() == input) {
+			return Ok(exact.clone());
+		}
+
+		let by_sequence = |number: &str| {
+			number
+				.parse::<usize>()
+				.ok()
+				.filter(|n| (1..=candidates.len()).contains(n))
+				.map(|n| (n, &candidates[n - 1]))
+		};
+		if let Some(number) = input.strip_prefix('#') {
+			return by_sequence(number).map(|(_, c)| c.clone()).ok_or_else(not_found);
+		}
+
+		// ULIDs are upper case, but short forms are often typed in lower case
+		let lowered = input.to_ascii_lowercase();
+		let needle = lowered.strip_prefix(Self::PREFIX).unwrap_or(&lowered);
+		if needle.is_empty() {
+			return Err(not_found());
+		}
+
+		let matches: Vec<&Self> = candidates
+			.iter()
+			.filter(|c| {
+				let body = c.as_ref().strip_prefix(Self::PREFIX).unwrap_or(c.as_ref());
+				let body = body.to_ascii_lowercase();
+				body.starts_with(needle) || body.ends_with(needle)
+			})
+			.collect();
+
+		match (by_sequence(input), matches.as_slice()) {
+			(Some((_, candidate)), []) => Ok(candidate.clone()),
+			(Some((n, candidate)), many) => {
+				let mut listed = vec![format!("#{} ({})", n, candidate)];
+				listed.extend(many.iter().filter(|c| **c != candidate).map(|c| c.to_string()));
+				Err(PPMError::AmbiguousId(input.to_string(), listed))
+			}
+			(None, []) => Err(not_found()),
+			(None, [single]) => Ok((*single).clone()),
+			(None, many) => Err(PPMError::AmbiguousId(
+				input.to_string(),
+				many.iter().map(|c| c.to_string()).collect(),
+			)),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::models::TaskId;
+
+	fn ids(bodies: &[&str]) -> Vec<TaskId> {
+		bodies.iter().map(|b| TaskId::from(format!("task_{}", b).as_str())).collect()
+	}
+
+	#[test]
+	fn resolves_full_ids_and_hash_numbers() {
+		let ids = ids(&["01AAA", "01BBB", "01CCC"]);
+
+		assert_eq!(TaskId::resolve("task_01BBB", &ids).unwrap(), ids[1]);
+		assert_eq!(TaskId::resolve("#3", &ids).unwrap(), ids[2]);
+		assert!(matches!(TaskId::resolve("#4", &ids), Err(PPMError::NotFound(_))));
+		assert!(matches!(TaskId::resolve("#0", &ids), Err(PPMError::NotFound(_))));
+	}
+
+	#[test]
+	fn resolves_unique_prefixes_and_suffixes() {
+		let ids = ids(&["01AAA", "01BBB", "01CCC"]);
+
+		assert_eq!(TaskId::resolve("01B", &ids).unwrap(), ids[1]);
+		assert_eq!(TaskId::resolve("task_01C", &ids).unwrap(), ids[2]);
+		assert_eq!(TaskId::resolve("AA", &ids).unwrap(), ids[0]);
+		assert!(matches!(TaskId::resolve("01", &ids), Err(PPMError::AmbiguousId(..))));
+		assert!(matches!(TaskId::resolve("ZZ", &ids), Err(PPMError::NotFound(_))));
+		assert!(matches!(TaskId::resolve("task_", &ids), Err(PPMError::NotFound(_))));
+	}
+
+	#[test]
+	fn prefixes_and_suffixes_ignore_case() {
+		let ids = ids(&["01JABCD", "01KXYZ"]);
+
+		assert_eq!(TaskId::resolve("01jab", &ids).unwrap(), ids[0]);
+		assert_eq!(TaskId::resolve("Task_01Kx", &ids).unwrap(), ids[1]);
+		assert_eq!(TaskId::resolve("xyz", &ids).unwrap(), ids[1]);
+		assert_eq!(TaskId::resolve("task_01jabcd", &ids).unwrap(), ids[0]);
+		assert!(matches!(TaskId::resolve("01j01", &ids), Err(PPMError::NotFound(_))));
+	}
+
+	#[test]
+	fn bare_numbers_that_match_an_id_are_ambiguous() {
+		let ids = ids(&["01AAA", "01BBB", "01CC2"]);
+
+		// `2` is #2 but also the suffix of the third ID
+		match TaskId::resolve("2", &ids) {
+			Err(PPMError::AmbiguousId(_, listed)) => {
+				assert_eq!(listed, vec!["#2 (task_01BBB)", "task_01CC2"])
+			}
+			other => panic!("expected an ambiguous ID, got {:?}", other),
+		}
+		assert!(matches!(TaskId::resolve("0", &ids), Err(PPMError::AmbiguousId(..))));
+		assert_eq!(TaskId::resolve("3", &ids).unwrap(), ids[2]);
+		assert_eq!(TaskId::resolve("1", &ids).unwrap(), ids[0]);
+	}
+
+	#[test]
+	fn generated_ids_are_ordered() {
+		let first = gen_id();
+		let second = gen_id();
+
+		assert_eq!(first.len(), 26);
+		assert!(first < second);
+	}
+}
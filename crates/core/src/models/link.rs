@@ -0,0 +1,124 @@
+use crate::models::{Note, NoteId, ProjectName, TaskId};
+
+/// A `[[target]]` or `[[target|label]]` link in a note body.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NoteLink {
+	/// Text between the brackets, without the label
+	pub raw: String,
+	pub target: LinkTarget,
+}
+
+/// What a link points at, judging by its text alone.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LinkTarget {
+	/// `[[note_…]]`
+	Note(NoteId),
+	/// `[[task_…]]`
+	Task(TaskId),
+	/// `[[project:name]]`
+	Project(ProjectName),
+	/// Any other text: a note title, or else a project name
+	Name(String),
+}
+
+impl LinkTarget {
+	pub fn parse(text: &str) -> Self {
+		if text.starts_with("note_") {
+			LinkTarget::Note(NoteId::from(text))
+		} else if text.starts_with("task_") {
+			LinkTarget::Task(TaskId::from(text))
+		} else if let Some(name) = text.strip_prefix("project:") {
+			LinkTarget::Project(ProjectName::from(name.trim()))
+		} else {
+			LinkTarget::Name(text.to_string())
+		}
+	}
+}
+
+impl Note {
+	/// Links in the body, in order of appearance.
+	pub fn links(&self) -> Vec<NoteLink> {
+		let mut links = Vec::new();
+		let mut rest = self.content.as_str();
+
+		while let Some(start) = rest.find("[[") {
+			rest = &rest[start + 2..];
+			let Some(end) = rest.find("]]") else {
+				break;
+			};
+
+			// Links do not span lines or nest, so a `[[` left open is plain text and the scan
+			// goes on right after it
+			let inner = &rest[..end];
+			if inner.contains('\n') || inner.contains("[[") {
+				continue;
+			}
+			rest = &rest[end + 2..];
+
+			let raw = inner.split('|').next().unwrap_or_default().trim();
+			if !raw.is_empty() {
+				links.push(NoteLink {
+					raw: raw.to_string(),
+					target: LinkTarget::parse(raw),
+				});
+			}
+		}
+
+		links
+	}
+
+	/// Whether `link` points at this note, by ID or by title.
+	pub fn is_linked_by(&self, link: &NoteLink) -> bool {
+		match link.target {
+			LinkTarget::Note(ref id) => id == &self.id,
+			LinkTarget::Name(ref name) => {
+				self.title.as_ref().is_some_and(|title| title.to_lowercase() == name.to_lowercase())
+			}
+			_ => false,
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_support::note;
+
+	fn raws(content: &str) -> Vec<String> {
+		note(content).links().into_iter().map(|l| l.raw).collect()
+	}
+
+	#[test]
+	fn parses_targets_and_labels() {
+		let links =
+			note("See [[note_01AB|the plan]], [[task_01CD]] and [[ project: Home ]].").links();
+		assert_eq!(links[0].target, LinkTarget::Note(NoteId::from("note_01AB")));
+		assert_eq!(links[1].target, LinkTarget::Task(TaskId::from("task_01CD")));
+		assert_eq!(links[2].target, LinkTarget::Project(ProjectName::from("Home")));
+		assert_eq!(links.len(), 3);
+
+		assert_eq!(raws("[[Weekly review]] [[]] [[ |label]]"), vec!["Weekly review"]);
+	}
+
+	#[test]
+	fn unclosed_brackets_do_not_hide_later_links() {
+		assert_eq!(raws("[[open\nthen [[b]]"), vec!["b"]);
+		assert_eq!(raws("[[open then [[b]] and [[c]]"), vec!["b", "c"]);
+		assert_eq!(raws("[[a]] [[never closed"), vec!["a"]);
+	}
+
+	#[test]
+	fn notes_are_linked_by_id_or_title() {
+		let target = Note {
+			title: Some("Weekly Review".to_string()),
+			..note("Body")
+		};
+		let linked_by =
+			|content: &str| note(content).links().iter().any(|l| target.is_linked_by(l));
+
+		assert!(linked_by(&format!("[[{}]]", target.id)));
+		assert!(linked_by("[[weekly review|last week]]"));
+		assert!(!linked_by("[[Weekly]]"));
+		assert!(!linked_by("[[project:Weekly Review]]"));
+	}
+}
@@ -1,36 +1,147 @@
-use chrono::{DateTime, Duration, Utc};
+mod estimate;
+mod front_matter;
+mod id;
+mod link;
+mod recurrence;
+mod trash;
+mod workflow;
+
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+pub use estimate::Estimate;
+pub use front_matter::NoteFrontMatter;
+pub use id::{ModelId, gen_id};
+pub use link::{LinkTarget, NoteLink};
 use model_macros::{model, model_id, model_name};
+pub use recurrence::Recurrence;
+pub use trash::{TrashEntry, TrashEntryId, TrashedItem};
+pub use workflow::{StateCategory, Transition, Workflow, WorkflowState};
 
-pub fn gen_id() -> String {
-	use std::time::{SystemTime, UNIX_EPOCH};
-	let timestamp = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_micros();
-	format!("{}", timestamp)
-}
+use crate::errors::{PPMError, PPMResult};
+use crate::yaml::YamlEntry;
 
 #[model_id(prefix = "session_", gen = crate::models::gen_id)]
 pub struct FocusSessionId(pub String);
 
+/// Name of a project, also its key.
+///
+/// Names are paths: `work/api` is a subproject of `work`, which is its parent.
 #[model_name]
 pub struct ProjectName(pub String);
 
+impl ProjectName {
+	pub const SEPARATOR: char = '/';
+
+	/// Checks the path syntax: one or more non-empty segments separated by `/`.
+	pub fn validate(&self) -> PPMResult<()> {
+		let invalid = |reason: &str| {
+			Err(PPMError::InvalidInput(format!("Invalid project name '{}': {}", self, reason)))
+		};
+
+		if self.0.trim().is_empty() {
+			return invalid("name cannot be empty");
+		}
+		for segment in self.segments() {
+			if segment.is_empty() {
+				return invalid("empty path segment");
+			}
+			if segment != segment.trim() {
+				return invalid("path segments cannot start or end with whitespace");
+			}
+			if segment == "." || segment == ".." {
+				return invalid("'.' and '..' are not allowed as path segments");
+			}
+		}
+
+		Ok(())
+	}
+
+	pub fn segments(&self) -> impl Iterator<Item = &str> {
+		self.0.split(Self::SEPARATOR)
+	}
+
+	/// Last segment, e.g. `api` for `work/api`.
+	pub fn leaf(&self) -> &str {
+		self.0.rsplit(Self::SEPARATOR).next().unwrap_or(&self.0)
+	}
+
+	pub fn parent(&self) -> Option<ProjectName> {
+		self.0.rsplit_once(Self::SEPARATOR).map(|(parent, _)| ProjectName::from(parent))
+	}
+
+	/// All ancestors, outermost first: `a`, `a/b` for `a/b/c`.
+	pub fn ancestors(&self) -> Vec<ProjectName> {
+		let mut ancestors = Vec::new();
+		let mut current = self.parent();
+		while let Some(name) = current {
+			current = name.parent();
+			ancestors.push(name);
+		}
+		ancestors.reverse();
+		ancestors
+	}
+
+	/// Whether `other` is this project or one of its descendants.
+	pub fn includes(&self, other: &ProjectName) -> bool {
+		other.0 == self.0
+			|| other
+				.0
+				.strip_prefix(self.0.as_str())
+				.is_some_and(|rest| rest.starts_with(Self::SEPARATOR))
+	}
+
+	/// Moves this name from the subtree of `from` to the subtree of `to`, e.g. `work/api`
+	/// becomes `job/api` for `work` → `job`. Returns `None` outside the subtree of `from`.
+	pub fn rebase(&self, from: &ProjectName, to: &ProjectName) -> Option<ProjectName> {
+		from.includes(self).then(|| ProjectName(format!("{}{}", to, &self.0[from.0.len()..])))
+	}
+}
+
 #[model]
 pub struct Project {
 	pub name: ProjectName,
 	pub description: String,
 	pub created_at: DateTime<Utc>,
 	pub status: ProjectStatus,
+	#[serde(default)]
+	pub milestones: Vec<Milestone>,
+}
+
+impl Project {
+	/// Finds a milestone by name, ignoring case.
+	pub fn milestone(&self, name: &str) -> Option<&Milestone> {
+		self.milestones.iter().find(|m| m.name.eq_ignore_ascii_case(name))
+	}
+
+	pub fn milestone_mut(&mut self, name: &str) -> Option<&mut Milestone> {
+		self.milestones.iter_mut().find(|m| m.name.eq_ignore_ascii_case(name))
+	}
+}
+
+/// Checkpoint of a project: a set of tasks to finish by a target date.
+#[model]
+pub struct Milestone {
+	pub name: String,
+	pub target: NaiveDate,
+	pub created_at: DateTime<Utc>,
+	#[serde(default)]
+	pub task_ids: Vec<TaskId>,
 }
 
 #[model]
+#[derive(PartialEq, Eq)]
 pub enum ProjectStatus {
 	Active,
 	Inactive,
+	/// Finished project, hidden from default listings and closed to new work.
+	Archived,
 }
 
 #[model]
 pub struct FocusSession {
 	pub id: FocusSessionId,
 	pub associated_project_name: Option<ProjectName>,
+	#[serde(default)]
+	pub associated_task_id: Option<TaskId>,
 	pub start: DateTime<Utc>,
 	pub end: DateTime<Utc>,
 }
@@ -43,6 +154,11 @@ impl FocusSession {
 	pub fn is_active(&self, now: DateTime<Utc>) -> bool {
 		now >= self.start && now <= self.end
 	}
+
+	/// Focus time actually spent so far: an active session only counts up to `now`.
+	pub fn elapsed(&self, now: DateTime<Utc>) -> Duration {
+		(self.end.min(now) - self.start).max(Duration::zero())
+	}
 }
 
 #[model_id(prefix = "task_", gen = crate::models::gen_id)]
@@ -55,6 +171,17 @@ pub struct Task {
 	pub description: String,
 	pub status: TaskStatus,
 	pub created_at: DateTime<Utc>,
+	#[serde(default)]
+	pub due: Option<NaiveDate>,
+	#[serde(default)]
+	pub recurrence: Option<Recurrence>,
+	#[serde(default)]
+	pub estimate: Option<Estimate>,
+	#[serde(default)]
+	pub tags: Vec<String>,
+	/// Name of the task's workflow state; `None` uses the default state of its status.
+	#[serde(default)]
+	pub workflow_state: Option<String>,
 }
 
 #[model]
@@ -70,7 +197,49 @@ pub struct NoteId(pub String);
 #[model]
 pub struct Note {
 	pub id: NoteId,
+	#[serde(default)]
+	pub title: Option<String>,
 	pub project_name: Option<ProjectName>,
+	/// Task the note is about
+	#[serde(default)]
+	pub task_id: Option<TaskId>,
+	/// Focus session the note was written in
+	#[serde(default)]
+	pub session_id: Option<FocusSessionId>,
 	pub content: String,
 	pub created_at: DateTime<Utc>,
+	#[serde(default)]
+	pub updated_at: Option<DateTime<Utc>>,
+	/// Local date of a daily journal note
+	#[serde(default)]
+	pub journal: Option<NaiveDate>,
+	/// Front matter keys ppm does not use, kept so that rewriting the file preserves them
+	#[serde(default)]
+	pub extra_front_matter: Vec<YamlEntry>,
+}
+
+impl Note {
+	/// Text of the first markdown heading in `content`, if any.
+	pub fn heading_title(content: &str) -> Option<String> {
+		content.lines().map(str::trim).find_map(|line| {
+			// `#tag` is not a heading; `# Heading` is
+			let heading = line.strip_prefix('#')?.trim_start_matches('#');
+			(heading.starts_with(' ') && !heading.trim().is_empty())
+				.then(|| heading.trim().to_string())
+		})
+	}
+
+	/// The title, or a preview of the first line for untitled notes.
+	pub fn display_title(&self) -> String {
+		if let Some(ref title) = self.title {
+			return title.clone();
+		}
+
+		let first_line = self.content.lines().next().unwrap_or("");
+		if first_line.chars().count() > 50 {
+			format!("{}...", first_line.chars().take(50).collect::<String>())
+		} else {
+			first_line.to_string()
+		}
+	}
 }
@@ -0,0 +1,161 @@
+use std::fmt;
+use std::str::FromStr;
+
+use chrono::{Datelike, Days, NaiveDate, Weekday};
+use model_macros::model;
+
+use crate::errors::PPMError;
+
+/// Rule describing how often a recurring task comes back.
+///
+/// Textual form (used by the CLI): `daily`, `weekdays`, `weekly:mon`, `monthly:15`, `every:3`.
+#[model]
+#[derive(PartialEq, Eq)]
+pub enum Recurrence {
+	Daily,
+	/// Monday to Friday.
+	Weekdays,
+	Weekly(Weekday),
+	/// Day of month. Clamped to the last day for shorter months, and to the 1st when 0.
+	Monthly(u32),
+	EveryNDays(u32),
+}
+
+impl Recurrence {
+	/// Returns the first occurrence strictly after `date`.
+	pub fn next_after(&self, date: NaiveDate) -> NaiveDate {
+		match self {
+			Recurrence::Daily => date + Days::new(1),
+			Recurrence::Weekdays => {
+				let mut next = date + Days::new(1);
+				while matches!(next.weekday(), Weekday::Sat | Weekday::Sun) {
+					next = next + Days::new(1);
+				}
+				next
+			}
+			Recurrence::Weekly(weekday) => {
+				let mut next = date + Days::new(1);
+				while next.weekday() != *weekday {
+					next = next + Days::new(1);
+				}
+				next
+			}
+			Recurrence::Monthly(day) => {
+				let this_month = clamped_day_of_month(date.year(), date.month(), *day);
+				if this_month > date {
+					return this_month;
+				}
+				let (year, month) = if date.month() == 12 {
+					(date.year() + 1, 1)
+				} else {
+					(date.year(), date.month() + 1)
+				};
+				clamped_day_of_month(year, month, *day)
+			}
+			Recurrence::EveryNDays(n) => date + Days::new(u64::from(*n)),
+		}
+	}
+}
+
+fn clamped_day_of_month(year: i32, month: u32, day: u32) -> NaiveDate {
+	// 0 can still come from a hand-edited tasks.json
+	let mut day = day.clamp(1, 31);
+	loop {
+		if let Some(date) = NaiveDate::from_ymd_opt(year, month, day) {
+			return date;
+		}
+		day -= 1;
+	}
+}
+
+impl fmt::Display for Recurrence {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Recurrence::Daily => write!(f, "daily"),
+			Recurrence::Weekdays => write!(f, "weekdays"),
+			Recurrence::Weekly(weekday) => {
+				write!(f, "weekly:{}", weekday.to_string().to_lowercase())
+			}
+			Recurrence::Monthly(day) => write!(f, "monthly:{}", day),
+			Recurrence::EveryNDays(n) => write!(f, "every:{}", n),
+		}
+	}
+}
+
+impl FromStr for Recurrence {
+	type Err = PPMError;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let invalid = || {
+			PPMError::InvalidInput(format!(
+				"Invalid recurrence '{}' (expected daily, weekdays, weekly:<day>, monthly:<1-31> or every:<days>)",
+				s
+			))
+		};
+
+		let s = s.trim().to_lowercase();
+		let (kind, arg) = match s.split_once(':') {
+			Some((kind, arg)) => (kind, Some(arg)),
+			None => (s.as_str(), None),
+		};
+
+		match (kind, arg) {
+			("daily", None) => Ok(Recurrence::Daily),
+			("weekdays", None) => Ok(Recurrence::Weekdays),
+			("weekly", Some(day)) => {
+				day.parse::<Weekday>().map(Recurrence::Weekly).map_err(|_| invalid())
+			}
+			("monthly", Some(day)) => match day.parse::<u32>() {
+				Ok(day) if (1..=31).contains(&day) => Ok(Recurrence::Monthly(day)),
+				_ => Err(invalid()),
+			},
+			("every", Some(days)) => match days.trim_end_matches('d').parse::<u32>() {
+				Ok(days) if days > 0 => Ok(Recurrence::EveryNDays(days)),
+				_ => Err(invalid()),
+			},
+			_ => Err(invalid()),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+		NaiveDate::from_ymd_opt(y, m, d).unwrap()
+	}
+
+	#[test]
+	fn next_after_is_strictly_later() {
+		// 2026-01-02 is a Friday
+		let friday = date(2026, 1, 2);
+		assert_eq!(Recurrence::Daily.next_after(friday), date(2026, 1, 3));
+		assert_eq!(Recurrence::Weekdays.next_after(friday), date(2026, 1, 5));
+		assert_eq!(Recurrence::Weekly(Weekday::Fri).next_after(friday), date(2026, 1, 9));
+		assert_eq!(Recurrence::Weekly(Weekday::Mon).next_after(friday), date(2026, 1, 5));
+		assert_eq!(Recurrence::EveryNDays(3).next_after(friday), date(2026, 1, 5));
+	}
+
+	#[test]
+	fn monthly_clamps_to_the_month() {
+		assert_eq!(Recurrence::Monthly(15).next_after(date(2026, 1, 10)), date(2026, 1, 15));
+		assert_eq!(Recurrence::Monthly(15).next_after(date(2026, 1, 15)), date(2026, 2, 15));
+		assert_eq!(Recurrence::Monthly(31).next_after(date(2026, 1, 31)), date(2026, 2, 28));
+		assert_eq!(Recurrence::Monthly(31).next_after(date(2026, 2, 28)), date(2026, 3, 31));
+		assert_eq!(Recurrence::Monthly(1).next_after(date(2026, 12, 5)), date(2027, 1, 1));
+		assert_eq!(Recurrence::Monthly(0).next_after(date(2026, 1, 10)), date(2026, 2, 1));
+		assert_eq!(Recurrence::Monthly(40).next_after(date(2026, 4, 1)), date(2026, 4, 30));
+	}
+
+	#[test]
+	fn parses_and_displays() {
+		for text in ["daily", "weekdays", "weekly:mon", "monthly:15", "every:3"] {
+			assert_eq!(text.parse::<Recurrence>().unwrap().to_string(), text);
+		}
+		assert_eq!("every:3d".parse::<Recurrence>().unwrap(), Recurrence::EveryNDays(3));
+		for text in ["monthly:0", "monthly:32", "every:0", "weekly", "daily:1", "hourly"] {
+			assert!(text.parse::<Recurrence>().is_err(), "{}", text);
+		}
+	}
+}
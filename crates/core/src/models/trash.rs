@@ -0,0 +1,102 @@
+use chrono::{DateTime, Utc};
+use model_macros::{model, model_id};
+use serde::{Deserialize, Serialize};
+
+use crate::models::id::derived_id;
+use crate::models::{FocusSession, ModelId, Note, Project, Task};
+
+#[model_id(prefix = "trash_", gen = crate::models::gen_id)]
+pub struct TrashEntryId(pub String);
+
+/// Deleted item kept in the trash until it is restored or purged.
+///
+/// Entries have their own ID, so the same item or two projects of the same name can be in the
+/// trash side by side.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(from = "StoredTrashEntry")]
+pub struct TrashEntry {
+	pub id: TrashEntryId,
+	pub item: TrashedItem,
+	pub deleted_at: DateTime<Utc>,
+}
+
+impl TrashEntry {
+	pub fn new(item: TrashedItem, deleted_at: DateTime<Utc>) -> Self {
+		Self {
+			id: TrashEntryId::new(),
+			item,
+			deleted_at,
+		}
+	}
+}
+
+/// Stored form of a [`TrashEntry`]; entries trashed before entry IDs existed have none.
+#[derive(Deserialize)]
+struct StoredTrashEntry {
+	id: Option<TrashEntryId>,
+	item: TrashedItem,
+	deleted_at: DateTime<Utc>,
+}
+
+impl From<StoredTrashEntry> for TrashEntry {
+	fn from(stored: StoredTrashEntry) -> Self {
+		// Older entries get an ID derived from their content, the same on every load
+		let id = stored.id.unwrap_or_else(|| {
+			let timestamp_ms = u64::try_from(stored.deleted_at.timestamp_millis()).unwrap_or(0);
+			TrashEntryId(format!(
+				"{}{}",
+				TrashEntryId::PREFIX,
+				derived_id(timestamp_ms, stored.item.id())
+			))
+		});
+
+		Self {
+			id,
+			item: stored.item,
+			deleted_at: stored.deleted_at,
+		}
+	}
+}
+
+#[model]
+pub enum TrashedItem {
+	Task(Task),
+	Project(Project),
+	Note(Note),
+	Session(FocusSession),
+}
+
+impl TrashedItem {
+	/// ID of the trashed item; the name for projects.
+	pub fn id(&self) -> &str {
+		match self {
+			TrashedItem::Task(task) => task.id.as_ref(),
+			TrashedItem::Project(project) => project.name.as_ref(),
+			TrashedItem::Note(note) => note.id.as_ref(),
+			TrashedItem::Session(session) => session.id.as_ref(),
+		}
+	}
+
+	pub fn kind(&self) -> &'static str {
+		match self {
+			TrashedItem::Task(_) => "task",
+			TrashedItem::Project(_) => "project",
+			TrashedItem::Note(_) => "note",
+			TrashedItem::Session(_) => "session",
+		}
+	}
+
+	/// One-line description for listings.
+	pub fn summary(&self) -> String {
+		match self {
+			TrashedItem::Task(task) => task.description.clone(),
+			TrashedItem::Project(project) => project.description.clone(),
+			TrashedItem::Note(note) => note.display_title(),
+			TrashedItem::Session(session) => format!(
+				"{} ({} minutes)",
+				session.start.format("%Y-%m-%d %H:%M"),
+				session.duration().num_minutes()
+			),
+		}
+	}
+}
@@ -0,0 +1,380 @@
+use model_macros::model;
+
+use crate::errors::{PPMError, PPMResult};
+use crate::models::{Task, TaskStatus};
+
+/// Configurable set of task states and the moves allowed between them.
+///
+/// Every state belongs to a category that maps onto `TaskStatus`, so commands that only know
+/// pending/done/canceled keep working. Tasks without a stored state, including tasks written
+/// before workflows existed, are placed in the default state of their category.
+#[model]
+pub struct Workflow {
+	/// States in board order.
+	pub states: Vec<WorkflowState>,
+	/// State of new pending tasks. Defaults to the first pending state.
+	#[serde(default)]
+	pub initial: Option<String>,
+	/// Allowed moves. Empty allows moving between any two states.
+	#[serde(default)]
+	pub transitions: Vec<Transition>,
+}
+
+#[model]
+pub struct WorkflowState {
+	pub name: String,
+	pub category: StateCategory,
+}
+
+#[model]
+#[derive(Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum StateCategory {
+	Pending,
+	Done,
+	Canceled,
+}
+
+#[model]
+pub struct Transition {
+	pub from: String,
+	pub to: String,
+}
+
+impl Workflow {
+	/// Finds a state by name, ignoring case.
+	pub fn state(&self, name: &str) -> Option<&WorkflowState> {
+		self.states.iter().find(|s| s.name.eq_ignore_ascii_case(name))
+	}
+
+	/// State used for tasks of `category` that have no (valid) stored state.
+	pub fn default_state(&self, category: StateCategory) -> Option<&WorkflowState> {
+		if category == StateCategory::Pending
+			&& let Some(initial) = self.initial.as_deref().and_then(|name| self.state(name))
+		{
+			return Some(initial);
+		}
+		self.states.iter().find(|s| s.category == category)
+	}
+
+	/// Current state of `task`.
+	///
+	/// A stored state is only used while it agrees with the task status, so tasks completed or
+	/// canceled through the plain commands land in the matching column.
+	/// Returns `None` only for workflows that fail `validate`.
+	pub fn state_of(&self, task: &Task) -> Option<&WorkflowState> {
+		let category = StateCategory::of(&task.status);
+		task.workflow_state
+			.as_deref()
+			.and_then(|name| self.state(name))
+			.filter(|state| state.category == category)
+			.or_else(|| self.default_state(category))
+	}
+
+	pub fn can_move(&self, from: &WorkflowState, to: &WorkflowState) -> bool {
+		from.name != to.name
+			&& (self.transitions.is_empty()
+				|| self.transitions.iter().any(|t| {
+					t.from.eq_ignore_ascii_case(&from.name) && t.to.eq_ignore_ascii_case(&to.name)
+				}))
+	}
+
+	/// Names of the states reachable from `from` in one move.
+	pub fn next_states(&self, from: &WorkflowState) -> Vec<&str> {
+		self.states.iter().filter(|to| self.can_move(from, to)).map(|s| s.name.as_str()).collect()
+	}
+
+	pub fn validate(&self) -> PPMResult<()> {
+		let invalid =
+			|message: String| Err(PPMError::ConfigError(format!("workflow: {}", message)));
+
+		for (i, state) in self.states.iter().enumerate() {
+			if state.name.trim().is_empty() || state.name.contains(char::is_whitespace) {
+				return invalid(format!("invalid state name '{}'", state.name));
+			}
+			if self.states[..i].iter().any(|s| s.name.eq_ignore_ascii_case(&state.name)) {
+				return invalid(format!("duplicate state '{}'", state.name));
+			}
+		}
+
+		for category in [StateCategory::Pending, StateCategory::Done, StateCategory::Canceled] {
+			if !self.states.iter().any(|s| s.category == category) {
+				return invalid(format!("at least one {} state is required", category));
+			}
+		}
+
+		if let Some(initial) = &self.initial {
+			match self.state(initial) {
+				Some(state) if state.category == StateCategory::Pending => {}
+				Some(_) => return invalid(format!("initial state '{}' is not pending", initial)),
+				None => return invalid(format!("unknown initial state '{}'", initial)),
+			}
+		}
+
+		for transition in &self.transitions {
+			for name in [&transition.from, &transition.to] {
+				if self.state(name).is_none() {
+					return invalid(format!("unknown state '{}' in transitions", name));
+				}
+			}
+		}
+
+		Ok(())
+	}
+}
+
+impl Default for Workflow {
+	/// backlog → todo → doing → review → done, with moves back one step, reopening and
+	/// canceling from any pending state.
+	fn default() -> Self {
+		let state = |name: &str, category| WorkflowState {
+			name: name.to_string(),
+			category,
+		};
+		let transition = |from: &str, to: &str| Transition {
+			from: from.to_string(),
+			to: to.to_string(),
+		};
+
+		let flow = ["backlog", "todo", "doing", "review", "done"];
+		let mut transitions = Vec::new();
+		for pair in flow.windows(2) {
+			transitions.push(transition(pair[0], pair[1]));
+			transitions.push(transition(pair[1], pair[0]));
+		}
+		for pending in &flow[..4] {
+			transitions.push(transition(pending, "canceled"));
+		}
+		transitions.push(transition("canceled", "backlog"));
+
+		Self {
+			states: vec![
+				state("backlog", StateCategory::Pending),
+				state("todo", StateCategory::Pending),
+				state("doing", StateCategory::Pending),
+				state("review", StateCategory::Pending),
+				state("done", StateCategory::Done),
+				state("canceled", StateCategory::Canceled),
+			],
+			initial: Some("todo".to_string()),
+			transitions,
+		}
+	}
+}
+
+impl StateCategory {
+	pub fn of(status: &TaskStatus) -> Self {
+		match status {
+			TaskStatus::Pending => StateCategory::Pending,
+			TaskStatus::Done(_) => StateCategory::Done,
+			TaskStatus::Canceled(_) => StateCategory::Canceled,
+		}
+	}
+}
+
+impl std::fmt::Display for StateCategory {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			StateCategory::Pending => write!(f, "pending"),
+			StateCategory::Done => write!(f, "done"),
+			StateCategory::Canceled => write!(f, "canceled"),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::sync::Arc;
+
+	use super::*;
+	use crate::clock::FixedClock;
+	use crate::output::InMemoryWriter;
+	use crate::repositories::task::{InMemoryTaskRepository, TaskRepository};
+	use crate::services::Service;
+	use crate::services::task::{MoveTaskState, TaskBoard};
+	use crate::test_support::{task, utc};
+
+	fn state(name: &str, category: StateCategory) -> WorkflowState {
+		WorkflowState {
+			name: name.to_string(),
+			category,
+		}
+	}
+
+	/// inbox → active → shipped, with dropped for canceled tasks and no transition rules.
+	fn custom() -> Workflow {
+		Workflow {
+			states: vec![
+				state("inbox", StateCategory::Pending),
+				state("active", StateCategory::Pending),
+				state("shipped", StateCategory::Done),
+				state("dropped", StateCategory::Canceled),
+			],
+			initial: None,
+			transitions: Vec::new(),
+		}
+	}
+
+	fn move_state(
+		workflow: Workflow,
+		task: Task,
+		to: &str,
+	) -> (PPMResult<()>, Task, Arc<InMemoryWriter>) {
+		let repository = Arc::new(InMemoryTaskRepository::new());
+		repository.create_task(task.clone()).unwrap();
+		let output = Arc::new(InMemoryWriter::new());
+
+		let result = MoveTaskState {
+			clock: Arc::new(FixedClock::new(utc(2026, 1, 10, 9, 0))),
+			task_repository: repository.clone(),
+			output_writer: output.clone(),
+			workflow,
+			task_id: task.id.to_string(),
+			state: to.to_string(),
+		}
+		.run();
+
+		(result, repository.get_task(&task.id).unwrap().unwrap(), output)
+	}
+
+	#[test]
+	fn states_are_found_ignoring_case() {
+		let workflow = Workflow::default();
+		assert!(workflow.validate().is_ok());
+		assert_eq!(workflow.state("Doing").unwrap().name, "doing");
+		assert!(workflow.state("blocked").is_none());
+	}
+
+	#[test]
+	fn tasks_without_a_matching_state_use_their_category_default() {
+		let workflow = Workflow::default();
+		assert_eq!(workflow.state_of(&task("New")).unwrap().name, "todo");
+
+		let stale = Task {
+			status: TaskStatus::Done(utc(2026, 1, 2, 0, 0)),
+			workflow_state: Some("doing".to_string()),
+			..task("Finished elsewhere")
+		};
+		assert_eq!(workflow.state_of(&stale).unwrap().name, "done");
+
+		let unknown = Task {
+			workflow_state: Some("blocked".to_string()),
+			..task("Old state")
+		};
+		assert_eq!(workflow.state_of(&unknown).unwrap().name, "todo");
+		assert_eq!(custom().state_of(&unknown).unwrap().name, "inbox");
+	}
+
+	#[test]
+	fn validate_rejects_unknown_and_missing_states() {
+		let mut workflow = custom();
+		workflow.initial = Some("later".to_string());
+		assert!(matches!(workflow.validate(), Err(PPMError::ConfigError(_))));
+
+		let mut workflow = custom();
+		workflow.transitions = vec![Transition {
+			from: "inbox".to_string(),
+			to: "blocked".to_string(),
+		}];
+		assert!(matches!(workflow.validate(), Err(PPMError::ConfigError(_))));
+
+		let mut workflow = custom();
+		workflow.states.retain(|s| s.category != StateCategory::Canceled);
+		assert!(matches!(workflow.validate(), Err(PPMError::ConfigError(_))));
+	}
+
+	#[test]
+	fn moving_to_an_unknown_state_fails() {
+		let (result, stored, _) = move_state(Workflow::default(), task("Write docs"), "blocked");
+
+		assert!(
+			matches!(result, Err(PPMError::InvalidInput(message)) if message.contains("blocked"))
+		);
+		assert_eq!(stored.workflow_state, None);
+	}
+
+	#[test]
+	fn moves_follow_the_configured_transitions() {
+		let (result, stored, _) = move_state(Workflow::default(), task("Write docs"), "done");
+
+		assert!(
+			matches!(result, Err(PPMError::InvalidInput(message)) if message.contains("allowed: backlog, doing, canceled"))
+		);
+		assert!(matches!(stored.status, TaskStatus::Pending));
+	}
+
+	#[test]
+	fn moving_into_a_terminal_state_sets_the_status() {
+		let now = utc(2026, 1, 10, 9, 0);
+
+		let (result, stored, output) = move_state(custom(), task("Ship it"), "shipped");
+		assert_eq!(result, Ok(()));
+		assert!(matches!(stored.status, TaskStatus::Done(at) if at == now));
+		assert_eq!(stored.workflow_state.as_deref(), Some("shipped"));
+		assert_eq!(
+			output.lines().unwrap(),
+			vec![format!("[ppm] Task {} moved from 'inbox' to 'shipped'", stored.id)]
+		);
+
+		let (result, stored, _) = move_state(custom(), task("Drop it"), "DROPPED");
+		assert_eq!(result, Ok(()));
+		assert!(matches!(stored.status, TaskStatus::Canceled(at) if at == now));
+		assert_eq!(stored.workflow_state.as_deref(), Some("dropped"));
+
+		let reopened = Task {
+			status: TaskStatus::Canceled(utc(2026, 1, 2, 0, 0)),
+			..task("Reopen it")
+		};
+		let (result, stored, _) = move_state(custom(), reopened, "active");
+		assert_eq!(result, Ok(()));
+		assert!(matches!(stored.status, TaskStatus::Pending));
+	}
+
+	#[test]
+	fn board_shows_one_column_per_custom_state() {
+		let repository = Arc::new(InMemoryTaskRepository::new());
+		let tasks = [
+			task("Triage"),
+			Task {
+				workflow_state: Some("active".to_string()),
+				..task("Build")
+			},
+			Task {
+				status: TaskStatus::Done(utc(2026, 1, 2, 0, 0)),
+				..task("Release")
+			},
+			Task {
+				status: TaskStatus::Canceled(utc(2026, 1, 2, 0, 0)),
+				..task("Rewrite")
+			},
+		];
+		for (i, t) in tasks.into_iter().enumerate() {
+			repository
+				.create_task(Task {
+					created_at: utc(2026, 1, 1, 0, i as u32),
+					..t
+				})
+				.unwrap();
+		}
+		let output = Arc::new(InMemoryWriter::new());
+
+		TaskBoard {
+			task_repository: repository,
+			output_writer: output.clone(),
+			workflow: custom(),
+			project_name: None,
+			show_canceled: false,
+		}
+		.run()
+		.unwrap();
+
+		let lines = output.lines().unwrap();
+		let row = |cells: &[&str]| {
+			let cells: Vec<String> = cells.iter().map(|c| format!("{:<24}", c)).collect();
+			format!("[ppm] {}", cells.join(" | ").trim_end())
+		};
+		assert_eq!(lines.len(), 3);
+		assert_eq!(lines[0], row(&["inbox (1)", "active (1)", "shipped (1)"]));
+		assert_eq!(lines[2], row(&["#1 Triage", "#2 Build", "#3 Release"]));
+	}
+}
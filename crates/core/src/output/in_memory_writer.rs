@@ -16,6 +16,11 @@ impl InMemoryWriter {
 			lines: Arc::new(Mutex::new(Vec::new())),
 		}
 	}
+
+	/// Lines written so far, including the [ppm] header
+	pub fn lines(&self) -> PPMResult<Vec<String>> {
+		Ok(self.lines.lock()?.clone())
+	}
 }
 
 impl Default for InMemoryWriter {
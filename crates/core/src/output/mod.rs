@@ -30,6 +30,8 @@ impl<W: io::Write + Send> OutputWriter for Mutex<W> {
 	fn write(&self, message: &dyn fmt::Display) -> PPMResult<()> {
 		let mut writer = self.lock()?;
 		write!(writer, "[ppm] {}", message)?;
+		// Partial lines (e.g. prompts) must be visible before reading input
+		writer.flush()?;
 		Ok(())
 	}
 
@@ -0,0 +1,67 @@
+use std::io::{self, BufRead, IsTerminal};
+use std::sync::Arc;
+
+use crate::errors::PPMResult;
+use crate::output::OutputWriter;
+
+/// Abstraction for asking the user yes/no questions.
+///
+/// Enables testing by allowing implementations that answer without a terminal.
+pub trait Prompt: Send + Sync {
+	/// Asks `question` and returns whether the user agreed.
+	fn confirm(&self, question: &str) -> PPMResult<bool>;
+}
+
+// --------------------------------------------------------------------------------
+// Concrete Implementations
+// --------------------------------------------------------------------------------
+
+/// Prompt that asks on the output writer and reads the answer from stdin.
+///
+/// When stdin is not a terminal (pipes, scripts), nothing is asked and the answer is no.
+pub struct TerminalPrompt {
+	output_writer: Arc<dyn OutputWriter>,
+}
+
+impl TerminalPrompt {
+	pub fn new(output_writer: Arc<dyn OutputWriter>) -> Self {
+		Self {
+			output_writer,
+		}
+	}
+}
+
+impl Prompt for TerminalPrompt {
+	fn confirm(&self, question: &str) -> PPMResult<bool> {
+		let stdin = io::stdin();
+		if !stdin.is_terminal() {
+			return Ok(false);
+		}
+
+		self.output_writer.write(&format!("{} [y/N] ", question))?;
+
+		let mut answer = String::new();
+		stdin.lock().read_line(&mut answer)?;
+
+		Ok(matches!(answer.trim().to_lowercase().as_str(), "y" | "yes"))
+	}
+}
+
+/// Prompt that gives the same answer to every question, for tests and scripts.
+pub struct FixedPrompt {
+	answer: bool,
+}
+
+impl FixedPrompt {
+	pub fn new(answer: bool) -> Self {
+		Self {
+			answer,
+		}
+	}
+}
+
+impl Prompt for FixedPrompt {
+	fn confirm(&self, _question: &str) -> PPMResult<bool> {
+		Ok(self.answer)
+	}
+}
@@ -0,0 +1,72 @@
+//! Small query languages for filtering stored items.
+//!
+//! Queries are parsed into typed filters that can be evaluated without a repository, so tools
+//! built on `ppm_core` can reuse them directly.
+
+pub mod task;
+
+use chrono::{Datelike, Days, NaiveDate, Weekday};
+pub use task::{DateComparison, StatusFilter, TaskPredicate, TaskQuery};
+
+use crate::errors::{PPMError, PPMResult};
+
+/// Parses a date relative to `today`.
+///
+/// Accepts `YYYY-MM-DD`, `today`, `tomorrow`, `yesterday` and weekday names (`fri`, `friday`),
+/// which refer to the next such day on or after today.
+pub fn parse_date(input: &str, today: NaiveDate) -> PPMResult<NaiveDate> {
+	let input = input.trim().to_lowercase();
+
+	match input.as_str() {
+		"today" => return Ok(today),
+		"tomorrow" => return Ok(today + Days::new(1)),
+		"yesterday" => return Ok(today - Days::new(1)),
+		_ => {}
+	}
+
+	if let Ok(weekday) = input.parse::<Weekday>() {
+		let days_ahead =
+			(7 + weekday.num_days_from_monday() - today.weekday().num_days_from_monday()) % 7;
+		return Ok(today + Days::new(u64::from(days_ahead)));
+	}
+
+	NaiveDate::parse_from_str(&input, "%Y-%m-%d").map_err(|_| {
+		PPMError::InvalidInput(format!(
+			"Invalid date '{}' (expected YYYY-MM-DD, today, tomorrow, yesterday or a weekday)",
+			input
+		))
+	})
+}
+
+/// Splits a query into terms on whitespace, keeping double-quoted phrases together.
+///
+/// Quotes are kept on the resulting terms so callers can tell phrases from keywords.
+fn tokenize(input: &str) -> PPMResult<Vec<String>> {
+	let mut terms = Vec::new();
+	let mut current = String::new();
+	let mut in_quotes = false;
+
+	for c in input.chars() {
+		match c {
+			'"' => {
+				in_quotes = !in_quotes;
+				current.push(c);
+			}
+			c if c.is_whitespace() && !in_quotes => {
+				if !current.is_empty() {
+					terms.push(std::mem::take(&mut current));
+				}
+			}
+			c => current.push(c),
+		}
+	}
+
+	if in_quotes {
+		return Err(PPMError::InvalidInput(format!("Unterminated quote in query '{}'", input)));
+	}
+	if !current.is_empty() {
+		terms.push(current);
+	}
+
+	Ok(terms)
+}
@@ -0,0 +1,285 @@
+use chrono::NaiveDate;
+
+use crate::errors::{PPMError, PPMResult};
+use crate::models::{ProjectName, Task, TaskStatus};
+use crate::query::{parse_date, tokenize};
+
+/// Typed filter over tasks.
+///
+/// A task matches when it satisfies every predicate; an empty query matches all tasks.
+///
+/// Textual form, e.g. `project:api status:pending tag:bug due<fri "login"`:
+/// - `project:<name>` (including its subprojects) / `project:none`
+/// - `status:pending|done|canceled`
+/// - `tag:<tag>`
+/// - `due<date`, `due<=date`, `due:date`, `due>=date`, `due>date`, `due:none`
+/// - bare words and `"quoted phrases"` match the description (case-insensitive)
+/// - a leading `-` negates any term, e.g. `-tag:later`
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TaskQuery {
+	pub predicates: Vec<TaskPredicate>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TaskPredicate {
+	/// Tasks of the given project or its subprojects, or tasks without a project for `None`.
+	Project(Option<ProjectName>),
+	Status(StatusFilter),
+	Tag(String),
+	Due(DateComparison, NaiveDate),
+	NoDue,
+	/// Case-insensitive substring of the description.
+	Text(String),
+	Not(Box<TaskPredicate>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatusFilter {
+	Pending,
+	Done,
+	Canceled,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DateComparison {
+	Before,
+	OnOrBefore,
+	On,
+	OnOrAfter,
+	After,
+}
+
+impl TaskQuery {
+	/// A query matching every task.
+	pub fn all() -> Self {
+		Self::default()
+	}
+
+	/// Adds a predicate that tasks must also satisfy.
+	pub fn with(mut self, predicate: TaskPredicate) -> Self {
+		self.predicates.push(predicate);
+		self
+	}
+
+	/// Parses the textual query language. Relative dates are resolved against `today`.
+	pub fn parse(input: &str, today: NaiveDate) -> PPMResult<Self> {
+		let predicates = tokenize(input)?
+			.iter()
+			.map(|term| parse_term(term, today))
+			.collect::<PPMResult<Vec<_>>>()?;
+
+		Ok(Self {
+			predicates,
+		})
+	}
+
+	pub fn matches(&self, task: &Task) -> bool {
+		self.predicates.iter().all(|p| p.matches(task))
+	}
+
+	/// Keeps only the matching tasks, preserving their order.
+	pub fn apply(&self, tasks: impl IntoIterator<Item = Task>) -> Vec<Task> {
+		tasks.into_iter().filter(|t| self.matches(t)).collect()
+	}
+}
+
+impl TaskPredicate {
+	pub fn matches(&self, task: &Task) -> bool {
+		match self {
+			TaskPredicate::Project(Some(project)) => {
+				task.project_name.as_ref().is_some_and(|name| project.includes(name))
+			}
+			TaskPredicate::Project(None) => task.project_name.is_none(),
+			TaskPredicate::Status(status) => status.matches(&task.status),
+			TaskPredicate::Tag(tag) => task.tags.iter().any(|t| t.eq_ignore_ascii_case(tag)),
+			TaskPredicate::Due(comparison, date) => {
+				task.due.is_some_and(|due| comparison.holds(due, *date))
+			}
+			TaskPredicate::NoDue => task.due.is_none(),
+			TaskPredicate::Text(text) => {
+				task.description.to_lowercase().contains(&text.to_lowercase())
+			}
+			TaskPredicate::Not(inner) => !inner.matches(task),
+		}
+	}
+}
+
+impl StatusFilter {
+	pub fn matches(&self, status: &TaskStatus) -> bool {
+		matches!(
+			(self, status),
+			(StatusFilter::Pending, TaskStatus::Pending)
+				| (StatusFilter::Done, TaskStatus::Done(_))
+				| (StatusFilter::Canceled, TaskStatus::Canceled(_))
+		)
+	}
+}
+
+impl DateComparison {
+	/// Whether `value` compares to `reference` as described.
+	pub fn holds(&self, value: NaiveDate, reference: NaiveDate) -> bool {
+		match self {
+			DateComparison::Before => value < reference,
+			DateComparison::OnOrBefore => value <= reference,
+			DateComparison::On => value == reference,
+			DateComparison::OnOrAfter => value >= reference,
+			DateComparison::After => value > reference,
+		}
+	}
+}
+
+fn parse_term(term: &str, today: NaiveDate) -> PPMResult<TaskPredicate> {
+	if let Some(negated) = term.strip_prefix('-').filter(|rest| !rest.is_empty()) {
+		return Ok(TaskPredicate::Not(Box::new(parse_term(negated, today)?)));
+	}
+
+	if let Some(phrase) = term.strip_prefix('"').and_then(|t| t.strip_suffix('"')) {
+		return Ok(TaskPredicate::Text(phrase.to_string()));
+	}
+
+	let Some(split) = term.find([':', '<', '>']) else {
+		return Ok(TaskPredicate::Text(term.to_string()));
+	};
+	let (key, rest) = term.split_at(split);
+	let (operator, value) = split_operator(rest);
+	let value = value.trim_matches('"');
+
+	if value.is_empty() {
+		return Err(PPMError::InvalidInput(format!("Missing value in query term '{}'", term)));
+	}
+
+	match (key.to_lowercase().as_str(), operator) {
+		("project", Operator::Colon) => Ok(TaskPredicate::Project(
+			(!value.eq_ignore_ascii_case("none")).then(|| ProjectName::from(value)),
+		)),
+		("status", Operator::Colon) => parse_status(value).map(TaskPredicate::Status),
+		("tag", Operator::Colon) => Ok(TaskPredicate::Tag(value.to_lowercase())),
+		("due", Operator::Colon) if value.eq_ignore_ascii_case("none") => Ok(TaskPredicate::NoDue),
+		("due", operator) => {
+			Ok(TaskPredicate::Due(operator.as_date_comparison(), parse_date(value, today)?))
+		}
+		_ => Err(PPMError::InvalidInput(format!(
+			"Unknown query term '{}' (quote it to search the description)",
+			term
+		))),
+	}
+}
+
+/// Operator between a query key and its value.
+#[derive(Clone, Copy)]
+enum Operator {
+	Colon,
+	Less,
+	LessOrEqual,
+	Greater,
+	GreaterOrEqual,
+}
+
+impl Operator {
+	fn as_date_comparison(self) -> DateComparison {
+		match self {
+			Operator::Colon => DateComparison::On,
+			Operator::Less => DateComparison::Before,
+			Operator::LessOrEqual => DateComparison::OnOrBefore,
+			Operator::Greater => DateComparison::After,
+			Operator::GreaterOrEqual => DateComparison::OnOrAfter,
+		}
+	}
+}
+
+/// Splits `:value`, `<value`, `<=value`, `>value` or `>=value` into its operator and value.
+fn split_operator(rest: &str) -> (Operator, &str) {
+	if let Some(value) = rest.strip_prefix("<=") {
+		(Operator::LessOrEqual, value)
+	} else if let Some(value) = rest.strip_prefix(">=") {
+		(Operator::GreaterOrEqual, value)
+	} else if let Some(value) = rest.strip_prefix('<') {
+		(Operator::Less, value)
+	} else if let Some(value) = rest.strip_prefix('>') {
+		(Operator::Greater, value)
+	} else {
+		(Operator::Colon, &rest[1..])
+	}
+}
+
+fn parse_status(value: &str) -> PPMResult<StatusFilter> {
+	match value.to_lowercase().as_str() {
+		"pending" => Ok(StatusFilter::Pending),
+		"done" => Ok(StatusFilter::Done),
+		"canceled" | "cancelled" => Ok(StatusFilter::Canceled),
+		_ => Err(PPMError::InvalidInput(format!(
+			"Unknown status '{}' (expected pending, done or canceled)",
+			value
+		))),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_support::task;
+
+	// 2026-01-07 is a Wednesday
+	fn today() -> NaiveDate {
+		NaiveDate::from_ymd_opt(2026, 1, 7).unwrap()
+	}
+
+	fn parse(input: &str) -> TaskQuery {
+		TaskQuery::parse(input, today()).unwrap()
+	}
+
+	#[test]
+	fn parses_terms() {
+		let friday = NaiveDate::from_ymd_opt(2026, 1, 9).unwrap();
+		assert_eq!(
+			parse(r#"project:api status:pending tag:Bug due<fri "log in" -tag:later"#),
+			TaskQuery::all()
+				.with(TaskPredicate::Project(Some(ProjectName::from("api"))))
+				.with(TaskPredicate::Status(StatusFilter::Pending))
+				.with(TaskPredicate::Tag("bug".to_string()))
+				.with(TaskPredicate::Due(DateComparison::Before, friday))
+				.with(TaskPredicate::Text("log in".to_string()))
+				.with(TaskPredicate::Not(Box::new(TaskPredicate::Tag("later".to_string()))))
+		);
+		assert_eq!(
+			parse("due<=today due>=2026-01-01 due>tomorrow due:none project:none"),
+			TaskQuery::all()
+				.with(TaskPredicate::Due(DateComparison::OnOrBefore, today()))
+				.with(TaskPredicate::Due(
+					DateComparison::OnOrAfter,
+					NaiveDate::from_ymd_opt(2026, 1, 1).unwrap()
+				))
+				.with(TaskPredicate::Due(DateComparison::After, today() + chrono::Days::new(1)))
+				.with(TaskPredicate::NoDue)
+				.with(TaskPredicate::Project(None))
+		);
+		assert_eq!(parse(""), TaskQuery::all());
+	}
+
+	#[test]
+	fn rejects_bad_terms() {
+		for input in ["status:open", "due<someday", "tag:", "owner:me", "\"unterminated"] {
+			assert!(TaskQuery::parse(input, today()).is_err(), "{}", input);
+		}
+	}
+
+	#[test]
+	fn matches_tasks() {
+		let api = Task {
+			project_name: Some(ProjectName::from("api/auth")),
+			tags: vec!["bug".to_string()],
+			due: Some(today()),
+			..task("Fix Login redirect")
+		};
+		let other = task("Write docs");
+
+		let query = parse("project:api tag:BUG due<=today login");
+		assert!(query.matches(&api));
+		assert!(!query.matches(&other));
+		assert!(parse("-project:api due:none").matches(&other));
+		assert!(!parse("due<today").matches(&api));
+		let found = parse("status:pending docs").apply([api, other.clone()]);
+		assert_eq!(found.len(), 1);
+		assert_eq!(found[0].id, other.id);
+	}
+}
@@ -0,0 +1,31 @@
+use std::collections::HashSet;
+use std::hash::Hash;
+
+use crate::models::ModelId;
+
+/// Gives every item whose ID already appeared earlier in `items` a freshly generated ID.
+///
+/// IDs used to be plain microsecond timestamps, so data written before the switch to ULIDs
+/// may contain collisions. The first item with a given ID keeps it, which leaves every
+/// existing ID valid. Returns the position and former ID of every reassigned item.
+pub(crate) fn reassign_duplicate_ids<T, I>(
+	items: &mut [T],
+	id_of: impl Fn(&mut T) -> &mut I,
+) -> Vec<(usize, I)>
+where
+	I: ModelId + Eq + Hash + Default,
+{
+	let mut seen = HashSet::new();
+	let mut reassigned = Vec::new();
+
+	for (position, item) in items.iter_mut().enumerate() {
+		let id = id_of(item);
+		if !seen.insert(id.clone()) {
+			let former = std::mem::take(id);
+			seen.insert(id.clone());
+			reassigned.push((position, former));
+		}
+	}
+
+	reassigned
+}
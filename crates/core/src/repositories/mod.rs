@@ -1,9 +1,14 @@
+pub(crate) mod migration;
 pub mod note;
 pub mod project;
 pub mod session;
 pub mod task;
+pub mod template;
+pub mod trash;
 
 pub use note::{InMemoryNoteRepository, LocalNoteRepository, NoteRepository};
 pub use project::{InMemoryProjectRepository, LocalProjectRepository, ProjectRepository};
 pub use session::{InMemorySessionRepository, LocalSessionRepository, SessionRepository};
 pub use task::{InMemoryTaskRepository, LocalTaskRepository, TaskRepository};
+pub use template::{InMemoryTemplateRepository, LocalTemplateRepository, TemplateRepository};
+pub use trash::{InMemoryTrashRepository, LocalTrashRepository, TrashRepository};
@@ -26,6 +26,11 @@ impl Default for InMemoryNoteRepository {
 impl NoteRepository for InMemoryNoteRepository {
 	fn create_note(&self, note: Note) -> PPMResult<()> {
 		let mut notes = self.notes.lock()?;
+
+		if notes.iter().any(|n| n.id == note.id) {
+			return Err(PPMError::AlreadyExists(format!("Note {} already exists", note.id)));
+		}
+
 		notes.push(note);
 		Ok(())
 	}
@@ -48,6 +53,17 @@ impl NoteRepository for InMemoryNoteRepository {
 			.collect())
 	}
 
+	fn update_note(&self, note: Note) -> PPMResult<()> {
+		let mut notes = self.notes.lock()?;
+
+		if let Some(existing) = notes.iter_mut().find(|n| n.id == note.id) {
+			*existing = note;
+			Ok(())
+		} else {
+			Err(PPMError::NotFound(format!("Note {} not found", note.id)))
+		}
+	}
+
 	fn delete_note(&self, note_id: &NoteId) -> PPMResult<()> {
 		let mut notes = self.notes.lock()?;
 		let initial_len = notes.len();
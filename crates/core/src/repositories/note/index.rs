@@ -0,0 +1,329 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use crate::errors::PPMResult;
+use crate::models::{Note, NoteId};
+
+/// Name of the index file kept next to the notes.
+pub const INDEX_FILE_NAME: &str = ".index.json";
+
+/// Bumped whenever the stored layout changes, so that old indexes are rebuilt.
+const INDEX_VERSION: u32 = 3;
+
+/// Persistent inverted index over the markdown files of a notes directory.
+///
+/// Each file is keyed by name together with its modification time and a hash of its
+/// content. [`NoteIndex::refresh`] only re-reads files whose modification time changed and
+/// only re-parses those whose content hash changed, so listing thousands of notes costs one
+/// directory scan. Files that are not valid notes are remembered the same way, so they are not
+/// re-read until they change. The postings map every word of a note's searchable text (its body and
+/// front matter values) to the files containing it.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct NoteIndex {
+	version: u32,
+	files: BTreeMap<String, IndexedFile>,
+	postings: BTreeMap<String, BTreeSet<String>>,
+	#[serde(skip)]
+	dirty: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct IndexedFile {
+	modified: (u64, u32),
+	hash: u64,
+	/// `None` for files that could not be parsed as notes.
+	note: Option<Note>,
+}
+
+impl NoteIndex {
+	/// Loads the index stored in `notes_dir`, or starts an empty one when it is missing,
+	/// corrupt or from another version.
+	pub fn load(notes_dir: &Path) -> Self {
+		fs::read_to_string(notes_dir.join(INDEX_FILE_NAME))
+			.ok()
+			.and_then(|content| serde_json::from_str::<Self>(&content).ok())
+			.filter(|index| index.version == INDEX_VERSION)
+			.unwrap_or_else(|| Self {
+				version: INDEX_VERSION,
+				dirty: true,
+				..Self::default()
+			})
+	}
+
+	/// Writes the index back to `notes_dir` if it changed since it was loaded.
+	pub fn save(&mut self, notes_dir: &Path) -> PPMResult<()> {
+		if !self.dirty || !notes_dir.exists() {
+			return Ok(());
+		}
+
+		// Write to a temporary file first so a crash never leaves a truncated index. The name
+		// is unique so that concurrent runs never write into each other's file.
+		let path = notes_dir.join(INDEX_FILE_NAME);
+		let nanos = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_nanos();
+		let temp_path =
+			notes_dir.join(format!("{}.{}-{}.tmp", INDEX_FILE_NAME, std::process::id(), nanos));
+		fs::write(&temp_path, serde_json::to_string(self)?)?;
+		fs::rename(&temp_path, &path)?;
+
+		self.dirty = false;
+		Ok(())
+	}
+
+	/// Brings the index in line with the markdown files currently in `notes_dir`.
+	///
+	/// Files that cannot be parsed as notes are left out of the notes and search results, as
+	/// [`Note::from_markdown`] would reject them anyway.
+	pub fn refresh(&mut self, notes_dir: &Path) -> PPMResult<()> {
+		let mut present = BTreeSet::new();
+
+		if notes_dir.exists() {
+			for entry in fs::read_dir(notes_dir)? {
+				let path = entry?.path();
+				if path.extension().and_then(|s| s.to_str()) != Some("md") {
+					continue;
+				}
+				let Some(file_name) = file_name(&path) else {
+					continue;
+				};
+
+				present.insert(file_name.clone());
+				self.refresh_file(&path, file_name)?;
+			}
+		}
+
+		let removed: Vec<String> =
+			self.files.keys().filter(|name| !present.contains(*name)).cloned().collect();
+		for file_name in removed {
+			self.remove(&file_name);
+		}
+
+		Ok(())
+	}
+
+	/// Re-indexes a single file if its modification time or content changed.
+	pub fn refresh_file(&mut self, path: &Path, file_name: String) -> PPMResult<()> {
+		let modified = modified_time(path)?;
+		if self.files.get(&file_name).is_some_and(|f| f.modified == modified) {
+			return Ok(());
+		}
+
+		let content = fs::read_to_string(path)?;
+		let hash = fnv1a(content.as_bytes());
+
+		if let Some(indexed) = self.files.get_mut(&file_name)
+			&& indexed.hash == hash
+		{
+			indexed.modified = modified;
+			self.dirty = true;
+			return Ok(());
+		}
+
+		self.remove(&file_name);
+		let note = Note::from_markdown(&content).ok();
+		if let Some(ref note) = note {
+			for word in words(&note.searchable_text()) {
+				self.postings.entry(word).or_default().insert(file_name.clone());
+			}
+		}
+		self.files.insert(
+			file_name,
+			IndexedFile {
+				modified,
+				hash,
+				note,
+			},
+		);
+		self.dirty = true;
+
+		Ok(())
+	}
+
+	/// Drops a file and its postings from the index.
+	pub fn remove(&mut self, file_name: &str) {
+		let Some(indexed) = self.files.remove(file_name) else {
+			return;
+		};
+		self.dirty = true;
+		let Some(note) = indexed.note else {
+			return;
+		};
+
+		for word in words(&note.searchable_text()) {
+			if let Some(files) = self.postings.get_mut(&word) {
+				files.remove(file_name);
+				if files.is_empty() {
+					self.postings.remove(&word);
+				}
+			}
+		}
+	}
+
+	/// Name of the file holding the note with `note_id`.
+	pub fn file_of(&self, note_id: &NoteId) -> Option<&str> {
+		self.files
+			.iter()
+			.find(|(_, f)| f.note.as_ref().is_some_and(|n| &n.id == note_id))
+			.map(|(name, _)| name.as_str())
+	}
+
+	pub fn notes(&self) -> impl Iterator<Item = &Note> {
+		self.files.values().filter_map(|f| f.note.as_ref())
+	}
+
+	/// Notes whose searchable text contains every (lowercase) term.
+	///
+	/// The postings narrow the candidates down to files having, for each word of each term,
+	/// some indexed word containing it; the candidates are then checked against the full term.
+	pub fn search(&self, terms: &[String]) -> Vec<&Note> {
+		let mut candidates: Option<BTreeSet<&String>> = None;
+
+		for word in terms.iter().flat_map(|term| words(term)) {
+			let files: BTreeSet<&String> = self
+				.postings
+				.iter()
+				.filter(|(indexed, _)| indexed.contains(&word))
+				.flat_map(|(_, files)| files)
+				.collect();
+
+			candidates = Some(match candidates {
+				Some(current) => current.intersection(&files).copied().collect(),
+				None => files,
+			});
+		}
+
+		let candidates: Vec<&IndexedFile> = match candidates {
+			Some(names) => names.into_iter().filter_map(|name| self.files.get(name)).collect(),
+			None => self.files.values().collect(),
+		};
+
+		candidates
+			.into_iter()
+			.filter_map(|f| f.note.as_ref())
+			.filter(|note| {
+				let document = note.searchable_text().to_lowercase();
+				terms.iter().all(|term| document.contains(term.as_str()))
+			})
+			.collect()
+	}
+}
+
+pub fn file_name(path: &Path) -> Option<String> {
+	path.file_name().and_then(|s| s.to_str()).map(str::to_string)
+}
+
+fn modified_time(path: &Path) -> PPMResult<(u64, u32)> {
+	let modified = fs::metadata(path)?.modified()?;
+	let since_epoch = modified.duration_since(UNIX_EPOCH).unwrap_or_default();
+	Ok((since_epoch.as_secs(), since_epoch.subsec_nanos()))
+}
+
+/// Lowercase alphanumeric words of `text`.
+fn words(text: &str) -> BTreeSet<String> {
+	text.split(|c: char| !c.is_alphanumeric())
+		.filter(|w| !w.is_empty())
+		.map(|w| w.to_lowercase())
+		.collect()
+}
+
+/// 64-bit FNV-1a hash, stable across runs and platforms.
+fn fnv1a(bytes: &[u8]) -> u64 {
+	const OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+	const PRIME: u64 = 0x100000001b3;
+
+	bytes.iter().fold(OFFSET_BASIS, |hash, byte| (hash ^ u64::from(*byte)).wrapping_mul(PRIME))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_support::{TempDir, note};
+
+	fn write_note(dir: &Path, file_name: &str, note: &Note) {
+		fs::write(dir.join(file_name), note.to_markdown()).unwrap();
+	}
+
+	fn refreshed(dir: &Path) -> NoteIndex {
+		let mut index = NoteIndex::load(dir);
+		index.refresh(dir).unwrap();
+		index.save(dir).unwrap();
+		index
+	}
+
+	fn contents(notes: Vec<&Note>) -> Vec<String> {
+		notes.iter().map(|n| n.content.clone()).collect()
+	}
+
+	#[test]
+	fn refresh_follows_changes_and_removals() {
+		let dir = TempDir::new();
+		let first = note("Rust borrow checker");
+		write_note(dir.path(), "a.md", &first);
+		write_note(dir.path(), "b.md", &note("Gardening plans"));
+
+		let index = refreshed(dir.path());
+		assert_eq!(index.notes().count(), 2);
+		assert_eq!(contents(index.search(&["borrow".to_string()])), vec!["Rust borrow checker"]);
+		assert_eq!(index.file_of(&first.id), Some("a.md"));
+
+		// Rewrite a.md with new content; the mtime changes with the content
+		std::thread::sleep(std::time::Duration::from_millis(20));
+		let edited = Note {
+			content: "Rust lifetimes".to_string(),
+			..first
+		};
+		write_note(dir.path(), "a.md", &edited);
+		fs::remove_file(dir.path().join("b.md")).unwrap();
+
+		let index = refreshed(dir.path());
+		assert_eq!(index.notes().count(), 1);
+		assert!(index.search(&["borrow".to_string()]).is_empty());
+		assert!(index.search(&["gardening".to_string()]).is_empty());
+		assert_eq!(contents(index.search(&["lifetimes".to_string()])), vec!["Rust lifetimes"]);
+		assert!(!index.postings.contains_key("borrow"));
+	}
+
+	#[test]
+	fn unparseable_files_are_remembered() {
+		let dir = TempDir::new();
+		fs::write(dir.path().join("plain.md"), "No front matter here").unwrap();
+		write_note(dir.path(), "a.md", &note("Valid"));
+
+		let mut index = refreshed(dir.path());
+		assert_eq!(index.notes().count(), 1);
+		assert!(index.files.get("plain.md").is_some_and(|f| f.note.is_none()));
+		assert!(index.search(&["front".to_string()]).is_empty());
+
+		// Unchanged, it is not re-read and the index stays clean
+		let mut reloaded = NoteIndex::load(dir.path());
+		reloaded.refresh(dir.path()).unwrap();
+		assert!(!reloaded.dirty);
+
+		// Once removed it is dropped from the index
+		fs::remove_file(dir.path().join("plain.md")).unwrap();
+		index.refresh(dir.path()).unwrap();
+		assert!(!index.files.contains_key("plain.md"));
+	}
+
+	#[test]
+	fn corrupt_index_is_rebuilt() {
+		let dir = TempDir::new();
+		write_note(dir.path(), "a.md", &note("Rebuilt from scratch"));
+		fs::write(dir.path().join(INDEX_FILE_NAME), "{ not json").unwrap();
+
+		let index = refreshed(dir.path());
+		assert_eq!(contents(index.search(&["scratch".to_string()])), vec!["Rebuilt from scratch"]);
+
+		let stored = fs::read_to_string(dir.path().join(INDEX_FILE_NAME)).unwrap();
+		assert_eq!(serde_json::from_str::<NoteIndex>(&stored).unwrap().version, INDEX_VERSION);
+		let leftovers: Vec<_> = fs::read_dir(dir.path())
+			.unwrap()
+			.filter_map(|e| file_name(&e.unwrap().path()))
+			.filter(|name| name.ends_with(".tmp"))
+			.collect();
+		assert!(leftovers.is_empty());
+	}
+}
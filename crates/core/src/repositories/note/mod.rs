@@ -1,4 +1,5 @@
 pub mod in_memory;
+mod index;
 pub mod repository;
 
 pub use in_memory::InMemoryNoteRepository;
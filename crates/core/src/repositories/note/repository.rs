@@ -1,10 +1,12 @@
 use std::fs;
-use std::path::PathBuf;
-
-use chrono::{DateTime, Utc};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
 
+use crate::clock::{Clock, SystemClock};
+use crate::config::NoteFilenames;
 use crate::errors::{PPMError, PPMResult};
-use crate::models::{Note, NoteId, ProjectName};
+use crate::models::{ModelId, Note, NoteId, ProjectName};
+use crate::repositories::note::index::{self, NoteIndex};
 
 /// Data access abstraction for notes.
 pub trait NoteRepository: Send + Sync {
@@ -12,7 +14,30 @@ pub trait NoteRepository: Send + Sync {
 	fn get_note(&self, note_id: &NoteId) -> PPMResult<Option<Note>>;
 	fn list_notes(&self) -> PPMResult<Vec<Note>>;
 	fn list_notes_by_project(&self, project_name: &ProjectName) -> PPMResult<Vec<Note>>;
+	/// Replaces the stored note that has the same ID as `note`.
+	fn update_note(&self, note: Note) -> PPMResult<()>;
 	fn delete_note(&self, note_id: &NoteId) -> PPMResult<()>;
+
+	/// Lists the notes whose body or front matter values contain every term, ignoring case.
+	fn search_notes(&self, terms: &[String]) -> PPMResult<Vec<Note>> {
+		let terms: Vec<String> = terms.iter().map(|t| t.to_lowercase()).collect();
+		Ok(self
+			.list_notes()?
+			.into_iter()
+			.filter(|note| {
+				let document = note.searchable_text().to_lowercase();
+				terms.iter().all(|term| document.contains(term))
+			})
+			.collect())
+	}
+
+	/// Resolves a full ID, sequence number or unique prefix/suffix to a stored note ID.
+	fn resolve_note_id(&self, input: &str) -> PPMResult<NoteId> {
+		let mut notes = self.list_notes()?;
+		notes.sort_by_key(|n| n.created_at);
+		let ids: Vec<NoteId> = notes.into_iter().map(|n| n.id).collect();
+		NoteId::resolve(input, &ids)
+	}
 }
 
 // --------------------------------------------------------------------------------
@@ -21,27 +46,48 @@ pub trait NoteRepository: Send + Sync {
 
 /// Markdown-based note repository storing each note as a separate file.
 ///
-/// Each note is stored as `{notes_dir}/{note_id}.md` with YAML front matter:
+/// Each note is stored as `{notes_dir}/{note_id}.md`, or `{notes_dir}/YYYY-MM-DD-slug.md` with
+/// [`NoteFilenames::Slug`], with YAML front matter (see
+/// [`NoteFrontMatter`](crate::models::NoteFrontMatter)):
 /// ```markdown
 /// ---
-/// id: note_1234567890
-/// project: my-project
+/// id: note_01JF3Q8ZK6W9V2X4T7N5B1C0DE
+/// title: Meeting notes
 /// created_at: 2025-12-13T10:30:00Z
+/// updated_at: 2025-12-14T08:00:00Z
+/// project: my-project
 /// ---
 ///
 /// # Note content in markdown
 /// ```
+///
+/// Notes are looked up by the `id` in their front matter, so files can be named freely; a
+/// file keeps its name when the note is updated. Listing and searching go through an
+/// inverted index stored as `{notes_dir}/.index.json`, which is refreshed from the files on
+/// every read and rebuilt when missing or corrupt.
 pub struct LocalNoteRepository {
 	notes_dir: PathBuf,
+	filenames: NoteFilenames,
+	/// Only used for the local date of a note's creation in slug filenames
+	clock: Arc<dyn Clock>,
 }
 
 impl LocalNoteRepository {
 	pub fn new(notes_dir: PathBuf) -> Self {
 		Self {
 			notes_dir,
+			filenames: NoteFilenames::default(),
+			clock: Arc::new(SystemClock::new()),
 		}
 	}
 
+	/// Sets how files of new notes are named, with `clock` giving the local date for slugs.
+	pub fn with_filenames(mut self, filenames: NoteFilenames, clock: Arc<dyn Clock>) -> Self {
+		self.filenames = filenames;
+		self.clock = clock;
+		self
+	}
+
 	fn ensure_notes_dir(&self) -> PPMResult<()> {
 		fs::create_dir_all(&self.notes_dir)?;
 		Ok(())
@@ -51,81 +97,60 @@ impl LocalNoteRepository {
 		self.notes_dir.join(format!("{}.md", note_id.as_ref()))
 	}
 
-	fn parse_note_file(&self, content: &str) -> PPMResult<Note> {
-		// Split front matter and body
-		let parts: Vec<&str> = content.splitn(3, "---").collect();
+	/// Path of the file storing the note with `note_id`, wherever it is.
+	fn find_note_file(&self, note_id: &NoteId) -> PPMResult<Option<PathBuf>> {
+		let file_path = self.note_file_path(note_id);
+		if file_path.exists() {
+			return Ok(Some(file_path));
+		}
 
-		if parts.len() < 3 {
-			return Err(PPMError::IoError(std::io::Error::other(
-				"Invalid note format: missing front matter",
-			)));
+		self.with_index(|index| index.file_of(note_id).map(|name| self.notes_dir.join(name)))
+	}
+
+	/// Path for a new note's file, following the configured naming scheme.
+	fn new_file_path(&self, note: &Note) -> PathBuf {
+		if self.filenames == NoteFilenames::Id {
+			return self.note_file_path(&note.id);
 		}
 
-		let front_matter = parts[1].trim();
-		let body = parts[2].trim();
-
-		// Parse YAML front matter manually (simple key: value format)
-		let mut id: Option<NoteId> = None;
-		let mut project: Option<ProjectName> = None;
-		let mut created_at: Option<DateTime<Utc>> = None;
-
-		for line in front_matter.lines() {
-			let line = line.trim();
-			if let Some((key, value)) = line.split_once(':') {
-				let key = key.trim();
-				let value = value.trim();
-
-				match key {
-					"id" => id = Some(NoteId::from(value)),
-					"project" => {
-						if !value.is_empty() {
-							project = Some(ProjectName::from(value));
-						}
-					}
-					"created_at" => {
-						created_at = Some(
-							DateTime::parse_from_rfc3339(value)
-								.map_err(|e| {
-									PPMError::IoError(std::io::Error::other(format!(
-										"Failed to parse date: {}",
-										e
-									)))
-								})?
-								.with_timezone(&Utc),
-						);
-					}
-					_ => {} // Ignore unknown fields
-				}
-			}
+		let source = note.title.clone().unwrap_or_else(|| note.display_title());
+		let mut slug = slugify(&source);
+		if slug.is_empty() {
+			slug = note.id.to_string();
 		}
 
-		let id = id.ok_or_else(|| {
-			PPMError::IoError(std::io::Error::other("Missing 'id' in front matter"))
-		})?;
-		let created_at = created_at.ok_or_else(|| {
-			PPMError::IoError(std::io::Error::other("Missing 'created_at' in front matter"))
-		})?;
-
-		Ok(Note {
-			id,
-			project_name: project,
-			content: body.to_string(),
-			created_at,
-		})
+		let stem = format!("{}-{}", self.clock.local_date(note.created_at), slug);
+		let mut file_path = self.notes_dir.join(format!("{}.md", stem));
+		let mut suffix = 2;
+		while file_path.exists() {
+			file_path = self.notes_dir.join(format!("{}-{}.md", stem, suffix));
+			suffix += 1;
+		}
+
+		file_path
 	}
 
-	fn format_note_file(&self, note: &Note) -> String {
-		let mut front_matter =
-			format!("---\nid: {}\ncreated_at: {}\n", note.id, note.created_at.to_rfc3339());
+	/// Runs `f` against the index after bringing it up to date with `notes_dir`.
+	fn with_index<T>(&self, f: impl FnOnce(&NoteIndex) -> T) -> PPMResult<T> {
+		let mut index = NoteIndex::load(&self.notes_dir);
+		index.refresh(&self.notes_dir)?;
+		index.save(&self.notes_dir)?;
+		Ok(f(&index))
+	}
 
-		if let Some(ref project) = note.project_name {
-			front_matter.push_str(&format!("project: {}\n", project));
+	/// Records a write to, or removal of, a single note file in the index.
+	fn reindex(&self, file_path: &Path) -> PPMResult<()> {
+		let Some(file_name) = index::file_name(file_path) else {
+			return Ok(());
+		};
+
+		let mut index = NoteIndex::load(&self.notes_dir);
+		if file_path.exists() {
+			index.refresh_file(file_path, file_name)?;
+		} else {
+			index.remove(&file_name);
 		}
-
-		front_matter.push_str("---\n\n");
-		front_matter.push_str(&note.content);
-
-		front_matter
+		index.save(&self.notes_dir)
 	}
 }
 
@@ -133,63 +158,148 @@ impl NoteRepository for LocalNoteRepository {
 	fn create_note(&self, note: Note) -> PPMResult<()> {
 		self.ensure_notes_dir()?;
 
-		let file_path = self.note_file_path(&note.id);
-		let content = self.format_note_file(&note);
+		if self.find_note_file(&note.id)?.is_some() {
+			return Err(PPMError::AlreadyExists(format!("Note {} already exists", note.id)));
+		}
+
+		let file_path = self.new_file_path(&note);
+		let content = note.to_markdown();
 
 		fs::write(&file_path, content)?;
-		Ok(())
+		self.reindex(&file_path)
 	}
 
 	fn get_note(&self, note_id: &NoteId) -> PPMResult<Option<Note>> {
-		let file_path = self.note_file_path(note_id);
-
-		if !file_path.exists() {
+		let Some(file_path) = self.find_note_file(note_id)? else {
 			return Ok(None);
-		}
+		};
 
 		let content = fs::read_to_string(&file_path)?;
-		let note = self.parse_note_file(&content)?;
+		let note = Note::from_markdown(&content)?;
 		Ok(Some(note))
 	}
 
 	fn list_notes(&self) -> PPMResult<Vec<Note>> {
-		if !self.notes_dir.exists() {
-			return Ok(Vec::new());
-		}
-
-		let mut notes = Vec::new();
-
-		for entry in fs::read_dir(&self.notes_dir)? {
-			let entry = entry?;
-			let path = entry.path();
-
-			if path.extension().and_then(|s| s.to_str()) == Some("md") {
-				let content = fs::read_to_string(&path)?;
-				if let Ok(note) = self.parse_note_file(&content) {
-					notes.push(note);
-				}
-			}
-		}
+		let mut notes = self.with_index(|index| index.notes().cloned().collect::<Vec<_>>())?;
 
 		// Sort by created_at descending (newest first)
-		notes.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+		notes.sort_by_key(|n| std::cmp::Reverse(n.created_at));
 
 		Ok(notes)
 	}
 
 	fn list_notes_by_project(&self, project_name: &ProjectName) -> PPMResult<Vec<Note>> {
-		let notes = self.list_notes()?;
-		Ok(notes.into_iter().filter(|n| n.project_name.as_ref() == Some(project_name)).collect())
+		self.with_index(|index| {
+			index
+				.notes()
+				.filter(|n| n.project_name.as_ref() == Some(project_name))
+				.cloned()
+				.collect()
+		})
+	}
+
+	fn update_note(&self, note: Note) -> PPMResult<()> {
+		let file_path = self
+			.find_note_file(&note.id)?
+			.ok_or_else(|| PPMError::NotFound(format!("Note {} not found", note.id)))?;
+
+		fs::write(&file_path, note.to_markdown())?;
+		self.reindex(&file_path)
 	}
 
 	fn delete_note(&self, note_id: &NoteId) -> PPMResult<()> {
-		let file_path = self.note_file_path(note_id);
+		let file_path = self
+			.find_note_file(note_id)?
+			.ok_or_else(|| PPMError::NotFound(format!("Note {} not found", note_id)))?;
+
+		fs::remove_file(&file_path)?;
+		self.reindex(&file_path)
+	}
+
+	fn search_notes(&self, terms: &[String]) -> PPMResult<Vec<Note>> {
+		let terms: Vec<String> = terms.iter().map(|t| t.to_lowercase()).collect();
+		self.with_index(|index| index.search(&terms).into_iter().cloned().collect())
+	}
+}
+
+/// Longest slug, in characters, that `slugify` produces.
+const SLUG_MAX_CHARS: usize = 60;
+
+/// Lowercase words of `text` joined by `-`, cut to a readable length.
+fn slugify(text: &str) -> String {
+	let words: Vec<String> = text
+		.split(|c: char| !c.is_alphanumeric())
+		.filter(|w| !w.is_empty())
+		.map(str::to_lowercase)
+		.collect();
+
+	let mut slug = String::new();
+	for word in words {
+		if slug.is_empty() {
+			// A single long word is cut rather than dropped
+			slug = word.chars().take(SLUG_MAX_CHARS).collect();
+			continue;
+		}
+		if slug.chars().count() + word.chars().count() >= SLUG_MAX_CHARS {
+			break;
+		}
+		slug.push('-');
+		slug.push_str(&word);
+	}
+
+	slug
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::clock::FixedClock;
+	use crate::test_support::{TempDir, note, utc};
+
+	#[test]
+	fn slugify_joins_lowercase_words() {
+		assert_eq!(slugify("Meeting notes: Q3 plan!"), "meeting-notes-q3-plan");
+		assert_eq!(slugify("Café über alles"), "café-über-alles");
+		assert_eq!(slugify("  --- "), "");
+	}
 
-		if !file_path.exists() {
-			return Err(PPMError::NotFound(format!("Note {} not found", note_id)));
+	#[test]
+	fn slugify_caps_the_length() {
+		let title = "word ".repeat(30);
+		let slug = slugify(&title);
+		assert!(slug.chars().count() < SLUG_MAX_CHARS);
+		assert!(slug.starts_with("word-word") && slug.ends_with("word"));
+
+		let long_word = "é".repeat(100);
+		assert_eq!(slugify(&long_word), "é".repeat(SLUG_MAX_CHARS));
+		assert_eq!(slugify(&format!("{} next", long_word)), "é".repeat(SLUG_MAX_CHARS));
+	}
+
+	#[test]
+	fn slug_filenames_get_a_suffix_on_collision() {
+		let dir = TempDir::new();
+		let repository = LocalNoteRepository::new(dir.path().to_path_buf())
+			.with_filenames(NoteFilenames::Slug, Arc::new(FixedClock::new(utc(2026, 3, 1, 12, 0))));
+
+		let notes: Vec<Note> = (0..3)
+			.map(|_| Note {
+				title: Some("Weekly review".to_string()),
+				..note("Same title")
+			})
+			.collect();
+		for n in &notes {
+			repository.create_note(n.clone()).unwrap();
 		}
 
-		fs::remove_file(&file_path)?;
-		Ok(())
+		for name in [
+			"2026-01-01-weekly-review.md",
+			"2026-01-01-weekly-review-2.md",
+			"2026-01-01-weekly-review-3.md",
+		] {
+			assert!(dir.path().join(name).exists(), "{}", name);
+		}
+		for n in &notes {
+			assert_eq!(repository.get_note(&n.id).unwrap().unwrap().id, n.id);
+		}
 	}
 }
@@ -63,6 +63,24 @@ impl ProjectRepository for InMemoryProjectRepository {
 		}
 	}
 
+	fn update_project(&self, project_name: &ProjectName, project: Project) -> PPMResult<()> {
+		let mut projects = self.projects.lock()?;
+
+		if &project.name != project_name && projects.iter().any(|p| p.name == project.name) {
+			return Err(PPMError::AlreadyExists(format!(
+				"Project '{}' already exists",
+				project.name
+			)));
+		}
+
+		if let Some(existing) = projects.iter_mut().find(|p| &p.name == project_name) {
+			*existing = project;
+			Ok(())
+		} else {
+			Err(PPMError::NotFound(format!("Project '{}' not found", project_name)))
+		}
+	}
+
 	fn list_projects(&self) -> PPMResult<Vec<Project>> {
 		self.get_all_projects()
 	}
@@ -14,6 +14,8 @@ pub trait ProjectRepository: Send + Sync {
 		project_name: &ProjectName,
 		status: ProjectStatus,
 	) -> PPMResult<()>;
+	/// Replaces the project stored as `project_name` with `project`, which may carry a new name.
+	fn update_project(&self, project_name: &ProjectName, project: Project) -> PPMResult<()>;
 	fn list_projects(&self) -> PPMResult<Vec<Project>>;
 	fn delete_project(&self, project_name: &ProjectName) -> PPMResult<()>;
 }
@@ -108,6 +110,25 @@ impl ProjectRepository for LocalProjectRepository {
 		}
 	}
 
+	fn update_project(&self, project_name: &ProjectName, project: Project) -> PPMResult<()> {
+		let mut projects = self.load_projects()?;
+
+		if &project.name != project_name && projects.iter().any(|p| p.name == project.name) {
+			return Err(PPMError::AlreadyExists(format!(
+				"Project '{}' already exists",
+				project.name
+			)));
+		}
+
+		if let Some(existing) = projects.iter_mut().find(|p| &p.name == project_name) {
+			*existing = project;
+			self.save_projects(&projects)?;
+			Ok(())
+		} else {
+			Err(PPMError::NotFound(format!("Project '{}' not found", project_name)))
+		}
+	}
+
 	fn list_projects(&self) -> PPMResult<Vec<Project>> {
 		self.load_projects()
 	}
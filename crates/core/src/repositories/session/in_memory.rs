@@ -33,6 +33,11 @@ impl SessionRepository for InMemorySessionRepository {
 
 	fn create_session(&self, session: FocusSession) -> PPMResult<()> {
 		let mut sessions = self.sessions.lock()?;
+
+		if sessions.iter().any(|s| s.id == session.id) {
+			return Err(PPMError::AlreadyExists(format!("Session {} already exists", session.id)));
+		}
+
 		sessions.push(session);
 		Ok(())
 	}
@@ -68,4 +73,25 @@ impl SessionRepository for InMemorySessionRepository {
 	fn list_sessions(&self) -> PPMResult<Vec<FocusSession>> {
 		Ok(self.sessions.lock()?.clone())
 	}
+
+	fn update_sessions(&self, sessions: Vec<FocusSession>) -> PPMResult<()> {
+		let mut stored = self.sessions.lock()?;
+
+		if let Some(missing) = sessions.iter().find(|s| !stored.iter().any(|e| e.id == s.id)) {
+			return Err(PPMError::NotFound(format!("Session {} not found", missing.id)));
+		}
+
+		for session in sessions {
+			if let Some(existing) = stored.iter_mut().find(|e| e.id == session.id) {
+				*existing = session;
+			}
+		}
+
+		Ok(())
+	}
+
+	fn replace_sessions(&self, sessions: Vec<FocusSession>) -> PPMResult<()> {
+		*self.sessions.lock()? = sessions;
+		Ok(())
+	}
 }
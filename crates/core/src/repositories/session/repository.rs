@@ -5,7 +5,7 @@ use std::path::PathBuf;
 use chrono::{DateTime, Utc};
 
 use crate::errors::{PPMError, PPMResult};
-use crate::models::{FocusSession, FocusSessionId};
+use crate::models::{FocusSession, FocusSessionId, ModelId};
 
 /// Data access abstraction for focus sessions.
 ///
@@ -21,6 +21,19 @@ pub trait SessionRepository: Send + Sync {
 	) -> PPMResult<()>;
 	fn delete_session(&self, session_id: &FocusSessionId) -> PPMResult<()>;
 	fn list_sessions(&self) -> PPMResult<Vec<FocusSession>>;
+	/// Replaces the stored sessions with matching IDs in a single write. Fails without changes
+	/// if any is missing.
+	fn update_sessions(&self, sessions: Vec<FocusSession>) -> PPMResult<()>;
+	/// Replaces every stored session with `sessions`, in a single write.
+	fn replace_sessions(&self, sessions: Vec<FocusSession>) -> PPMResult<()>;
+
+	/// Resolves a full ID, sequence number or unique prefix/suffix to a stored session ID.
+	fn resolve_session_id(&self, input: &str) -> PPMResult<FocusSessionId> {
+		let mut sessions = self.list_sessions()?;
+		sessions.sort_by_key(|s| s.start);
+		let ids: Vec<FocusSessionId> = sessions.into_iter().map(|s| s.id).collect();
+		FocusSessionId::resolve(input, &ids)
+	}
 }
 
 // --------------------------------------------------------------------------------
@@ -83,6 +96,11 @@ impl SessionRepository for LocalSessionRepository {
 
 	fn create_session(&self, session: FocusSession) -> PPMResult<()> {
 		let mut sessions = self.load_sessions()?;
+
+		if sessions.iter().any(|s| s.id == session.id) {
+			return Err(PPMError::AlreadyExists(format!("Session {} already exists", session.id)));
+		}
+
 		sessions.push(session);
 		self.save_sessions(&sessions)?;
 		Ok(())
@@ -122,4 +140,24 @@ impl SessionRepository for LocalSessionRepository {
 	fn list_sessions(&self) -> PPMResult<Vec<FocusSession>> {
 		self.load_sessions()
 	}
+
+	fn update_sessions(&self, sessions: Vec<FocusSession>) -> PPMResult<()> {
+		let mut stored = self.load_sessions()?;
+
+		if let Some(missing) = sessions.iter().find(|s| !stored.iter().any(|e| e.id == s.id)) {
+			return Err(PPMError::NotFound(format!("Session {} not found", missing.id)));
+		}
+
+		for session in sessions {
+			if let Some(existing) = stored.iter_mut().find(|e| e.id == session.id) {
+				*existing = session;
+			}
+		}
+
+		self.save_sessions(&stored)
+	}
+
+	fn replace_sessions(&self, sessions: Vec<FocusSession>) -> PPMResult<()> {
+		self.save_sessions(&sessions)
+	}
 }
@@ -30,6 +30,11 @@ impl Default for InMemoryTaskRepository {
 impl TaskRepository for InMemoryTaskRepository {
 	fn create_task(&self, task: Task) -> PPMResult<()> {
 		let mut tasks = self.tasks.lock()?;
+
+		if tasks.iter().any(|t| t.id == task.id) {
+			return Err(PPMError::AlreadyExists(format!("Task {} already exists", task.id)));
+		}
+
 		tasks.push(task);
 		Ok(())
 	}
@@ -50,6 +55,17 @@ impl TaskRepository for InMemoryTaskRepository {
 		}
 	}
 
+	fn update_task(&self, task: Task) -> PPMResult<()> {
+		let mut tasks = self.tasks.lock()?;
+
+		if let Some(existing) = tasks.iter_mut().find(|t| t.id == task.id) {
+			*existing = task;
+			Ok(())
+		} else {
+			Err(PPMError::NotFound(format!("Task {} not found", task.id)))
+		}
+	}
+
 	fn list_tasks(&self) -> PPMResult<Vec<Task>> {
 		self.get_all_tasks()
 	}
@@ -75,4 +91,33 @@ impl TaskRepository for InMemoryTaskRepository {
 
 		Ok(())
 	}
+
+	fn upsert_tasks(&self, tasks: Vec<Task>) -> PPMResult<()> {
+		let mut stored = self.tasks.lock()?;
+
+		for task in tasks {
+			match stored.iter_mut().find(|t| t.id == task.id) {
+				Some(existing) => *existing = task,
+				None => stored.push(task),
+			}
+		}
+
+		Ok(())
+	}
+
+	fn delete_tasks(&self, task_ids: &[TaskId]) -> PPMResult<()> {
+		let mut tasks = self.tasks.lock()?;
+
+		if let Some(missing) = task_ids.iter().find(|id| !tasks.iter().any(|t| &t.id == *id)) {
+			return Err(PPMError::NotFound(format!("Task {} not found", missing)));
+		}
+
+		tasks.retain(|t| !task_ids.contains(&t.id));
+		Ok(())
+	}
+
+	fn replace_tasks(&self, tasks: Vec<Task>) -> PPMResult<()> {
+		*self.tasks.lock()? = tasks;
+		Ok(())
+	}
 }
@@ -3,16 +3,38 @@ use std::io::{BufReader, BufWriter, Write};
 use std::path::PathBuf;
 
 use crate::errors::{PPMError, PPMResult};
-use crate::models::{ProjectName, Task, TaskId, TaskStatus};
+use crate::models::{ModelId, ProjectName, Task, TaskId, TaskStatus};
+use crate::query::TaskQuery;
 
 /// Data access abstraction for tasks.
 pub trait TaskRepository: Send + Sync {
 	fn create_task(&self, task: Task) -> PPMResult<()>;
 	fn get_task(&self, task_id: &TaskId) -> PPMResult<Option<Task>>;
 	fn update_task_status(&self, task_id: &TaskId, status: TaskStatus) -> PPMResult<()>;
+	/// Replaces the stored task that has the same ID as `task`.
+	fn update_task(&self, task: Task) -> PPMResult<()>;
 	fn list_tasks(&self) -> PPMResult<Vec<Task>>;
 	fn list_tasks_by_project(&self, project_name: &ProjectName) -> PPMResult<Vec<Task>>;
 	fn delete_task(&self, task_id: &TaskId) -> PPMResult<()>;
+	/// Replaces stored tasks with matching IDs and appends the rest, in a single write.
+	fn upsert_tasks(&self, tasks: Vec<Task>) -> PPMResult<()>;
+	/// Deletes all given tasks in a single write. Fails without changes if any is missing.
+	fn delete_tasks(&self, task_ids: &[TaskId]) -> PPMResult<()>;
+	/// Replaces every stored task with `tasks`, in a single write.
+	fn replace_tasks(&self, tasks: Vec<Task>) -> PPMResult<()>;
+
+	/// Lists the tasks matching `query`.
+	fn find_tasks(&self, query: &TaskQuery) -> PPMResult<Vec<Task>> {
+		Ok(query.apply(self.list_tasks()?))
+	}
+
+	/// Resolves a full ID, sequence number or unique prefix/suffix to a stored task ID.
+	fn resolve_task_id(&self, input: &str) -> PPMResult<TaskId> {
+		let mut tasks = self.list_tasks()?;
+		tasks.sort_by_key(|t| t.created_at);
+		let ids: Vec<TaskId> = tasks.into_iter().map(|t| t.id).collect();
+		TaskId::resolve(input, &ids)
+	}
 }
 
 // --------------------------------------------------------------------------------
@@ -70,6 +92,11 @@ impl LocalTaskRepository {
 impl TaskRepository for LocalTaskRepository {
 	fn create_task(&self, task: Task) -> PPMResult<()> {
 		let mut tasks = self.load_tasks()?;
+
+		if tasks.iter().any(|t| t.id == task.id) {
+			return Err(PPMError::AlreadyExists(format!("Task {} already exists", task.id)));
+		}
+
 		tasks.push(task);
 		self.save_tasks(&tasks)?;
 		Ok(())
@@ -92,6 +119,18 @@ impl TaskRepository for LocalTaskRepository {
 		}
 	}
 
+	fn update_task(&self, task: Task) -> PPMResult<()> {
+		let mut tasks = self.load_tasks()?;
+
+		if let Some(existing) = tasks.iter_mut().find(|t| t.id == task.id) {
+			*existing = task;
+			self.save_tasks(&tasks)?;
+			Ok(())
+		} else {
+			Err(PPMError::NotFound(format!("Task {} not found", task.id)))
+		}
+	}
+
 	fn list_tasks(&self) -> PPMResult<Vec<Task>> {
 		self.load_tasks()
 	}
@@ -114,4 +153,34 @@ impl TaskRepository for LocalTaskRepository {
 		self.save_tasks(&tasks)?;
 		Ok(())
 	}
+
+	fn upsert_tasks(&self, tasks: Vec<Task>) -> PPMResult<()> {
+		let mut stored = self.load_tasks()?;
+
+		for task in tasks {
+			match stored.iter_mut().find(|t| t.id == task.id) {
+				Some(existing) => *existing = task,
+				None => stored.push(task),
+			}
+		}
+
+		self.save_tasks(&stored)?;
+		Ok(())
+	}
+
+	fn delete_tasks(&self, task_ids: &[TaskId]) -> PPMResult<()> {
+		let mut tasks = self.load_tasks()?;
+
+		if let Some(missing) = task_ids.iter().find(|id| !tasks.iter().any(|t| &t.id == *id)) {
+			return Err(PPMError::NotFound(format!("Task {} not found", missing)));
+		}
+
+		tasks.retain(|t| !task_ids.contains(&t.id));
+		self.save_tasks(&tasks)?;
+		Ok(())
+	}
+
+	fn replace_tasks(&self, tasks: Vec<Task>) -> PPMResult<()> {
+		self.save_tasks(&tasks)
+	}
 }
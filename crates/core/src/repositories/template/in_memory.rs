@@ -0,0 +1,40 @@
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex};
+
+use crate::errors::PPMResult;
+use crate::repositories::template::TemplateRepository;
+
+/// In-memory template repository for testing
+pub struct InMemoryTemplateRepository {
+	templates: Arc<Mutex<BTreeMap<String, String>>>,
+}
+
+impl InMemoryTemplateRepository {
+	pub fn new() -> Self {
+		Self {
+			templates: Arc::new(Mutex::new(BTreeMap::new())),
+		}
+	}
+
+	/// Adds or replaces the template called `name`.
+	pub fn insert(&self, name: &str, content: &str) -> PPMResult<()> {
+		self.templates.lock()?.insert(name.to_string(), content.to_string());
+		Ok(())
+	}
+}
+
+impl Default for InMemoryTemplateRepository {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl TemplateRepository for InMemoryTemplateRepository {
+	fn get_template(&self, name: &str) -> PPMResult<Option<String>> {
+		Ok(self.templates.lock()?.get(name).cloned())
+	}
+
+	fn list_templates(&self) -> PPMResult<Vec<String>> {
+		Ok(self.templates.lock()?.keys().cloned().collect())
+	}
+}
@@ -0,0 +1,5 @@
+pub mod in_memory;
+pub mod repository;
+
+pub use in_memory::InMemoryTemplateRepository;
+pub use repository::{LocalTemplateRepository, TemplateRepository};
@@ -0,0 +1,64 @@
+use std::fs;
+use std::path::PathBuf;
+
+use crate::errors::PPMResult;
+
+/// Data access abstraction for note templates.
+pub trait TemplateRepository: Send + Sync {
+	/// Raw text of the template called `name`.
+	fn get_template(&self, name: &str) -> PPMResult<Option<String>>;
+	/// Names of all templates, sorted.
+	fn list_templates(&self) -> PPMResult<Vec<String>>;
+}
+
+// --------------------------------------------------------------------------------
+// Concrete Implementations
+// --------------------------------------------------------------------------------
+
+/// Template repository reading `{templates_dir}/{name}.md` files.
+pub struct LocalTemplateRepository {
+	templates_dir: PathBuf,
+}
+
+impl LocalTemplateRepository {
+	pub fn new(templates_dir: PathBuf) -> Self {
+		Self {
+			templates_dir,
+		}
+	}
+}
+
+impl TemplateRepository for LocalTemplateRepository {
+	fn get_template(&self, name: &str) -> PPMResult<Option<String>> {
+		// Names are plain file stems; anything else cannot refer to a template
+		if name.is_empty() || name.contains(['/', '\\']) || name.starts_with('.') {
+			return Ok(None);
+		}
+
+		let file_path = self.templates_dir.join(format!("{}.md", name));
+		if !file_path.exists() {
+			return Ok(None);
+		}
+
+		Ok(Some(fs::read_to_string(&file_path)?))
+	}
+
+	fn list_templates(&self) -> PPMResult<Vec<String>> {
+		if !self.templates_dir.exists() {
+			return Ok(Vec::new());
+		}
+
+		let mut names = Vec::new();
+		for entry in fs::read_dir(&self.templates_dir)? {
+			let path = entry?.path();
+			if path.extension().and_then(|s| s.to_str()) == Some("md")
+				&& let Some(stem) = path.file_stem().and_then(|s| s.to_str())
+			{
+				names.push(stem.to_string());
+			}
+		}
+		names.sort();
+
+		Ok(names)
+	}
+}
@@ -0,0 +1,72 @@
+use std::sync::{Arc, Mutex};
+
+use chrono::{DateTime, Utc};
+
+use crate::errors::{PPMError, PPMResult};
+use crate::models::{TrashEntry, TrashEntryId};
+use crate::repositories::trash::TrashRepository;
+
+/// In-memory trash repository for testing
+pub struct InMemoryTrashRepository {
+	entries: Arc<Mutex<Vec<TrashEntry>>>,
+}
+
+impl InMemoryTrashRepository {
+	pub fn new() -> Self {
+		Self {
+			entries: Arc::new(Mutex::new(Vec::new())),
+		}
+	}
+}
+
+impl Default for InMemoryTrashRepository {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl TrashRepository for InMemoryTrashRepository {
+	fn add_entries(&self, entries: Vec<TrashEntry>) -> PPMResult<()> {
+		let mut stored = self.entries.lock()?;
+
+		stored.extend(entries);
+
+		Ok(())
+	}
+
+	fn list_entries(&self) -> PPMResult<Vec<TrashEntry>> {
+		Ok(self.entries.lock()?.clone())
+	}
+
+	fn remove_entries(&self, entry_ids: &[TrashEntryId]) -> PPMResult<Vec<TrashEntry>> {
+		let mut stored = self.entries.lock()?;
+
+		if let Some(missing) = entry_ids.iter().find(|id| !stored.iter().any(|e| &e.id == *id)) {
+			return Err(PPMError::NotFound(format!("Trash entry {} not found", missing)));
+		}
+
+		let (removed, kept): (Vec<_>, Vec<_>) =
+			stored.drain(..).partition(|e| entry_ids.contains(&e.id));
+		*stored = kept;
+
+		Ok(removed)
+	}
+
+	fn purge_before(&self, cutoff: DateTime<Utc>) -> PPMResult<Vec<TrashEntry>> {
+		let mut stored = self.entries.lock()?;
+
+		let (purged, kept): (Vec<_>, Vec<_>) =
+			stored.drain(..).partition(|e| e.deleted_at < cutoff);
+		*stored = kept;
+
+		Ok(purged)
+	}
+
+	fn clear(&self) -> PPMResult<usize> {
+		let mut stored = self.entries.lock()?;
+		let count = stored.len();
+		stored.clear();
+
+		Ok(count)
+	}
+}
@@ -0,0 +1,193 @@
+use std::fs::{self, OpenOptions};
+use std::io::{BufReader, BufWriter, Write};
+use std::path::PathBuf;
+
+use chrono::{DateTime, Utc};
+
+use crate::errors::{PPMError, PPMResult};
+use crate::models::{TrashEntry, TrashEntryId};
+
+/// Data access abstraction for the trash.
+///
+/// Entries are keyed by their own [`TrashEntryId`]; trashing an item that is already in the
+/// trash adds a second entry next to the first.
+pub trait TrashRepository: Send + Sync {
+	/// Adds all given entries in a single write.
+	fn add_entries(&self, entries: Vec<TrashEntry>) -> PPMResult<()>;
+	fn list_entries(&self) -> PPMResult<Vec<TrashEntry>>;
+	/// Removes and returns the entries with the given IDs. Fails without changes if any is
+	/// missing.
+	fn remove_entries(&self, entry_ids: &[TrashEntryId]) -> PPMResult<Vec<TrashEntry>>;
+	/// Removes and returns the entries deleted before `cutoff`.
+	fn purge_before(&self, cutoff: DateTime<Utc>) -> PPMResult<Vec<TrashEntry>>;
+	/// Removes every entry, returning how many were removed.
+	fn clear(&self) -> PPMResult<usize>;
+}
+
+// --------------------------------------------------------------------------------
+// Concrete Implementations
+// --------------------------------------------------------------------------------
+
+/// File-based trash repository storing data in JSON format.
+pub struct LocalTrashRepository {
+	storage_path: PathBuf,
+}
+
+impl LocalTrashRepository {
+	pub fn new(storage_path: PathBuf) -> Self {
+		Self {
+			storage_path,
+		}
+	}
+
+	fn ensure_storage_dir(&self) -> PPMResult<()> {
+		if let Some(parent) = self.storage_path.parent() {
+			fs::create_dir_all(parent)?;
+		}
+		Ok(())
+	}
+
+	fn load_entries(&self) -> PPMResult<Vec<TrashEntry>> {
+		if !self.storage_path.exists() {
+			return Ok(Vec::new());
+		}
+
+		let file = fs::File::open(&self.storage_path)?;
+		let reader = BufReader::new(file);
+		let entries: Vec<TrashEntry> = serde_json::from_reader(reader)
+			.map_err(|e| std::io::Error::other(format!("Failed to parse trash: {}", e)))?;
+
+		Ok(entries)
+	}
+
+	fn save_entries(&self, entries: &[TrashEntry]) -> PPMResult<()> {
+		self.ensure_storage_dir()?;
+
+		let file =
+			OpenOptions::new().write(true).create(true).truncate(true).open(&self.storage_path)?;
+
+		let mut writer = BufWriter::new(file);
+		serde_json::to_writer_pretty(&mut writer, entries)
+			.map_err(|e| std::io::Error::other(format!("Failed to write trash: {}", e)))?;
+
+		writer.flush()?;
+
+		Ok(())
+	}
+}
+
+impl TrashRepository for LocalTrashRepository {
+	fn add_entries(&self, entries: Vec<TrashEntry>) -> PPMResult<()> {
+		let mut stored = self.load_entries()?;
+
+		stored.extend(entries);
+
+		self.save_entries(&stored)
+	}
+
+	fn list_entries(&self) -> PPMResult<Vec<TrashEntry>> {
+		self.load_entries()
+	}
+
+	fn remove_entries(&self, entry_ids: &[TrashEntryId]) -> PPMResult<Vec<TrashEntry>> {
+		let stored = self.load_entries()?;
+
+		if let Some(missing) = entry_ids.iter().find(|id| !stored.iter().any(|e| &e.id == *id)) {
+			return Err(PPMError::NotFound(format!("Trash entry {} not found", missing)));
+		}
+
+		let (removed, kept): (Vec<_>, Vec<_>) =
+			stored.into_iter().partition(|e| entry_ids.contains(&e.id));
+		self.save_entries(&kept)?;
+
+		Ok(removed)
+	}
+
+	fn purge_before(&self, cutoff: DateTime<Utc>) -> PPMResult<Vec<TrashEntry>> {
+		let stored = self.load_entries()?;
+
+		let (purged, kept): (Vec<_>, Vec<_>) =
+			stored.into_iter().partition(|e| e.deleted_at < cutoff);
+		if !purged.is_empty() {
+			self.save_entries(&kept)?;
+		}
+
+		Ok(purged)
+	}
+
+	fn clear(&self) -> PPMResult<usize> {
+		let count = self.load_entries()?.len();
+		if count > 0 {
+			self.save_entries(&[])?;
+		}
+
+		Ok(count)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::models::TrashedItem;
+	use crate::test_support::{TempDir, project, task, utc};
+
+	fn repository(dir: &TempDir) -> LocalTrashRepository {
+		LocalTrashRepository::new(dir.path().join("trash.json"))
+	}
+
+	#[test]
+	fn entries_with_the_same_item_are_kept_apart() {
+		let dir = TempDir::new();
+		let trash = repository(&dir);
+		let first = TrashEntry::new(TrashedItem::Project(project("api")), utc(2026, 1, 1, 0, 0));
+		let second = TrashEntry::new(TrashedItem::Project(project("api")), utc(2026, 1, 2, 0, 0));
+		trash.add_entries(vec![first.clone()]).unwrap();
+		trash.add_entries(vec![second.clone()]).unwrap();
+		assert_eq!(trash.list_entries().unwrap().len(), 2);
+
+		let removed = trash.remove_entries(std::slice::from_ref(&first.id)).unwrap();
+		assert_eq!(removed.len(), 1);
+		assert_eq!(removed[0].deleted_at, first.deleted_at);
+		let kept = trash.list_entries().unwrap();
+		assert_eq!(kept.len(), 1);
+		assert_eq!(kept[0].id, second.id);
+
+		// Any missing ID fails without removing the others
+		assert!(trash.remove_entries(&[second.id.clone(), first.id.clone()]).is_err());
+		assert_eq!(trash.list_entries().unwrap().len(), 1);
+	}
+
+	#[test]
+	fn purge_removes_only_older_entries() {
+		let dir = TempDir::new();
+		let trash = repository(&dir);
+		trash
+			.add_entries(vec![
+				TrashEntry::new(TrashedItem::Task(task("Old")), utc(2026, 1, 1, 0, 0)),
+				TrashEntry::new(TrashedItem::Task(task("New")), utc(2026, 1, 20, 0, 0)),
+			])
+			.unwrap();
+
+		let purged = trash.purge_before(utc(2026, 1, 10, 0, 0)).unwrap();
+		assert_eq!(purged.len(), 1);
+		assert_eq!(purged[0].item.summary(), "Old");
+		assert_eq!(trash.list_entries().unwrap().len(), 1);
+		assert_eq!(trash.clear().unwrap(), 1);
+		assert!(trash.list_entries().unwrap().is_empty());
+	}
+
+	#[test]
+	fn entries_without_an_id_get_a_stable_one() {
+		let dir = TempDir::new();
+		let trash = repository(&dir);
+		let entry = TrashEntry::new(TrashedItem::Project(project("api")), utc(2026, 1, 1, 0, 0));
+		let mut stored = serde_json::to_value(vec![entry]).unwrap();
+		stored[0].as_object_mut().unwrap().remove("id");
+		fs::write(dir.path().join("trash.json"), stored.to_string()).unwrap();
+
+		let loaded = trash.list_entries().unwrap();
+		assert!(loaded[0].id.as_ref().starts_with("trash_"));
+		assert_eq!(trash.list_entries().unwrap()[0].id, loaded[0].id);
+		assert_eq!(trash.remove_entries(&[loaded[0].id.clone()]).unwrap().len(), 1);
+	}
+}
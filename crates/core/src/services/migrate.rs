@@ -0,0 +1,513 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::errors::PPMResult;
+use crate::models::{FocusSession, FocusSessionId, Note, Project, Task, TaskId};
+use crate::output::OutputWriter;
+use crate::repositories::migration::reassign_duplicate_ids;
+use crate::repositories::{NoteRepository, ProjectRepository, SessionRepository, TaskRepository};
+use crate::services::Service;
+
+/// Service to give duplicated task and session IDs fresh ones, along with the references to
+/// them.
+///
+/// IDs used to be plain microsecond timestamps, so data written before the switch to ULIDs
+/// may hold several tasks or sessions with the same ID. The first of each keeps its ID, so
+/// every existing ID stays valid. A reference to a duplicated ID moves to another copy only
+/// when that copy is the one it must have meant: the session running when a note was written,
+/// or the only task in the project of the session, note or milestone referring to it. Other
+/// references stay on the first copy and are reported.
+///
+/// The stores are written one after another. If any write fails, the writes already made are
+/// reverted in reverse order before the error is returned.
+pub struct MigrateIds {
+	pub task_repository: Arc<dyn TaskRepository>,
+	pub session_repository: Arc<dyn SessionRepository>,
+	pub project_repository: Arc<dyn ProjectRepository>,
+	pub note_repository: Arc<dyn NoteRepository>,
+	pub output_writer: Arc<dyn OutputWriter>,
+	/// Only report what would change
+	pub dry_run: bool,
+}
+
+/// Write that has been made, holding the original data needed to revert it.
+enum Applied {
+	Tasks(Vec<Task>),
+	Sessions(Vec<FocusSession>),
+	Project(Project),
+	Note(Note),
+}
+
+/// Migrated data to write, with the stored originals of changed projects and notes.
+struct Changes {
+	tasks: Vec<Task>,
+	sessions: Vec<FocusSession>,
+	projects: Vec<(Project, Project)>,
+	notes: Vec<(Note, Note)>,
+}
+
+/// References updated and left ambiguous so far.
+#[derive(Default)]
+struct Tally {
+	updated: usize,
+	ambiguous: usize,
+}
+
+impl MigrateIds {
+	/// Picks the copy of a duplicated ID that `fits`, when exactly one does.
+	///
+	/// Counts the reference as updated when it moves off the first copy, and warns about it
+	/// when it cannot be told apart.
+	fn pick<'a, T>(
+		&self,
+		tally: &mut Tally,
+		copies: &[&'a T],
+		fits: impl Fn(&T) -> bool,
+		referrer: &str,
+		kind: &str,
+		id: &dyn std::fmt::Display,
+	) -> PPMResult<Option<&'a T>> {
+		let fitting: Vec<&T> = copies.iter().copied().filter(|c| fits(c)).collect();
+		match fitting.as_slice() {
+			[single] if !std::ptr::eq(*single, copies[0]) => {
+				tally.updated += 1;
+				Ok(Some(*single))
+			}
+			[_] => Ok(None),
+			_ => {
+				tally.ambiguous += 1;
+				self.output_writer.write_line(&format!(
+					"Warning: {} refers to duplicated {} {}; left on the first copy",
+					referrer, kind, id
+				))?;
+				Ok(None)
+			}
+		}
+	}
+
+	fn apply(
+		&self,
+		changes: Changes,
+		originals: (Vec<Task>, Vec<FocusSession>),
+		applied: &mut Vec<Applied>,
+	) -> PPMResult<()> {
+		let (tasks, sessions) = originals;
+		self.task_repository.replace_tasks(changes.tasks)?;
+		applied.push(Applied::Tasks(tasks));
+		self.session_repository.replace_sessions(changes.sessions)?;
+		applied.push(Applied::Sessions(sessions));
+
+		for (original, project) in changes.projects {
+			self.project_repository.update_project(&project.name.clone(), project)?;
+			applied.push(Applied::Project(original));
+		}
+		// Notes are stored one file each, so each one is its own step
+		for (original, note) in changes.notes {
+			self.note_repository.update_note(note)?;
+			applied.push(Applied::Note(original));
+		}
+
+		Ok(())
+	}
+
+	fn rollback(&self, applied: Vec<Applied>) -> PPMResult<()> {
+		for step in applied.into_iter().rev() {
+			let (description, result) = match step {
+				Applied::Tasks(originals) => (
+					format!("{} task(s)", originals.len()),
+					self.task_repository.replace_tasks(originals),
+				),
+				Applied::Sessions(originals) => (
+					format!("{} session(s)", originals.len()),
+					self.session_repository.replace_sessions(originals),
+				),
+				Applied::Project(original) => (
+					format!("project '{}'", original.name),
+					self.project_repository.update_project(&original.name.clone(), original),
+				),
+				Applied::Note(original) => {
+					(format!("note {}", original.id), self.note_repository.update_note(original))
+				}
+			};
+
+			if let Err(e) = result {
+				self.output_writer
+					.write_line(&format!("Failed to roll back {}: {}", description, e))?;
+			}
+		}
+
+		Ok(())
+	}
+}
+
+impl Service for MigrateIds {
+	fn run(&self) -> PPMResult<()> {
+		let mut tasks = self.task_repository.list_tasks()?;
+		let mut sessions = self.session_repository.list_sessions()?;
+		let originals = (tasks.clone(), sessions.clone());
+		let task_moves = reassign_duplicate_ids(&mut tasks, |t| &mut t.id);
+		let session_moves = reassign_duplicate_ids(&mut sessions, |s| &mut s.id);
+
+		if task_moves.is_empty() && session_moves.is_empty() {
+			self.output_writer.write_line(&"No duplicate IDs found")?;
+			return Ok(());
+		}
+
+		// Every copy of each duplicated task ID, the one keeping it first
+		let mut task_copies: HashMap<TaskId, Vec<&Task>> = HashMap::new();
+		for (position, former) in &task_moves {
+			let task = &tasks[*position];
+			self.output_writer.write_line(&format!(
+				"Task {} is duplicated: '{}' is now {}",
+				former, task.description, task.id
+			))?;
+			task_copies
+				.entry(former.clone())
+				.or_insert_with(|| tasks.iter().filter(|t| &t.id == former).collect())
+				.push(task);
+		}
+
+		let mut tally = Tally::default();
+
+		for session in sessions.iter_mut() {
+			let Some(copies) =
+				session.associated_task_id.as_ref().and_then(|id| task_copies.get(id))
+			else {
+				continue;
+			};
+			let referrer = format!("Session {}", session.id);
+			if let Some(task) = self.pick(
+				&mut tally,
+				copies,
+				|t| t.project_name == session.associated_project_name,
+				&referrer,
+				"task",
+				&copies[0].id,
+			)? {
+				self.output_writer
+					.write_line(&format!("{} now refers to task {}", referrer, task.id))?;
+				session.associated_task_id = Some(task.id.clone());
+			}
+		}
+
+		let mut session_copies: HashMap<FocusSessionId, Vec<&FocusSession>> = HashMap::new();
+		for (position, former) in &session_moves {
+			let session = &sessions[*position];
+			self.output_writer.write_line(&format!(
+				"Session {} is duplicated: the one started {} is now {}",
+				former,
+				session.start.format("%Y-%m-%d %H:%M"),
+				session.id
+			))?;
+			session_copies
+				.entry(former.clone())
+				.or_insert_with(|| sessions.iter().filter(|s| &s.id == former).collect())
+				.push(session);
+		}
+
+		let mut changed_projects = Vec::new();
+		for original in self.project_repository.list_projects()? {
+			let mut project = original.clone();
+			let mut changed = false;
+			for milestone in project.milestones.iter_mut() {
+				for task_id in milestone.task_ids.iter_mut() {
+					let Some(copies) = task_copies.get(task_id) else {
+						continue;
+					};
+					let referrer = format!("Milestone '{}' of '{}'", milestone.name, project.name);
+					if let Some(task) = self.pick(
+						&mut tally,
+						copies,
+						|t| t.project_name.as_ref().is_some_and(|n| project.name.includes(n)),
+						&referrer,
+						"task",
+						&copies[0].id,
+					)? {
+						self.output_writer
+							.write_line(&format!("{} now links task {}", referrer, task.id))?;
+						*task_id = task.id.clone();
+						changed = true;
+					}
+				}
+			}
+			if changed {
+				changed_projects.push((original, project));
+			}
+		}
+
+		let mut changed_notes = Vec::new();
+		for original in self.note_repository.list_notes()? {
+			let mut note = original.clone();
+			let referrer = format!("Note {}", note.id);
+			let mut changed = false;
+
+			if let Some(copies) = note.task_id.as_ref().and_then(|id| task_copies.get(id))
+				&& let Some(task) = self.pick(
+					&mut tally,
+					copies,
+					|t| t.project_name == note.project_name,
+					&referrer,
+					"task",
+					&copies[0].id,
+				)? {
+				self.output_writer
+					.write_line(&format!("{} now refers to task {}", referrer, task.id))?;
+				note.task_id = Some(task.id.clone());
+				changed = true;
+			}
+
+			if let Some(copies) = note.session_id.as_ref().and_then(|id| session_copies.get(id))
+				&& let Some(session) = self.pick(
+					&mut tally,
+					copies,
+					|s| s.start <= note.created_at && note.created_at <= s.end,
+					&referrer,
+					"session",
+					&copies[0].id,
+				)? {
+				self.output_writer
+					.write_line(&format!("{} now refers to session {}", referrer, session.id))?;
+				note.session_id = Some(session.id.clone());
+				changed = true;
+			}
+
+			if changed {
+				changed_notes.push((original, note));
+			}
+		}
+
+		self.output_writer.write_line(&format!(
+			"Reassigned {} task ID(s) and {} session ID(s), updated {} reference(s)",
+			task_moves.len(),
+			session_moves.len(),
+			tally.updated
+		))?;
+		if tally.ambiguous > 0 {
+			self.output_writer.write_line(&format!(
+				"{} reference(s) could not be told apart and stay on the first copy",
+				tally.ambiguous
+			))?;
+		}
+
+		if self.dry_run {
+			self.output_writer.write_line(&"Dry run: nothing was changed")?;
+			return Ok(());
+		}
+
+		let changes = Changes {
+			tasks,
+			sessions,
+			projects: changed_projects,
+			notes: changed_notes,
+		};
+		let mut applied = Vec::new();
+		if let Err(e) = self.apply(changes, originals, &mut applied) {
+			self.output_writer.write_line(&format!(
+				"Migration failed, rolling back {} completed update(s)",
+				applied.len()
+			))?;
+			self.rollback(applied)?;
+			return Err(e);
+		}
+
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::errors::PPMError;
+	use crate::models::{Milestone, ProjectName};
+	use crate::output::InMemoryWriter;
+	use crate::repositories::{
+		InMemoryNoteRepository, InMemoryProjectRepository, InMemorySessionRepository,
+		InMemoryTaskRepository,
+	};
+	use crate::test_support::{FailingNoteRepository, note, project, session, task, utc};
+
+	struct Fixture {
+		tasks: Arc<InMemoryTaskRepository>,
+		sessions: Arc<InMemorySessionRepository>,
+		projects: Arc<InMemoryProjectRepository>,
+		notes: Arc<InMemoryNoteRepository>,
+	}
+
+	impl Fixture {
+		fn new() -> Self {
+			Self {
+				tasks: Arc::new(InMemoryTaskRepository::new()),
+				sessions: Arc::new(InMemorySessionRepository::new()),
+				projects: Arc::new(InMemoryProjectRepository::new()),
+				notes: Arc::new(InMemoryNoteRepository::new()),
+			}
+		}
+
+		fn migrate(&self, dry_run: bool) -> Vec<String> {
+			let output_writer = Arc::new(InMemoryWriter::new());
+			MigrateIds {
+				task_repository: self.tasks.clone(),
+				session_repository: self.sessions.clone(),
+				project_repository: self.projects.clone(),
+				note_repository: self.notes.clone(),
+				output_writer: output_writer.clone(),
+				dry_run,
+			}
+			.run()
+			.unwrap();
+			output_writer.lines().unwrap()
+		}
+	}
+
+	fn in_project(description: &str, id: &TaskId, project: &str) -> Task {
+		Task {
+			id: id.clone(),
+			project_name: Some(ProjectName::from(project)),
+			..task(description)
+		}
+	}
+
+	#[test]
+	fn moves_references_that_fit_one_copy() {
+		let fixture = Fixture::new();
+		let shared = TaskId::from("task_1700000000000000");
+		fixture
+			.tasks
+			.replace_tasks(vec![
+				in_project("A", &shared, "alpha"),
+				in_project("B", &shared, "beta"),
+			])
+			.unwrap();
+
+		let mut beta_session = session(utc(2026, 1, 2, 9, 0), 60);
+		beta_session.associated_project_name = Some(ProjectName::from("beta"));
+		beta_session.associated_task_id = Some(shared.clone());
+		fixture.sessions.create_session(beta_session.clone()).unwrap();
+
+		let mut beta = project("beta");
+		beta.milestones.push(Milestone {
+			name: "v1".to_string(),
+			target: utc(2026, 2, 1, 0, 0).date_naive(),
+			created_at: utc(2026, 1, 1, 0, 0),
+			task_ids: vec![shared.clone()],
+		});
+		fixture.projects.create_project(beta).unwrap();
+
+		let mut alpha_note = note("about A");
+		alpha_note.project_name = Some(ProjectName::from("alpha"));
+		alpha_note.task_id = Some(shared.clone());
+		let mut loose_note = note("about either");
+		loose_note.task_id = Some(shared.clone());
+		fixture.notes.create_note(alpha_note.clone()).unwrap();
+		fixture.notes.create_note(loose_note.clone()).unwrap();
+
+		let output = fixture.migrate(false);
+
+		let tasks = fixture.tasks.list_tasks().unwrap();
+		assert_eq!(tasks[0].id, shared);
+		assert_ne!(tasks[1].id, shared);
+		let moved = tasks[1].id.clone();
+
+		let sessions = fixture.sessions.list_sessions().unwrap();
+		assert_eq!(sessions[0].associated_task_id, Some(moved.clone()));
+		let beta = fixture.projects.get_project(&ProjectName::from("beta")).unwrap().unwrap();
+		assert_eq!(beta.milestones[0].task_ids, vec![moved]);
+		let alpha_note = fixture.notes.get_note(&alpha_note.id).unwrap().unwrap();
+		assert_eq!(alpha_note.task_id, Some(shared.clone()));
+		let loose_note = fixture.notes.get_note(&loose_note.id).unwrap().unwrap();
+		assert_eq!(loose_note.task_id, Some(shared));
+
+		assert!(output.iter().any(|l| l.contains("updated 2 reference(s)")));
+		assert!(output.iter().any(|l| l.contains("1 reference(s) could not be told apart")));
+	}
+
+	#[test]
+	fn notes_follow_the_session_they_were_written_in() {
+		let fixture = Fixture::new();
+		let shared = FocusSessionId::from("session_1700000000000000");
+		let mut morning = session(utc(2026, 1, 2, 9, 0), 60);
+		morning.id = shared.clone();
+		let mut evening = session(utc(2026, 1, 2, 18, 0), 60);
+		evening.id = shared.clone();
+		fixture.sessions.replace_sessions(vec![morning, evening]).unwrap();
+
+		let mut evening_note = note("late");
+		evening_note.session_id = Some(shared.clone());
+		evening_note.created_at = utc(2026, 1, 2, 18, 30);
+		fixture.notes.create_note(evening_note.clone()).unwrap();
+
+		fixture.migrate(false);
+
+		let sessions = fixture.sessions.list_sessions().unwrap();
+		let evening_note = fixture.notes.get_note(&evening_note.id).unwrap().unwrap();
+		assert_eq!(sessions[0].id, shared);
+		assert_eq!(evening_note.session_id, Some(sessions[1].id.clone()));
+	}
+
+	#[test]
+	fn dry_run_changes_nothing() {
+		let fixture = Fixture::new();
+		let shared = TaskId::from("task_1700000000000000");
+		let mut first = task("A");
+		first.id = shared.clone();
+		let mut second = task("B");
+		second.id = shared.clone();
+		fixture.tasks.replace_tasks(vec![first, second]).unwrap();
+
+		let output = fixture.migrate(true);
+
+		assert!(fixture.tasks.list_tasks().unwrap().iter().all(|t| t.id == shared));
+		assert!(output.iter().any(|l| l.contains("Dry run")));
+		assert_eq!(fixture.migrate(false).len(), 2);
+		assert_eq!(fixture.migrate(false), vec!["[ppm] No duplicate IDs found"]);
+	}
+
+	#[test]
+	fn failed_writes_are_rolled_back() {
+		let fixture = Fixture::new();
+		let shared = TaskId::from("task_1700000000000000");
+		fixture
+			.tasks
+			.replace_tasks(vec![
+				in_project("A", &shared, "alpha"),
+				in_project("B", &shared, "beta"),
+			])
+			.unwrap();
+		let mut beta_session = session(utc(2026, 1, 2, 9, 0), 60);
+		beta_session.associated_project_name = Some(ProjectName::from("beta"));
+		beta_session.associated_task_id = Some(shared.clone());
+		fixture.sessions.create_session(beta_session).unwrap();
+
+		let notes = Arc::new(FailingNoteRepository {
+			inner: InMemoryNoteRepository::new(),
+			fail_writes: true.into(),
+		});
+		let mut beta_note = note("about B");
+		beta_note.project_name = Some(ProjectName::from("beta"));
+		beta_note.task_id = Some(shared.clone());
+		notes.create_note(beta_note.clone()).unwrap();
+
+		let output_writer = Arc::new(InMemoryWriter::new());
+		let result = MigrateIds {
+			task_repository: fixture.tasks.clone(),
+			session_repository: fixture.sessions.clone(),
+			project_repository: fixture.projects.clone(),
+			note_repository: notes.clone(),
+			output_writer: output_writer.clone(),
+			dry_run: false,
+		}
+		.run();
+
+		assert!(matches!(result, Err(PPMError::IoError(_))));
+		assert!(fixture.tasks.list_tasks().unwrap().iter().all(|t| t.id == shared));
+		let sessions = fixture.sessions.list_sessions().unwrap();
+		assert_eq!(sessions[0].associated_task_id, Some(shared.clone()));
+		assert_eq!(notes.get_note(&beta_note.id).unwrap().unwrap().task_id, Some(shared));
+		let output = output_writer.lines().unwrap();
+		assert!(
+			output.contains(
+				&"[ppm] Migration failed, rolling back 2 completed update(s)".to_string()
+			)
+		);
+	}
+}
@@ -1,8 +1,13 @@
+pub mod migrate;
 pub mod note;
 pub mod project;
 pub mod session;
 pub mod task;
+pub mod trash;
 
+use chrono::{DateTime, Duration, Utc};
+
+use crate::clock::Clock;
 use crate::errors::PPMResult;
 
 /// Core abstraction for all business logic operations.
@@ -12,3 +17,21 @@ use crate::errors::PPMResult;
 pub trait Service {
 	fn run(&self) -> PPMResult<()>;
 }
+
+/// Formats a duration as `1h 30m`, or `45m` under an hour.
+pub(crate) fn format_duration(duration: &Duration) -> String {
+	let total_minutes = duration.num_minutes();
+	let hours = total_minutes / 60;
+	let minutes = total_minutes % 60;
+
+	if hours > 0 {
+		format!("{}h {}m", hours, minutes)
+	} else {
+		format!("{}m", minutes)
+	}
+}
+
+/// Formats `time` as `2026-01-31 14:05` in the user's time zone.
+pub(crate) fn format_local_time(clock: &dyn Clock, time: DateTime<Utc>) -> String {
+	time.with_timezone(&clock.local_offset(time)).format("%Y-%m-%d %H:%M").to_string()
+}
@@ -1,36 +1,113 @@
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
+use chrono::{DateTime, Utc};
+
 use crate::clock::Clock;
+use crate::config::ReferenceMode;
 use crate::editor::Editor;
-use crate::errors::PPMResult;
-use crate::models::{Note, NoteId, ProjectName};
+use crate::errors::{PPMError, PPMResult};
+use crate::input::InputReader;
+use crate::models::{FocusSession, Note, NoteFrontMatter, NoteId, ProjectName, Task, TaskId};
 use crate::output::OutputWriter;
+use crate::prompt::Prompt;
 use crate::repositories::note::NoteRepository;
+use crate::repositories::project::ProjectRepository;
 use crate::repositories::session::SessionRepository;
-use crate::services::Service;
+use crate::repositories::task::TaskRepository;
+use crate::repositories::template::TemplateRepository;
+use crate::services::note::template::fill_placeholders;
+use crate::services::{Service, project};
 
+/// Service to write a new note in the editor, or to take its text from a message or stdin.
+///
+/// The editor starts from the named template, if any, with these placeholders filled in:
+/// `{{date}}`, `{{time}}`, `{{title}}`, `{{project}}`, `{{active_task}}`,
+/// `{{active_task_id}}` and `{{session_id}}`. Front matter in a template, or in given text, is
+/// merged into the note's own; given text is appended to the template's body.
+///
+/// A note written during a focus session is linked to that session and, unless another task
+/// is given, to the session's task.
 pub struct CreateNote {
 	pub clock: Arc<dyn Clock>,
 	pub note_repository: Arc<dyn NoteRepository>,
 	pub session_repository: Arc<dyn SessionRepository>,
+	pub project_repository: Arc<dyn ProjectRepository>,
+	pub task_repository: Arc<dyn TaskRepository>,
+	pub template_repository: Arc<dyn TemplateRepository>,
 	pub output_writer: Arc<dyn OutputWriter>,
+	pub prompt: Arc<dyn Prompt>,
 	pub editor: Arc<dyn Editor>,
+	pub input_reader: Arc<dyn InputReader>,
 	pub project_name: Option<ProjectName>,
+	/// Task to attach the note to
+	pub task_id: Option<TaskId>,
+	/// Title to use instead of the first heading of the note
+	pub title: Option<String>,
+	/// Name of the template to start from
+	pub template: Option<String>,
+	pub source: NoteSource,
+	pub project_references: ReferenceMode,
+}
+
+/// Where the text of a new note comes from.
+#[derive(Debug, Clone)]
+pub enum NoteSource {
+	/// Written in the editor, starting from the template
+	Editor,
+	/// Given directly, e.g. on the command line
+	Message(String),
+	/// Read from the input reader until end of file
+	Stdin,
 }
 
 impl CreateNote {
-	/// Extracts the note body from full content (removes front matter)
-	fn extract_note_body(&self, full_content: &str) -> String {
-		// Split by --- delimiters
-		let parts: Vec<&str> = full_content.splitn(3, "---").collect();
-
-		if parts.len() >= 3 {
-			// Front matter exists, return body part
-			parts[2].trim().to_string()
-		} else {
-			// No front matter found, return as-is
-			full_content.trim().to_string()
+	/// Loads the template called `name` with its placeholders filled in.
+	fn render_template(
+		&self,
+		name: &str,
+		now: DateTime<Utc>,
+		project_name: Option<&ProjectName>,
+		session: Option<&FocusSession>,
+	) -> PPMResult<String> {
+		let Some(template) = self.template_repository.get_template(name)? else {
+			let available = self.template_repository.list_templates()?;
+			let hint = if available.is_empty() {
+				"no templates found".to_string()
+			} else {
+				format!("available: {}", available.join(", "))
+			};
+			return Err(PPMError::NotFound(format!("Template '{}' not found ({})", name, hint)));
+		};
+
+		let active_task = match session.and_then(|s| s.associated_task_id.as_ref()) {
+			Some(task_id) => self.task_repository.get_task(task_id)?,
+			None => None,
+		};
+
+		let local_now = now.with_timezone(&self.clock.local_offset(now));
+		let values = BTreeMap::from([
+			("date", local_now.format("%Y-%m-%d").to_string()),
+			("time", local_now.format("%H:%M").to_string()),
+			("title", self.title.clone().unwrap_or_default()),
+			("project", project_name.map(|p| p.to_string()).unwrap_or_default()),
+			(
+				"active_task",
+				active_task.as_ref().map(|t| t.description.clone()).unwrap_or_default(),
+			),
+			("active_task_id", active_task.map(|t| t.id.to_string()).unwrap_or_default()),
+			("session_id", session.map(|s| s.id.to_string()).unwrap_or_default()),
+		]);
+
+		let (filled, unknown) = fill_placeholders(&template, &values);
+		for placeholder in unknown {
+			self.output_writer.write_line(&format!(
+				"Warning: unknown placeholder '{{{{{}}}}}' in template '{}'",
+				placeholder, name
+			))?;
 		}
+
+		Ok(filled)
 	}
 }
 
@@ -39,57 +116,252 @@ impl Service for CreateNote {
 		let current_time = self.clock.now()?;
 		let note_id = NoteId::new();
 
-		// Determine project name: use provided, or fetch from active session
+		let active_session = self.session_repository.get_active_session(current_time)?;
+
+		// Link the given task, or else the active session's
+		let task_id = match self.task_id {
+			Some(ref task_id) => Some(self.task_repository.resolve_task_id(task_id)?),
+			None => active_session.as_ref().and_then(|s| s.associated_task_id.clone()),
+		};
+		let task: Option<Task> = match task_id {
+			Some(ref task_id) => Some(
+				self.task_repository
+					.get_task(task_id)?
+					.ok_or_else(|| PPMError::NotFound(format!("Task {} not found", task_id)))?,
+			),
+			None => None,
+		};
+
+		// Determine project name: use provided, or take the task's or the active session's
 		let project_name = if let Some(ref name) = self.project_name {
+			project::ensure_accepts_work(
+				self.project_repository.as_ref(),
+				self.output_writer.as_ref(),
+				self.prompt.as_ref(),
+				self.project_references,
+				current_time,
+				name,
+			)?;
 			Some(name.clone())
 		} else {
-			// Try to get project from active session
-			self.session_repository
-				.get_active_session(current_time)?
-				.and_then(|session| session.associated_project_name)
+			task.as_ref()
+				.and_then(|t| t.project_name.clone())
+				.or_else(|| active_session.as_ref().and_then(|s| s.associated_project_name.clone()))
 		};
 
-		// Prepare initial content with front matter template
-		let mut initial_content =
-			format!("---\nid: {}\ncreated_at: {}\n", note_id, current_time.to_rfc3339());
-
-		if let Some(ref project) = project_name {
-			initial_content.push_str(&format!("project: {}\n", project));
-		}
+		let (template_front_matter, template_body) = match self.template {
+			Some(ref name) => NoteFrontMatter::parse(&self.render_template(
+				name,
+				current_time,
+				project_name.as_ref(),
+				active_session.as_ref(),
+			)?)?,
+			None => (
+				NoteFrontMatter::default(),
+				format!("# {}", self.title.as_deref().unwrap_or("Write your note here")),
+			),
+		};
 
-		initial_content.push_str("---\n\n# Write your note here\n\n");
+		// Prepare initial front matter, merged with the template's
+		let initial_front_matter = NoteFrontMatter {
+			id: Some(note_id.clone()),
+			title: self.title.clone().or(template_front_matter.title),
+			project_name: project_name.clone(),
+			task_id: task_id.clone(),
+			session_id: active_session.as_ref().map(|s| s.id.clone()),
+			created_at: Some(current_time),
+			updated_at: None,
+			journal: None,
+			extra: template_front_matter.extra,
+		};
 
-		// Open editor with front matter template
-		let full_content = self.editor.open(Some(&initial_content))?;
+		let written = match self.source {
+			NoteSource::Editor => self
+				.editor
+				.open(Some(&initial_front_matter.render(&format!("{}\n\n", template_body))))?,
+			NoteSource::Message(ref message) => Some(message.clone()),
+			NoteSource::Stdin => Some(self.input_reader.read_all()?),
+		};
 
 		// If user didn't write anything, abort
-		let full_content = match full_content {
-			Some(c) => c,
-			None => {
+		let written = match written {
+			Some(c) if !c.trim().is_empty() => c,
+			_ => {
 				self.output_writer.write_line(&"Note creation cancelled (no content provided)")?;
 				return Ok(());
 			}
 		};
 
-		// Parse the edited content to extract body (skip front matter)
-		let content = self.extract_note_body(&full_content);
+		// Take the body and any keys the user added; the note's own fields stay as generated
+		let (front_matter, content) = match self.source {
+			NoteSource::Editor => NoteFrontMatter::parse(&written)?,
+			NoteSource::Message(_) | NoteSource::Stdin => {
+				let (input_front_matter, input_body) = split_given_text(&written);
+				let mut front_matter = initial_front_matter;
+				front_matter.title = front_matter.title.or(input_front_matter.title);
+				front_matter.extra.extend(input_front_matter.extra);
+
+				// Given text follows the template's body, if any
+				let content = match self.template {
+					Some(_) => format!("{}\n\n{}", template_body, input_body).trim().to_string(),
+					None => input_body,
+				};
+				(front_matter, content)
+			}
+		};
+
+		// An explicit title wins over one typed into the front matter, then the first heading
+		let title =
+			self.title.clone().or(front_matter.title).or_else(|| Note::heading_title(&content));
 
 		let note = Note {
 			id: note_id,
+			title,
 			project_name: project_name.clone(),
+			task_id,
+			session_id: active_session.map(|s| s.id),
 			content,
 			created_at: current_time,
+			updated_at: None,
+			journal: None,
+			extra_front_matter: front_matter.extra,
 		};
 
 		self.note_repository.create_note(note)?;
 
-		let message = match project_name {
+		let mut message = match project_name {
 			Some(ref project) => format!("Note created for project '{}'", project),
 			None => "Note created".to_string(),
 		};
+		if let Some(task) = task {
+			message.push_str(&format!(", attached to task '{}'", task.description));
+		}
 
 		self.output_writer.write_line(&message)?;
 
 		Ok(())
 	}
 }
+
+/// Splits text given with a message or on stdin into front matter and body.
+///
+/// The text only has front matter when a closed block parses into at least one entry, so
+/// text that merely starts with a `---` rule, or piped YAML or diff output, is all body.
+fn split_given_text(text: &str) -> (NoteFrontMatter, String) {
+	match NoteFrontMatter::parse(text) {
+		Ok((front_matter, body)) if front_matter != NoteFrontMatter::default() => {
+			(front_matter, body)
+		}
+		_ => (NoteFrontMatter::default(), text.trim().to_string()),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use chrono::FixedOffset;
+
+	use super::*;
+	use crate::clock::FixedClock;
+	use crate::config::ReferenceMode;
+	use crate::editor::FixedEditor;
+	use crate::input::FixedInput;
+	use crate::output::InMemoryWriter;
+	use crate::prompt::FixedPrompt;
+	use crate::repositories::{
+		InMemoryNoteRepository, InMemoryProjectRepository, InMemorySessionRepository,
+		InMemoryTaskRepository, InMemoryTemplateRepository,
+	};
+	use crate::test_support::utc;
+
+	struct Fixture {
+		notes: Arc<InMemoryNoteRepository>,
+		sessions: Arc<InMemorySessionRepository>,
+		tasks: Arc<InMemoryTaskRepository>,
+		templates: Arc<InMemoryTemplateRepository>,
+	}
+
+	impl Fixture {
+		fn new() -> Self {
+			Self {
+				notes: Arc::new(InMemoryNoteRepository::new()),
+				sessions: Arc::new(InMemorySessionRepository::new()),
+				tasks: Arc::new(InMemoryTaskRepository::new()),
+				templates: Arc::new(InMemoryTemplateRepository::new()),
+			}
+		}
+
+		/// Service writing a note at 2026-01-05 23:30 UTC, 08:30 the next day at UTC+9
+		fn service(&self, source: NoteSource) -> CreateNote {
+			let clock = FixedClock::new(utc(2026, 1, 5, 23, 30))
+				.with_offset(FixedOffset::east_opt(9 * 3600).unwrap());
+			CreateNote {
+				clock: Arc::new(clock),
+				note_repository: self.notes.clone(),
+				session_repository: self.sessions.clone(),
+				project_repository: Arc::new(InMemoryProjectRepository::new()),
+				task_repository: self.tasks.clone(),
+				template_repository: self.templates.clone(),
+				output_writer: Arc::new(InMemoryWriter::new()),
+				prompt: Arc::new(FixedPrompt::new(true)),
+				editor: Arc::new(FixedEditor::unchanged()),
+				input_reader: Arc::new(FixedInput::new("")),
+				project_name: None,
+				task_id: None,
+				title: None,
+				template: None,
+				source,
+				project_references: ReferenceMode::default(),
+			}
+		}
+
+		fn only_note(&self) -> Note {
+			let notes = self.notes.list_notes().unwrap();
+			assert_eq!(notes.len(), 1);
+			notes.into_iter().next().unwrap()
+		}
+	}
+
+	#[test]
+	fn given_text_starting_with_a_rule_is_all_body() {
+		for text in ["---\nafter a rule", "---\n---\ntwo rules", "--- a/file\n+++ b/file\n"] {
+			let fixture = Fixture::new();
+			fixture.service(NoteSource::Message(text.to_string())).run().unwrap();
+
+			let note = fixture.only_note();
+			assert_eq!(note.content, text.trim());
+			assert!(note.extra_front_matter.is_empty());
+		}
+	}
+
+	#[test]
+	fn given_text_can_carry_front_matter() {
+		let fixture = Fixture::new();
+		let input = FixedInput::new("---\ntitle: Piped\ntags: [a]\n---\nbody");
+		CreateNote {
+			input_reader: Arc::new(input),
+			..fixture.service(NoteSource::Stdin)
+		}
+		.run()
+		.unwrap();
+
+		let note = fixture.only_note();
+		assert_eq!(note.title.as_deref(), Some("Piped"));
+		assert_eq!(note.content, "body");
+		assert_eq!(note.extra_front_matter[0].key, "tags");
+	}
+
+	#[test]
+	fn template_dates_are_local() {
+		let fixture = Fixture::new();
+		fixture.templates.insert("daily", "# Log {{date}} {{time}}").unwrap();
+
+		CreateNote {
+			template: Some("daily".to_string()),
+			..fixture.service(NoteSource::Editor)
+		}
+		.run()
+		.unwrap();
+
+		assert_eq!(fixture.only_note().content, "# Log 2026-01-06 08:30");
+	}
+}
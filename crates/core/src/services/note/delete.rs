@@ -1,21 +1,43 @@
 use std::sync::Arc;
 
-use crate::errors::PPMResult;
-use crate::models::NoteId;
+use crate::clock::Clock;
+use crate::errors::{PPMError, PPMResult};
+use crate::models::{NoteId, TrashEntry, TrashedItem};
 use crate::output::OutputWriter;
 use crate::repositories::note::NoteRepository;
+use crate::repositories::trash::TrashRepository;
 use crate::services::Service;
+use crate::services::trash::purge_expired;
 
+/// Service to move a note to the trash.
 pub struct DeleteNote {
+	pub clock: Arc<dyn Clock>,
 	pub note_repository: Arc<dyn NoteRepository>,
+	pub trash_repository: Arc<dyn TrashRepository>,
 	pub output_writer: Arc<dyn OutputWriter>,
+	pub trash_retention_days: u32,
 	pub note_id: NoteId,
 }
 
 impl Service for DeleteNote {
 	fn run(&self) -> PPMResult<()> {
-		self.note_repository.delete_note(&self.note_id)?;
-		self.output_writer.write_line(&format!("Note {} deleted", self.note_id))?;
+		let now = self.clock.now()?;
+		purge_expired(
+			self.trash_repository.as_ref(),
+			self.output_writer.as_ref(),
+			now,
+			self.trash_retention_days,
+		)?;
+
+		let note_id = self.note_repository.resolve_note_id(&self.note_id)?;
+		let note = self
+			.note_repository
+			.get_note(&note_id)?
+			.ok_or_else(|| PPMError::NotFound(format!("Note {} not found", note_id)))?;
+
+		self.trash_repository.add_entries(vec![TrashEntry::new(TrashedItem::Note(note), now)])?;
+		self.note_repository.delete_note(&note_id)?;
+		self.output_writer.write_line(&format!("Note {} moved to trash", note_id))?;
 
 		Ok(())
 	}
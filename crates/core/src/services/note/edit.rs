@@ -0,0 +1,158 @@
+use std::sync::Arc;
+
+use crate::clock::Clock;
+use crate::editor::Editor;
+use crate::errors::{PPMError, PPMResult};
+use crate::models::{Note, NoteFrontMatter, NoteId};
+use crate::output::OutputWriter;
+use crate::repositories::note::NoteRepository;
+use crate::services::Service;
+
+/// Service to reopen a stored note, front matter included, in the editor.
+///
+/// The body, the `title`, `project`, `task` and `session` fields and any other keys are taken from
+/// the edited document. A title that was taken from the first heading follows that heading unless
+/// the `title` field itself was changed. The note keeps its ID and `created_at`, and
+/// `updated_at` is set when anything changed.
+pub struct EditNote {
+	pub clock: Arc<dyn Clock>,
+	pub note_repository: Arc<dyn NoteRepository>,
+	pub output_writer: Arc<dyn OutputWriter>,
+	pub editor: Arc<dyn Editor>,
+	pub note_id: NoteId,
+}
+
+impl Service for EditNote {
+	fn run(&self) -> PPMResult<()> {
+		let note_id = self.note_repository.resolve_note_id(&self.note_id)?;
+		let mut note = self
+			.note_repository
+			.get_note(&note_id)?
+			.ok_or_else(|| PPMError::NotFound(format!("Note {} not found", note_id)))?;
+
+		let Some(document) = self.editor.open(Some(&note.to_markdown()))? else {
+			self.output_writer.write_line(&"Note edit cancelled (no content provided)")?;
+			return Ok(());
+		};
+
+		let (front_matter, content) = NoteFrontMatter::parse(&document)?;
+		let follows_heading =
+			note.title.is_some() && note.title == Note::heading_title(&note.content);
+		let title = match front_matter.title {
+			Some(ref title) if follows_heading && note.title.as_ref() == Some(title) => {
+				Note::heading_title(&content).or(front_matter.title)
+			}
+			Some(title) => Some(title),
+			None => Note::heading_title(&content),
+		};
+		if content == note.content
+			&& title == note.title
+			&& front_matter.project_name == note.project_name
+			&& front_matter.task_id == note.task_id
+			&& front_matter.session_id == note.session_id
+			&& front_matter.extra == note.extra_front_matter
+		{
+			self.output_writer.write_line(&format!("Note {} unchanged", note_id))?;
+			return Ok(());
+		}
+
+		note.content = content;
+		note.title = title;
+		note.project_name = front_matter.project_name;
+		note.task_id = front_matter.task_id;
+		note.session_id = front_matter.session_id;
+		note.extra_front_matter = front_matter.extra;
+		note.updated_at = Some(self.clock.now()?);
+
+		self.note_repository.update_note(note)?;
+		self.output_writer.write_line(&format!("Note {} updated", note_id))?;
+
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::clock::FixedClock;
+	use crate::editor::FixedEditor;
+	use crate::output::InMemoryWriter;
+	use crate::repositories::InMemoryNoteRepository;
+	use crate::test_support::{note, utc};
+
+	/// Stores `original`, lets the editor turn its document into `edit(document)` and returns
+	/// the stored note with the output.
+	fn edit(original: &Note, edit: impl Fn(&str) -> String) -> (Note, Vec<String>) {
+		let repository = Arc::new(InMemoryNoteRepository::new());
+		repository.create_note(original.clone()).unwrap();
+		let output = Arc::new(InMemoryWriter::new());
+
+		EditNote {
+			clock: Arc::new(FixedClock::new(utc(2026, 2, 1, 12, 0))),
+			note_repository: repository.clone(),
+			output_writer: output.clone(),
+			editor: Arc::new(FixedEditor::returning(edit(&original.to_markdown()))),
+			note_id: original.id.clone(),
+		}
+		.run()
+		.unwrap();
+
+		(repository.get_note(&original.id).unwrap().unwrap(), output.lines().unwrap())
+	}
+
+	#[test]
+	fn changes_set_updated_at_and_keep_created_at() {
+		let original = note("First draft");
+
+		let (edited, lines) = edit(&original, |document| document.replace("First", "Second"));
+		assert_eq!(edited.content, "Second draft");
+		assert_eq!(edited.created_at, original.created_at);
+		assert_eq!(edited.updated_at, Some(utc(2026, 2, 1, 12, 0)));
+		assert_eq!(lines, vec![format!("[ppm] Note {} updated", original.id)]);
+	}
+
+	#[test]
+	fn unchanged_documents_are_not_written() {
+		let original = Note {
+			title: Some("Plan".to_string()),
+			updated_at: Some(utc(2026, 1, 5, 0, 0)),
+			..note("Body")
+		};
+
+		let (stored, lines) = edit(&original, str::to_string);
+		assert_eq!(stored.updated_at, original.updated_at);
+		assert_eq!(lines, vec![format!("[ppm] Note {} unchanged", original.id)]);
+	}
+
+	#[test]
+	fn a_title_taken_from_the_heading_follows_it() {
+		let original = Note {
+			title: Some("Old heading".to_string()),
+			..note("# Old heading\n\nBody")
+		};
+
+		let (edited, _) =
+			edit(&original, |document| document.replace("# Old heading", "# New heading"));
+		assert_eq!(edited.title.as_deref(), Some("New heading"));
+		assert_eq!(edited.content, "# New heading\n\nBody");
+
+		// A title set apart from the heading stays as it is
+		let original = Note {
+			title: Some("Custom".to_string()),
+			..note("# Old heading\n\nBody")
+		};
+		let (edited, _) =
+			edit(&original, |document| document.replace("# Old heading", "# New heading"));
+		assert_eq!(edited.title.as_deref(), Some("Custom"));
+
+		// Editing the title field itself wins over the heading
+		let original = Note {
+			title: Some("Old heading".to_string()),
+			..note("# Old heading\n\nBody")
+		};
+		let (edited, _) = edit(&original, |document| {
+			document.replace("title: Old heading", "title: Renamed").replace("# Old", "# New")
+		});
+		assert_eq!(edited.title.as_deref(), Some("Renamed"));
+	}
+}
@@ -0,0 +1,254 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Duration, FixedOffset, NaiveDate, Utc};
+
+use crate::clock::Clock;
+use crate::editor::Editor;
+use crate::errors::PPMResult;
+use crate::models::{FocusSession, Note, NoteFrontMatter, NoteId, Task, TaskStatus};
+use crate::output::OutputWriter;
+use crate::repositories::note::NoteRepository;
+use crate::repositories::session::SessionRepository;
+use crate::repositories::task::TaskRepository;
+use crate::services::note::EditNote;
+use crate::services::{Service, format_duration};
+
+/// Service to open today's journal note, creating it first if there is none.
+///
+/// There is one journal note per local date, marked by the `journal` key of its front matter.
+/// A new one starts out listing the day's focus sessions, the tasks completed today and the
+/// pending tasks due today; an existing one is opened as is, like `note edit` would.
+pub struct OpenJournal {
+	pub clock: Arc<dyn Clock>,
+	pub note_repository: Arc<dyn NoteRepository>,
+	pub session_repository: Arc<dyn SessionRepository>,
+	pub task_repository: Arc<dyn TaskRepository>,
+	pub output_writer: Arc<dyn OutputWriter>,
+	pub editor: Arc<dyn Editor>,
+}
+
+impl OpenJournal {
+	/// Body of a new journal note for `today`.
+	fn prefill(
+		&self,
+		now: DateTime<Utc>,
+		offset: FixedOffset,
+		today: NaiveDate,
+	) -> PPMResult<String> {
+		let local_date = |time: &DateTime<Utc>| time.with_timezone(&offset).date_naive();
+		let tasks = self.task_repository.list_tasks()?;
+
+		let mut sessions: Vec<FocusSession> = self
+			.session_repository
+			.list_sessions()?
+			.into_iter()
+			.filter(|s| local_date(&s.start) == today)
+			.collect();
+		sessions.sort_by_key(|s| s.start);
+
+		let mut completed: Vec<&Task> = tasks
+			.iter()
+			.filter(|t| matches!(t.status, TaskStatus::Done(at) if local_date(&at) == today))
+			.collect();
+		completed.sort_by_key(|t| match t.status {
+			TaskStatus::Done(at) => Some(at),
+			_ => None,
+		});
+
+		let mut due: Vec<&Task> = tasks
+			.iter()
+			.filter(|t| matches!(t.status, TaskStatus::Pending) && t.due == Some(today))
+			.collect();
+		due.sort_by_key(|t| t.created_at);
+
+		let mut body = format!("# Journal {}\n\n## Sessions\n\n", today);
+		if sessions.is_empty() {
+			body.push_str("- None\n");
+		}
+		for session in &sessions {
+			let end = if session.is_active(now) {
+				"now".to_string()
+			} else {
+				session.end.with_timezone(&offset).format("%H:%M").to_string()
+			};
+			let task = match session.associated_task_id {
+				Some(ref task_id) => tasks.iter().find(|t| &t.id == task_id),
+				None => None,
+			};
+
+			let mut line = format!(
+				"- {}-{} ({})",
+				session.start.with_timezone(&offset).format("%H:%M"),
+				end,
+				format_duration(&session.elapsed(now))
+			);
+			if let Some(ref project) = session.associated_project_name {
+				line.push_str(&format!(" [{}]", project));
+			}
+			if let Some(task) = task {
+				line.push_str(&format!(" {}", task.description));
+			}
+			body.push_str(&line);
+			body.push('\n');
+		}
+		let total = sessions.iter().fold(Duration::zero(), |sum, s| sum + s.elapsed(now));
+		if !sessions.is_empty() {
+			body.push_str(&format!("\nTotal focus: {}\n", format_duration(&total)));
+		}
+
+		body.push_str("\n## Completed today\n\n");
+		push_tasks(&mut body, &completed);
+
+		body.push_str("\n## Due today\n\n");
+		push_tasks(&mut body, &due);
+
+		body.push_str("\n## Notes\n\n");
+		Ok(body)
+	}
+}
+
+impl Service for OpenJournal {
+	fn run(&self) -> PPMResult<()> {
+		let now = self.clock.now()?;
+		let offset = self.clock.local_offset(now);
+		let today = now.with_timezone(&offset).date_naive();
+
+		let existing =
+			self.note_repository.list_notes()?.into_iter().find(|n| n.journal == Some(today));
+		if let Some(note) = existing {
+			self.output_writer.write_line(&format!("Opening journal for {}", today))?;
+			return EditNote {
+				clock: self.clock.clone(),
+				note_repository: self.note_repository.clone(),
+				output_writer: self.output_writer.clone(),
+				editor: self.editor.clone(),
+				note_id: note.id,
+			}
+			.run();
+		}
+
+		let note_id = NoteId::new();
+		let initial_front_matter = NoteFrontMatter {
+			id: Some(note_id.clone()),
+			title: Some(format!("Journal {}", today)),
+			created_at: Some(now),
+			journal: Some(today),
+			..NoteFrontMatter::default()
+		};
+		let initial = initial_front_matter.render(&self.prefill(now, offset, today)?);
+
+		let written = match self.editor.open(Some(&initial))? {
+			Some(c) if !c.trim().is_empty() => c,
+			_ => {
+				self.output_writer
+					.write_line(&"Journal creation cancelled (no content provided)")?;
+				return Ok(());
+			}
+		};
+
+		// The note stays today's journal whatever happened to its front matter in the editor
+		let (front_matter, content) = NoteFrontMatter::parse(&written)?;
+		let title = front_matter
+			.title
+			.or_else(|| Note::heading_title(&content))
+			.or(initial_front_matter.title);
+
+		self.note_repository.create_note(Note {
+			id: note_id,
+			title,
+			project_name: front_matter.project_name,
+			task_id: front_matter.task_id,
+			session_id: front_matter.session_id,
+			content,
+			created_at: now,
+			updated_at: None,
+			journal: Some(today),
+			extra_front_matter: front_matter.extra,
+		})?;
+		self.output_writer.write_line(&format!("Journal for {} created", today))?;
+
+		Ok(())
+	}
+}
+
+fn push_tasks(body: &mut String, tasks: &[&Task]) {
+	if tasks.is_empty() {
+		body.push_str("- None\n");
+	}
+	for task in tasks {
+		match task.project_name {
+			Some(ref project) => body.push_str(&format!("- {} [{}]\n", task.description, project)),
+			None => body.push_str(&format!("- {}\n", task.description)),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::clock::FixedClock;
+	use crate::editor::FixedEditor;
+	use crate::output::InMemoryWriter;
+	use crate::repositories::note::InMemoryNoteRepository;
+	use crate::repositories::session::InMemorySessionRepository;
+	use crate::repositories::task::InMemoryTaskRepository;
+	use crate::test_support::{session, task, utc};
+
+	fn dated(description: &str, status: TaskStatus, due: Option<NaiveDate>) -> Task {
+		Task {
+			status,
+			due,
+			..task(description)
+		}
+	}
+
+	#[test]
+	fn journal_follows_the_local_date() {
+		// 23:30 UTC on the 5th is 08:30 on the 6th at UTC+9
+		let clock = FixedClock::new(utc(2026, 1, 5, 23, 30))
+			.with_offset(FixedOffset::east_opt(9 * 3600).unwrap());
+		let note_repository = Arc::new(InMemoryNoteRepository::new());
+		let session_repository = Arc::new(InMemorySessionRepository::new());
+		let task_repository = Arc::new(InMemoryTaskRepository::new());
+		let editor = Arc::new(FixedEditor::unchanged());
+
+		let today = NaiveDate::from_ymd_opt(2026, 1, 6).unwrap();
+		let yesterday = NaiveDate::from_ymd_opt(2026, 1, 5).unwrap();
+		session_repository.create_session(session(utc(2026, 1, 5, 22, 0), 30)).unwrap();
+		session_repository.create_session(session(utc(2026, 1, 5, 10, 0), 30)).unwrap();
+		for task in [
+			dated("Shipped", TaskStatus::Done(utc(2026, 1, 5, 23, 10)), None),
+			dated("Old work", TaskStatus::Done(utc(2026, 1, 5, 9, 0)), None),
+			dated("Due now", TaskStatus::Pending, Some(today)),
+			dated("Was due", TaskStatus::Pending, Some(yesterday)),
+		] {
+			task_repository.create_task(task).unwrap();
+		}
+
+		let service = OpenJournal {
+			clock: Arc::new(clock),
+			note_repository: note_repository.clone(),
+			session_repository,
+			task_repository,
+			output_writer: Arc::new(InMemoryWriter::new()),
+			editor: editor.clone(),
+		};
+		service.run().unwrap();
+
+		let notes = note_repository.list_notes().unwrap();
+		assert_eq!(notes.len(), 1);
+		assert_eq!(notes[0].journal, Some(today));
+		assert!(notes[0].content.starts_with("# Journal 2026-01-06"));
+		assert!(notes[0].content.contains("- 07:00-07:30 (30m)"));
+		assert!(!notes[0].content.contains("19:00"));
+		assert!(notes[0].content.contains("- Shipped"));
+		assert!(!notes[0].content.contains("Old work"));
+		assert!(notes[0].content.contains("- Due now"));
+		assert!(!notes[0].content.contains("Was due"));
+
+		// A second run reopens the same note instead of creating another
+		service.run().unwrap();
+		assert_eq!(note_repository.list_notes().unwrap().len(), 1);
+		assert_eq!(editor.opened().unwrap().len(), 2);
+	}
+}
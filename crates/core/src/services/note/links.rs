@@ -0,0 +1,352 @@
+use std::sync::Arc;
+
+use crate::errors::{PPMError, PPMResult};
+use crate::models::{
+	FocusSession, LinkTarget, ModelId, Note, NoteId, NoteLink, Project, ProjectName, Task, TaskId,
+};
+use crate::output::OutputWriter;
+use crate::repositories::note::NoteRepository;
+use crate::repositories::project::ProjectRepository;
+use crate::repositories::session::SessionRepository;
+use crate::repositories::task::TaskRepository;
+use crate::services::Service;
+
+/// Service to show a note's `[[links]]` and the notes linking back to it.
+///
+/// Without a note, every broken link across all notes is reported instead. The `task` and
+/// `session` front matter fields count as links too, and are reported when they point at a task
+/// or session that no longer exists, e.g. one in the trash.
+pub struct NoteLinks {
+	pub note_repository: Arc<dyn NoteRepository>,
+	pub task_repository: Arc<dyn TaskRepository>,
+	pub project_repository: Arc<dyn ProjectRepository>,
+	pub session_repository: Arc<dyn SessionRepository>,
+	pub output_writer: Arc<dyn OutputWriter>,
+	pub note_id: Option<NoteId>,
+}
+
+/// Where a link leads once looked up.
+enum Resolved<'a> {
+	Notes(Vec<&'a Note>),
+	Task(&'a Task),
+	Project(&'a Project),
+	Broken,
+}
+
+/// Everything a link can point at, loaded once.
+struct LinkTargets {
+	/// Oldest first, so positions give sequence numbers
+	notes: Vec<Note>,
+	ids: Vec<NoteId>,
+	/// Oldest first, like `notes`
+	tasks: Vec<Task>,
+	task_ids: Vec<TaskId>,
+	projects: Vec<Project>,
+	sessions: Vec<FocusSession>,
+}
+
+impl LinkTargets {
+	fn resolve(&self, link: &NoteLink) -> Resolved<'_> {
+		let project = |name: &ProjectName| self.projects.iter().find(|p| &p.name == name);
+
+		match link.target {
+			// IDs may be shortened as on the command line
+			LinkTarget::Note(ref id) => match NoteId::resolve(id.as_ref(), &self.ids) {
+				Ok(id) => Resolved::Notes(self.notes.iter().filter(|n| n.id == id).collect()),
+				Err(_) => Resolved::Broken,
+			},
+			LinkTarget::Task(ref id) => match TaskId::resolve(id.as_ref(), &self.task_ids) {
+				Ok(id) => {
+					self.tasks.iter().find(|t| t.id == id).map_or(Resolved::Broken, Resolved::Task)
+				}
+				Err(_) => Resolved::Broken,
+			},
+			LinkTarget::Project(ref name) => {
+				project(name).map_or(Resolved::Broken, Resolved::Project)
+			}
+			LinkTarget::Name(ref name) => {
+				let notes: Vec<&Note> =
+					self.notes.iter().filter(|n| n.is_linked_by(link)).collect();
+				if !notes.is_empty() {
+					Resolved::Notes(notes)
+				} else {
+					project(&ProjectName::from(name.as_str()))
+						.map_or(Resolved::Broken, Resolved::Project)
+				}
+			}
+		}
+	}
+
+	/// `task: …` and `session: …` front matter fields of `note` pointing at nothing.
+	fn broken_front_matter(&self, note: &Note) -> Vec<String> {
+		let task = note
+			.task_id
+			.as_ref()
+			.filter(|id| !self.tasks.iter().any(|t| &t.id == *id))
+			.map(|id| format!("task: {}", id));
+		let session = note
+			.session_id
+			.as_ref()
+			.filter(|id| !self.sessions.iter().any(|s| &s.id == *id))
+			.map(|id| format!("session: {}", id));
+
+		task.into_iter().chain(session).collect()
+	}
+
+	fn describe(&self, note: &Note) -> String {
+		format!(
+			"#{} {} - {}",
+			note.id.sequence_number(&self.ids).unwrap_or_default(),
+			note.id,
+			note.display_title()
+		)
+	}
+}
+
+impl NoteLinks {
+	fn write_links(&self, targets: &LinkTargets, note: &Note) -> PPMResult<()> {
+		self.output_writer.write_line(&format!("Note {}", targets.describe(note)))?;
+
+		let links = note.links();
+		let mut broken = 0;
+		for field in targets.broken_front_matter(note) {
+			broken += 1;
+			self.output_writer.write_line(&format!("Front matter {} (broken)", field))?;
+		}
+		if links.is_empty() {
+			self.output_writer.write_line(&"No links")?;
+		} else {
+			self.output_writer.write_line(&format!("Links ({}):", links.len()))?;
+		}
+		for link in &links {
+			match targets.resolve(link) {
+				Resolved::Notes(notes) => {
+					for target in notes {
+						self.output_writer
+							.write_line(&format!("  -> note {}", targets.describe(target)))?;
+					}
+				}
+				Resolved::Task(task) => self
+					.output_writer
+					.write_line(&format!("  -> task {} - {}", task.id, task.description))?,
+				Resolved::Project(project) => {
+					self.output_writer.write_line(&format!("  -> project '{}'", project.name))?
+				}
+				Resolved::Broken => {
+					broken += 1;
+					self.output_writer.write_line(&format!("  -> [[{}]] (broken)", link.raw))?
+				}
+			}
+		}
+
+		let backlinks: Vec<&Note> = targets
+			.notes
+			.iter()
+			.filter(|other| {
+				other.id != note.id
+					&& other.links().iter().any(|l| {
+						matches!(targets.resolve(l), Resolved::Notes(notes) if notes.iter().any(|n| n.id == note.id))
+					})
+			})
+			.collect();
+		if backlinks.is_empty() {
+			self.output_writer.write_line(&"No backlinks")?;
+		} else {
+			self.output_writer.write_line(&format!("Backlinks ({}):", backlinks.len()))?;
+			for other in backlinks {
+				self.output_writer.write_line(&format!("  <- {}", targets.describe(other)))?;
+			}
+		}
+
+		if broken > 0 {
+			self.output_writer.write_line(&format!("{} broken link(s)", broken))?;
+		}
+
+		Ok(())
+	}
+
+	fn write_broken_links(&self, targets: &LinkTargets) -> PPMResult<()> {
+		let broken: Vec<(&Note, String)> = targets
+			.notes
+			.iter()
+			.flat_map(|note| {
+				let links = note
+					.links()
+					.into_iter()
+					.filter(|link| matches!(targets.resolve(link), Resolved::Broken))
+					.map(|link| format!("[[{}]]", link.raw));
+				targets.broken_front_matter(note).into_iter().chain(links).map(move |l| (note, l))
+			})
+			.collect();
+
+		if broken.is_empty() {
+			self.output_writer.write_line(&"No broken links")?;
+			return Ok(());
+		}
+
+		self.output_writer.write_line(&format!("{} broken link(s):", broken.len()))?;
+		for (note, link) in broken {
+			self.output_writer.write_line(&format!("  {} -> {}", targets.describe(note), link))?;
+		}
+
+		Ok(())
+	}
+}
+
+impl Service for NoteLinks {
+	fn run(&self) -> PPMResult<()> {
+		let mut notes = self.note_repository.list_notes()?;
+		notes.sort_by_key(|n| n.created_at);
+		let mut tasks = self.task_repository.list_tasks()?;
+		tasks.sort_by_key(|t| t.created_at);
+		let targets = LinkTargets {
+			ids: notes.iter().map(|n| n.id.clone()).collect(),
+			notes,
+			task_ids: tasks.iter().map(|t| t.id.clone()).collect(),
+			tasks,
+			projects: self.project_repository.list_projects()?,
+			sessions: self.session_repository.list_sessions()?,
+		};
+
+		let Some(ref input) = self.note_id else {
+			return self.write_broken_links(&targets);
+		};
+
+		let note_id = NoteId::resolve(input, &targets.ids)?;
+		let note = targets
+			.notes
+			.iter()
+			.find(|n| n.id == note_id)
+			.ok_or_else(|| PPMError::NotFound(format!("Note {} not found", note_id)))?;
+
+		self.write_links(&targets, note)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::output::InMemoryWriter;
+	use crate::repositories::{
+		InMemoryNoteRepository, InMemoryProjectRepository, InMemorySessionRepository,
+		InMemoryTaskRepository,
+	};
+	use crate::test_support::{note, project, task, utc};
+
+	struct Fixture {
+		notes: Arc<InMemoryNoteRepository>,
+		tasks: Arc<InMemoryTaskRepository>,
+		projects: Arc<InMemoryProjectRepository>,
+		output: Arc<InMemoryWriter>,
+	}
+
+	impl Fixture {
+		fn new() -> Self {
+			Self {
+				notes: Arc::new(InMemoryNoteRepository::new()),
+				tasks: Arc::new(InMemoryTaskRepository::new()),
+				projects: Arc::new(InMemoryProjectRepository::new()),
+				output: Arc::new(InMemoryWriter::new()),
+			}
+		}
+
+		fn run(&self, note_id: Option<&NoteId>) -> Vec<String> {
+			NoteLinks {
+				note_repository: self.notes.clone(),
+				task_repository: self.tasks.clone(),
+				project_repository: self.projects.clone(),
+				session_repository: Arc::new(InMemorySessionRepository::new()),
+				output_writer: self.output.clone(),
+				note_id: note_id.cloned(),
+			}
+			.run()
+			.unwrap();
+			self.output.lines().unwrap()
+		}
+	}
+
+	#[test]
+	fn missing_front_matter_links_are_broken() {
+		let fixture = Fixture::new();
+		let kept = task("Still here");
+		fixture.tasks.create_task(kept.clone()).unwrap();
+		let trashed = TaskId::new();
+		let linked = Note {
+			task_id: Some(kept.id.clone()),
+			..note("Fine")
+		};
+		let dangling = Note {
+			task_id: Some(trashed.clone()),
+			..note("Dangling")
+		};
+		fixture.notes.create_note(linked).unwrap();
+		fixture.notes.create_note(dangling.clone()).unwrap();
+
+		let lines = fixture.run(None);
+		assert!(lines.iter().any(|l| l.contains("1 broken link(s):")));
+		assert!(lines.iter().any(|l| l.ends_with(&format!("-> task: {}", trashed))));
+
+		let lines = fixture.run(Some(&dangling.id));
+		assert!(
+			lines.iter().any(|l| l.contains(&format!("Front matter task: {} (broken)", trashed)))
+		);
+	}
+
+	#[test]
+	fn links_resolve_short_ids_and_report_backlinks() {
+		let fixture = Fixture::new();
+		let target = Note {
+			title: Some("Weekly review".to_string()),
+			created_at: utc(2026, 1, 1, 0, 0),
+			..note("Target")
+		};
+		let todo = task("Write report");
+		fixture.tasks.create_task(todo.clone()).unwrap();
+		fixture.projects.create_project(project("home")).unwrap();
+		let short_task = &todo.id.as_ref()[..todo.id.as_ref().len() - 6];
+		let source = Note {
+			created_at: utc(2026, 1, 2, 0, 0),
+			..note(&format!(
+				"[[weekly review]] [[{}]] [[project:home]] [[Nowhere]]",
+				short_task.to_lowercase()
+			))
+		};
+		let by_id = Note {
+			created_at: utc(2026, 1, 3, 0, 0),
+			..note(&format!("[[{}]]", target.id))
+		};
+		for n in [&target, &source, &by_id] {
+			fixture.notes.create_note(n.clone()).unwrap();
+		}
+
+		let lines = fixture.run(Some(&source.id));
+		assert!(lines.contains(&format!("[ppm]   -> note #1 {} - Weekly review", target.id)));
+		assert!(lines.contains(&format!("[ppm]   -> task {} - Write report", todo.id)));
+		assert!(lines.contains(&"[ppm]   -> project 'home'".to_string()));
+		assert!(lines.contains(&"[ppm]   -> [[Nowhere]] (broken)".to_string()));
+		assert!(lines.contains(&"[ppm] 1 broken link(s)".to_string()));
+
+		let lines = fixture.run(Some(&target.id));
+		assert!(lines.contains(&"[ppm] No links".to_string()));
+		assert!(lines.contains(&"[ppm] Backlinks (2):".to_string()));
+		assert!(lines.iter().any(|l| l.starts_with(&format!("[ppm]   <- #2 {}", source.id))));
+		assert!(lines.iter().any(|l| l.starts_with(&format!("[ppm]   <- #3 {}", by_id.id))));
+	}
+
+	#[test]
+	fn broken_links_are_listed_across_notes() {
+		let fixture = Fixture::new();
+		fixture.notes.create_note(note("[[Missing]] and [[task_01ZZZ]]")).unwrap();
+		fixture.notes.create_note(note("Nothing linked")).unwrap();
+
+		let lines = fixture.run(None);
+		assert_eq!(lines[0], "[ppm] 2 broken link(s):");
+		assert!(lines[1].ends_with("-> [[Missing]]"));
+		assert!(lines[2].ends_with("-> [[task_01ZZZ]]"));
+		assert_eq!(lines.len(), 3);
+
+		let fixture = Fixture::new();
+		fixture.notes.create_note(note("Nothing linked")).unwrap();
+		assert_eq!(fixture.run(None), vec!["[ppm] No broken links"]);
+	}
+}
@@ -1,6 +1,7 @@
 use std::sync::Arc;
 
 use crate::errors::PPMResult;
+use crate::models::{ModelId, NoteId, ProjectName};
 use crate::output::OutputWriter;
 use crate::repositories::note::NoteRepository;
 use crate::services::Service;
@@ -9,14 +10,26 @@ pub struct ListNotes {
 	pub note_repository: Arc<dyn NoteRepository>,
 	pub output_writer: Arc<dyn OutputWriter>,
 	pub limit: Option<usize>,
+	/// Only list notes of this project and its subprojects
+	pub project_name: Option<ProjectName>,
 }
 
 impl Service for ListNotes {
 	fn run(&self) -> PPMResult<()> {
 		let mut notes = self.note_repository.list_notes()?;
 
-		// Sort by created_at descending (newest first)
-		notes.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+		// Sequence numbers follow creation order, independent of the limit
+		notes.sort_by_key(|n| n.created_at);
+		let ids: Vec<NoteId> = notes.iter().map(|n| n.id.clone()).collect();
+
+		// Newest first
+		notes.reverse();
+
+		if let Some(ref project_name) = self.project_name {
+			notes.retain(|n| {
+				n.project_name.as_ref().is_some_and(|name| project_name.includes(name))
+			});
+		}
 
 		// Apply limit if specified
 		if let Some(limit) = self.limit {
@@ -36,15 +49,13 @@ impl Service for ListNotes {
 				None => String::new(),
 			};
 
-			// Truncate content for list view (first 50 chars)
-			let content_preview = if note.content.len() > 50 {
-				format!("{}...", &note.content[..50])
-			} else {
-				note.content.clone()
-			};
-
-			self.output_writer
-				.write_line(&format!("  {} - {}{}", note.id, content_preview, project_display))?;
+			self.output_writer.write_line(&format!(
+				"  #{} {} - {}{}",
+				note.id.sequence_number(&ids).unwrap_or_default(),
+				note.id,
+				note.display_title(),
+				project_display
+			))?;
 		}
 
 		Ok(())
@@ -1,7 +1,18 @@
 pub mod create;
 pub mod delete;
+pub mod edit;
+pub mod journal;
+pub mod links;
 pub mod list;
+pub mod search;
+pub mod show;
+mod template;
 
-pub use create::CreateNote;
+pub use create::{CreateNote, NoteSource};
 pub use delete::DeleteNote;
+pub use edit::EditNote;
+pub use journal::OpenJournal;
+pub use links::NoteLinks;
 pub use list::ListNotes;
+pub use search::SearchNotes;
+pub use show::ShowNote;
@@ -0,0 +1,255 @@
+use std::sync::Arc;
+
+use chrono::{FixedOffset, NaiveDate};
+
+use crate::clock::Clock;
+use crate::errors::{PPMError, PPMResult};
+use crate::models::{ModelId, Note, NoteId, ProjectName};
+use crate::output::OutputWriter;
+use crate::query::parse_date;
+use crate::repositories::note::NoteRepository;
+use crate::services::Service;
+
+/// Characters of context shown before the first match in a snippet.
+const SNIPPET_LEAD: usize = 30;
+/// Total characters shown in a snippet, not counting highlight markers.
+const SNIPPET_LENGTH: usize = 100;
+
+/// Service to search note bodies and front matter values, ranked by relevance.
+///
+/// A note matches when it contains every term, ignoring case. Matches in the title
+/// weigh more than matches further down, and notes containing the terms as one phrase rank
+/// above notes where they are scattered. Matches are highlighted as `**term**`.
+pub struct SearchNotes {
+	pub clock: Arc<dyn Clock>,
+	pub note_repository: Arc<dyn NoteRepository>,
+	pub output_writer: Arc<dyn OutputWriter>,
+	pub terms: Vec<String>,
+	/// Only search notes of this project and its subprojects
+	pub project_name: Option<ProjectName>,
+	/// Only search notes created on or after this date (YYYY-MM-DD or a relative date)
+	pub since: Option<String>,
+	/// Only search notes created on or before this date
+	pub until: Option<String>,
+	pub limit: Option<usize>,
+}
+
+impl SearchNotes {
+	fn matches_filters(
+		&self,
+		note: &Note,
+		offset: FixedOffset,
+		since: Option<NaiveDate>,
+		until: Option<NaiveDate>,
+	) -> bool {
+		let created = note.created_at.with_timezone(&offset).date_naive();
+
+		self.project_name.as_ref().is_none_or(|project| {
+			note.project_name.as_ref().is_some_and(|name| project.includes(name))
+		}) && since.is_none_or(|since| created >= since)
+			&& until.is_none_or(|until| created <= until)
+	}
+}
+
+impl Service for SearchNotes {
+	fn run(&self) -> PPMResult<()> {
+		let terms: Vec<String> =
+			self.terms.iter().map(|t| t.trim().to_lowercase()).filter(|t| !t.is_empty()).collect();
+		if terms.is_empty() {
+			return Err(PPMError::InvalidInput("Search query cannot be empty".to_string()));
+		}
+
+		// Dates are the user's local ones, as in the rest of the output
+		let now = self.clock.now()?;
+		let offset = self.clock.local_offset(now);
+		let today = now.with_timezone(&offset).date_naive();
+		let since = self.since.as_deref().map(|d| parse_date(d, today)).transpose()?;
+		let until = self.until.as_deref().map(|d| parse_date(d, today)).transpose()?;
+
+		// Sequence numbers follow creation order across all notes
+		let mut all_notes = self.note_repository.list_notes()?;
+		all_notes.sort_by_key(|n| n.created_at);
+		let ids: Vec<NoteId> = all_notes.into_iter().map(|n| n.id).collect();
+
+		let mut results: Vec<(usize, Note)> = self
+			.note_repository
+			.search_notes(&terms)?
+			.into_iter()
+			.filter(|note| self.matches_filters(note, offset, since, until))
+			.map(|note| (relevance(&note, &terms), note))
+			.collect();
+
+		// Most relevant first, newest first among equals
+		results.sort_by(|(a_score, a), (b_score, b)| {
+			b_score.cmp(a_score).then(b.created_at.cmp(&a.created_at))
+		});
+		if let Some(limit) = self.limit {
+			results.truncate(limit);
+		}
+
+		if results.is_empty() {
+			self.output_writer.write_line(&"No notes found")?;
+			return Ok(());
+		}
+
+		self.output_writer.write_line(&format!("{} note(s) found:", results.len()))?;
+
+		for (_, note) in results {
+			let project_display = match note.project_name {
+				Some(ref project) => format!(" ({})", project),
+				None => String::new(),
+			};
+
+			self.output_writer.write_line(&format!(
+				"  #{} {} - {} ({}){}",
+				note.id.sequence_number(&ids).unwrap_or_default(),
+				note.id,
+				note.display_title(),
+				note.created_at.with_timezone(&offset).format("%Y-%m-%d"),
+				project_display
+			))?;
+			self.output_writer.write_line(&format!("      {}", snippet(&note.content, &terms)))?;
+		}
+
+		Ok(())
+	}
+}
+
+/// Scores how well a note matches the (lowercase) terms.
+fn relevance(note: &Note, terms: &[String]) -> usize {
+	let content = note.content.to_lowercase();
+	let title = note.display_title().to_lowercase();
+	let project = note.project_name.as_ref().map(|p| p.to_lowercase()).unwrap_or_default();
+
+	let mut score = 0;
+	for term in terms {
+		score += content.matches(term.as_str()).count();
+		score += 3 * title.matches(term.as_str()).count();
+		if project.contains(term.as_str()) {
+			score += 2;
+		}
+	}
+	if terms.len() > 1 && content.contains(&terms.join(" ")) {
+		score += 5;
+	}
+
+	score
+}
+
+/// Cuts a single-line excerpt around the first match and highlights every match in it.
+fn snippet(content: &str, terms: &[String]) -> String {
+	let text: Vec<char> =
+		content.split_whitespace().collect::<Vec<_>>().join(" ").chars().collect();
+	let terms: Vec<Vec<char>> = {
+		let mut terms: Vec<Vec<char>> = terms.iter().map(|t| t.chars().collect()).collect();
+		// Prefer the longest term where several match at the same place
+		terms.sort_by_key(|t| std::cmp::Reverse(t.len()));
+		terms
+	};
+
+	let first_match =
+		(0..text.len()).find(|&i| terms.iter().any(|t| matches_at(&text, i, t))).unwrap_or(0);
+	let start = first_match.saturating_sub(SNIPPET_LEAD);
+	let mut end = (start + SNIPPET_LENGTH).min(text.len());
+
+	let mut excerpt = String::new();
+	if start > 0 {
+		excerpt.push_str("...");
+	}
+
+	let mut i = start;
+	while i < end {
+		if let Some(term) = terms.iter().find(|t| matches_at(&text, i, t)) {
+			excerpt.push_str("**");
+			excerpt.extend(&text[i..i + term.len()]);
+			excerpt.push_str("**");
+			i += term.len();
+			end = end.max(i);
+		} else {
+			excerpt.push(text[i]);
+			i += 1;
+		}
+	}
+
+	if end < text.len() {
+		excerpt.push_str("...");
+	}
+
+	excerpt
+}
+
+/// Whether `term` (lowercase) occurs in `text` at `index`, ignoring case.
+fn matches_at(text: &[char], index: usize, term: &[char]) -> bool {
+	!term.is_empty()
+		&& text.len() >= index + term.len()
+		&& text[index..index + term.len()]
+			.iter()
+			.zip(term)
+			.all(|(c, t)| c.to_lowercase().eq(t.to_lowercase()))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::clock::FixedClock;
+	use crate::output::InMemoryWriter;
+	use crate::repositories::InMemoryNoteRepository;
+	use crate::test_support::{note, utc};
+
+	fn search(
+		repository: &Arc<InMemoryNoteRepository>,
+		terms: &[&str],
+		since: Option<&str>,
+	) -> Vec<String> {
+		// 23:30 UTC on the 5th is 08:30 on the 6th at UTC+9
+		let clock = FixedClock::new(utc(2026, 1, 5, 23, 30))
+			.with_offset(FixedOffset::east_opt(9 * 3600).unwrap());
+		let output_writer = Arc::new(InMemoryWriter::new());
+		SearchNotes {
+			clock: Arc::new(clock),
+			note_repository: repository.clone(),
+			output_writer: output_writer.clone(),
+			terms: terms.iter().map(|t| t.to_string()).collect(),
+			project_name: None,
+			since: since.map(str::to_string),
+			until: None,
+			limit: None,
+		}
+		.run()
+		.unwrap();
+		output_writer.lines().unwrap()
+	}
+
+	#[test]
+	fn front_matter_keys_do_not_match() {
+		let repository = Arc::new(InMemoryNoteRepository::new());
+		repository.create_note(note("groceries")).unwrap();
+
+		assert_eq!(search(&repository, &["created"], None), vec!["[ppm] No notes found"]);
+		assert_eq!(search(&repository, &["title"], None), vec!["[ppm] No notes found"]);
+		assert_eq!(search(&repository, &["GROCERIES"], None)[0], "[ppm] 1 note(s) found:");
+	}
+
+	#[test]
+	fn front_matter_values_match() {
+		let repository = Arc::new(InMemoryNoteRepository::new());
+		let mut tagged = note("body");
+		tagged.title = Some("Quarterly plan".to_string());
+		repository.create_note(tagged).unwrap();
+
+		assert_eq!(search(&repository, &["quarterly"], None)[0], "[ppm] 1 note(s) found:");
+	}
+
+	#[test]
+	fn date_filters_use_the_local_date() {
+		let repository = Arc::new(InMemoryNoteRepository::new());
+		let mut late = note("late entry");
+		// The 5th in UTC, already the 6th at UTC+9
+		late.created_at = utc(2026, 1, 5, 20, 0);
+		repository.create_note(late).unwrap();
+
+		let found = search(&repository, &["entry"], Some("today"));
+		assert_eq!(found[0], "[ppm] 1 note(s) found:");
+		assert!(found[1].contains("(2026-01-06)"));
+	}
+}
@@ -0,0 +1,100 @@
+use std::sync::Arc;
+
+use crate::clock::Clock;
+use crate::errors::{PPMError, PPMResult};
+use crate::models::NoteId;
+use crate::output::OutputWriter;
+use crate::repositories::note::NoteRepository;
+use crate::services::{Service, format_local_time};
+
+/// Service to print a note in full, with its times in local time.
+pub struct ShowNote {
+	pub clock: Arc<dyn Clock>,
+	pub note_repository: Arc<dyn NoteRepository>,
+	pub output_writer: Arc<dyn OutputWriter>,
+	pub note_id: NoteId,
+}
+
+impl Service for ShowNote {
+	fn run(&self) -> PPMResult<()> {
+		let note_id = self.note_repository.resolve_note_id(&self.note_id)?;
+		let note = self
+			.note_repository
+			.get_note(&note_id)?
+			.ok_or_else(|| PPMError::NotFound(format!("Note {} not found", note_id)))?;
+
+		self.output_writer.write_line(&format!("Note {}", note.id))?;
+		if let Some(ref title) = note.title {
+			self.output_writer.write_line(&format!("Title: {}", title))?;
+		}
+		if let Some(ref project) = note.project_name {
+			self.output_writer.write_line(&format!("Project: {}", project))?;
+		}
+		if let Some(ref task_id) = note.task_id {
+			self.output_writer.write_line(&format!("Task: {}", task_id))?;
+		}
+		if let Some(ref session_id) = note.session_id {
+			self.output_writer.write_line(&format!("Session: {}", session_id))?;
+		}
+		let local_time = |time| format_local_time(self.clock.as_ref(), time);
+		self.output_writer.write_line(&format!("Created: {}", local_time(note.created_at)))?;
+		if let Some(updated_at) = note.updated_at {
+			self.output_writer.write_line(&format!("Updated: {}", local_time(updated_at)))?;
+		}
+
+		self.output_writer.write_line(&"")?;
+		for line in note.content.lines() {
+			self.output_writer.write_line(&line)?;
+		}
+
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use chrono::FixedOffset;
+
+	use super::*;
+	use crate::clock::FixedClock;
+	use crate::models::Note;
+	use crate::output::InMemoryWriter;
+	use crate::repositories::InMemoryNoteRepository;
+	use crate::test_support::{note, utc};
+
+	#[test]
+	fn times_are_shown_in_local_time() {
+		let repository = Arc::new(InMemoryNoteRepository::new());
+		let shown = Note {
+			created_at: utc(2026, 1, 1, 20, 0),
+			updated_at: Some(utc(2026, 1, 2, 7, 15)),
+			..note("Line one\nLine two")
+		};
+		repository.create_note(shown.clone()).unwrap();
+		let output = Arc::new(InMemoryWriter::new());
+
+		ShowNote {
+			clock: Arc::new(
+				FixedClock::new(utc(2026, 1, 10, 9, 0))
+					.with_offset(FixedOffset::west_opt(5 * 3600).unwrap()),
+			),
+			note_repository: repository,
+			output_writer: output.clone(),
+			note_id: shown.id.clone(),
+		}
+		.run()
+		.unwrap();
+
+		assert_eq!(
+			output.lines().unwrap(),
+			vec![
+				format!("[ppm] Note {}", shown.id),
+				"[ppm] Created: 2026-01-01 15:00".to_string(),
+				"[ppm] Updated: 2026-01-02 02:15".to_string(),
+				"[ppm] ".to_string(),
+				"[ppm] Line one".to_string(),
+				"[ppm] Line two".to_string(),
+			]
+		);
+	}
+}
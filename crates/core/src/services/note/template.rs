@@ -0,0 +1,80 @@
+use std::collections::BTreeMap;
+
+/// Replaces each `{{name}}` in `template` with its value.
+///
+/// Unknown placeholders are left in place and their names returned, so that templates using
+/// braces for other purposes still render.
+pub(crate) fn fill_placeholders(
+	template: &str,
+	values: &BTreeMap<&str, String>,
+) -> (String, Vec<String>) {
+	let mut filled = String::new();
+	let mut unknown = Vec::new();
+	let mut rest = template;
+
+	while let Some(start) = rest.find("{{") {
+		filled.push_str(&rest[..start]);
+		let after = &rest[start + 2..];
+
+		let Some(end) = after.find("}}") else {
+			// Unterminated: the rest, from the `{{` on, is kept as is below
+			rest = &rest[start..];
+			break;
+		};
+		let name = after[..end].trim();
+
+		match values.get(name) {
+			Some(value) => filled.push_str(value),
+			None => {
+				filled.push_str(&rest[start..start + 2 + end + 2]);
+				if !unknown.iter().any(|n| n == name) {
+					unknown.push(name.to_string());
+				}
+			}
+		}
+		rest = &after[end + 2..];
+	}
+	filled.push_str(rest);
+
+	(filled, unknown)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn values() -> BTreeMap<&'static str, String> {
+		BTreeMap::from([("date", "2026-01-05".to_string()), ("title", "Standup".to_string())])
+	}
+
+	#[test]
+	fn fills_known_placeholders() {
+		let (filled, unknown) = fill_placeholders("# {{ title }} on {{date}}", &values());
+
+		assert_eq!(filled, "# Standup on 2026-01-05");
+		assert!(unknown.is_empty());
+	}
+
+	#[test]
+	fn keeps_unknown_placeholders_once() {
+		let (filled, unknown) = fill_placeholders("{{mood}} {{date}} {{mood}}", &values());
+
+		assert_eq!(filled, "{{mood}} 2026-01-05 {{mood}}");
+		assert_eq!(unknown, vec!["mood"]);
+	}
+
+	#[test]
+	fn keeps_unterminated_braces() {
+		let (filled, unknown) = fill_placeholders("Hello {{date}} then {{ broken", &values());
+
+		assert_eq!(filled, "Hello 2026-01-05 then {{ broken");
+		assert!(unknown.is_empty());
+	}
+
+	#[test]
+	fn leaves_text_without_placeholders_alone() {
+		let (filled, _) = fill_placeholders("fn main() { println!(\"{}\", 1); }", &values());
+
+		assert_eq!(filled, "fn main() { println!(\"{}\", 1); }");
+	}
+}
@@ -1,5 +1,7 @@
 use std::sync::Arc;
 
+use chrono::{DateTime, Utc};
+
 use crate::clock::Clock;
 use crate::errors::PPMResult;
 use crate::models::{Project, ProjectName, ProjectStatus};
@@ -7,6 +9,9 @@ use crate::output::OutputWriter;
 use crate::repositories::project::ProjectRepository;
 use crate::services::Service;
 
+/// Service to create a project.
+///
+/// Missing parent projects of a path-like name (`work` for `work/api`) are created too.
 pub struct CreateProject {
 	pub clock: Arc<dyn Clock>,
 	pub project_repository: Arc<dyn ProjectRepository>,
@@ -17,16 +22,45 @@ pub struct CreateProject {
 
 impl Service for CreateProject {
 	fn run(&self) -> PPMResult<()> {
-		let project = Project {
-			name: self.name.clone(),
-			description: self.description.clone(),
-			created_at: self.clock.now()?,
-			status: ProjectStatus::Active,
-		};
+		create_with_ancestors(
+			self.project_repository.as_ref(),
+			self.output_writer.as_ref(),
+			self.clock.now()?,
+			&self.name,
+			&self.description,
+		)
+	}
+}
+
+/// Creates an active project named `name`, first creating any of its missing ancestors.
+pub(crate) fn create_with_ancestors(
+	project_repository: &dyn ProjectRepository,
+	output_writer: &dyn OutputWriter,
+	now: DateTime<Utc>,
+	name: &ProjectName,
+	description: &str,
+) -> PPMResult<()> {
+	name.validate()?;
 
-		self.project_repository.create_project(project)?;
-		self.output_writer.write_line(&format!("Project '{}' created", self.name))?;
+	for ancestor in name.ancestors() {
+		if project_repository.get_project(&ancestor)?.is_none() {
+			project_repository.create_project(new_project(ancestor.clone(), "", now))?;
+			output_writer.write_line(&format!("Project '{}' created", ancestor))?;
+		}
+	}
+
+	project_repository.create_project(new_project(name.clone(), description, now))?;
+	output_writer.write_line(&format!("Project '{}' created", name))?;
+
+	Ok(())
+}
 
-		Ok(())
+fn new_project(name: ProjectName, description: &str, now: DateTime<Utc>) -> Project {
+	Project {
+		name,
+		description: description.to_string(),
+		created_at: now,
+		status: ProjectStatus::Active,
+		milestones: Vec::new(),
 	}
 }
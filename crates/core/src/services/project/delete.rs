@@ -0,0 +1,630 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+
+use crate::clock::Clock;
+use crate::errors::{PPMError, PPMResult};
+use crate::models::{
+	FocusSession, FocusSessionId, Note, Project, ProjectName, ProjectStatus, Task, TaskId,
+	TrashEntry, TrashEntryId, TrashedItem,
+};
+use crate::output::OutputWriter;
+use crate::prompt::Prompt;
+use crate::repositories::{
+	NoteRepository, ProjectRepository, SessionRepository, TaskRepository, TrashRepository,
+};
+use crate::services::Service;
+use crate::services::trash::purge_expired;
+
+/// What happens to the tasks, notes and sessions of a deleted project.
+#[derive(Debug, Clone)]
+pub enum DeleteMode {
+	/// Move them to the trash along with the project. Links to them from other notes, sessions
+	/// and milestones are kept, like `task delete` does, so that restoring brings everything
+	/// back; until then `note links` reports them as broken.
+	Cascade,
+	/// Keep them without a project. Links between them stay as they are.
+	Orphan,
+	/// Move them to another existing project.
+	Reassign(ProjectName),
+}
+
+/// Service to move a project to the trash.
+///
+/// A project that still has tasks, notes or sessions requires a `DeleteMode`. The affected
+/// counts are shown and confirmed before anything changes, unless `skip_confirmation` is set.
+/// If a cascading delete fails part way, the updates already made are reverted.
+pub struct DeleteProject {
+	pub clock: Arc<dyn Clock>,
+	pub project_repository: Arc<dyn ProjectRepository>,
+	pub task_repository: Arc<dyn TaskRepository>,
+	pub note_repository: Arc<dyn NoteRepository>,
+	pub session_repository: Arc<dyn SessionRepository>,
+	pub trash_repository: Arc<dyn TrashRepository>,
+	pub output_writer: Arc<dyn OutputWriter>,
+	pub prompt: Arc<dyn Prompt>,
+	pub trash_retention_days: u32,
+	pub name: ProjectName,
+	pub mode: Option<DeleteMode>,
+	pub skip_confirmation: bool,
+}
+
+/// Items referring to the deleted project.
+struct References {
+	tasks: Vec<Task>,
+	notes: Vec<Note>,
+	sessions: Vec<FocusSession>,
+}
+
+impl References {
+	fn is_empty(&self) -> bool {
+		self.tasks.is_empty() && self.notes.is_empty() && self.sessions.is_empty()
+	}
+
+	fn summary(&self) -> String {
+		format!(
+			"{} task(s), {} note(s) and {} session(s)",
+			self.tasks.len(),
+			self.notes.len(),
+			self.sessions.len()
+		)
+	}
+}
+
+/// Items kept by a cascading delete that link to the tasks or sessions it trashes.
+#[derive(Default)]
+struct DanglingLinks {
+	notes: Vec<Note>,
+	sessions: Vec<FocusSession>,
+	/// Other projects with milestones listing trashed tasks.
+	projects: Vec<Project>,
+}
+
+impl DanglingLinks {
+	fn is_empty(&self) -> bool {
+		self.notes.is_empty() && self.sessions.is_empty() && self.projects.is_empty()
+	}
+
+	fn summary(&self) -> String {
+		let milestones: usize = self.projects.iter().map(|p| p.milestones.len()).sum();
+		format!(
+			"{} note(s), {} session(s) and {} milestone(s)",
+			self.notes.len(),
+			self.sessions.len(),
+			milestones
+		)
+	}
+}
+
+/// Update that has been written by a cascading delete, holding what is needed to revert it.
+enum Applied {
+	/// IDs of the entries added to the trash.
+	Trashed(Vec<TrashEntryId>),
+	Tasks(Vec<Task>),
+	/// Every stored session.
+	Sessions(Vec<FocusSession>),
+	DeletedNote(Note),
+}
+
+impl DeleteProject {
+	fn collect_references(&self) -> PPMResult<References> {
+		let sessions = self
+			.session_repository
+			.list_sessions()?
+			.into_iter()
+			.filter(|s| s.associated_project_name.as_ref() == Some(&self.name))
+			.collect();
+
+		Ok(References {
+			tasks: self.task_repository.list_tasks_by_project(&self.name)?,
+			notes: self.note_repository.list_notes_by_project(&self.name)?,
+			sessions,
+		})
+	}
+
+	fn collect_dangling_links(&self, references: &References) -> PPMResult<DanglingLinks> {
+		let trashed_task = |id: &TaskId| references.tasks.iter().any(|t| &t.id == id);
+		let trashed_session = |id: &FocusSessionId| references.sessions.iter().any(|s| &s.id == id);
+
+		let notes = self
+			.note_repository
+			.list_notes()?
+			.into_iter()
+			.filter(|n| !references.notes.iter().any(|r| r.id == n.id))
+			.filter(|n| {
+				n.task_id.as_ref().is_some_and(trashed_task)
+					|| n.session_id.as_ref().is_some_and(trashed_session)
+			})
+			.collect();
+		let sessions = self
+			.session_repository
+			.list_sessions()?
+			.into_iter()
+			.filter(|s| !trashed_session(&s.id))
+			.filter(|s| s.associated_task_id.as_ref().is_some_and(trashed_task))
+			.collect();
+		let projects = self
+			.project_repository
+			.list_projects()?
+			.into_iter()
+			.filter(|p| p.name != self.name)
+			.filter_map(|p| {
+				// Only the milestones listing trashed tasks are counted
+				let milestones: Vec<_> = p
+					.milestones
+					.iter()
+					.filter(|m| m.task_ids.iter().any(trashed_task))
+					.cloned()
+					.collect();
+				(!milestones.is_empty()).then_some(Project {
+					milestones,
+					..p
+				})
+			})
+			.collect();
+
+		Ok(DanglingLinks {
+			notes,
+			sessions,
+			projects,
+		})
+	}
+
+	fn validate_reassign_target(&self, target: &ProjectName) -> PPMResult<()> {
+		if target == &self.name {
+			return Err(PPMError::InvalidInput(format!(
+				"Cannot reassign items of '{}' to itself",
+				self.name
+			)));
+		}
+
+		let project = self
+			.project_repository
+			.get_project(target)?
+			.ok_or_else(|| PPMError::NotFound(format!("Project '{}' not found", target)))?;
+		if project.status == ProjectStatus::Archived {
+			return Err(PPMError::ProjectArchived(target.to_string()));
+		}
+
+		Ok(())
+	}
+
+	/// Shows what will happen and asks to go ahead. `mode` is `None` when nothing refers to
+	/// the project.
+	fn confirm(
+		&self,
+		references: &References,
+		links: &DanglingLinks,
+		mode: Option<&DeleteMode>,
+	) -> PPMResult<()> {
+		let effect = match mode {
+			None => "No tasks, notes or sessions refer to it".to_string(),
+			Some(DeleteMode::Cascade) => {
+				format!("{} will be moved to the trash", references.summary())
+			}
+			Some(DeleteMode::Orphan) => {
+				format!("{} will be kept without a project", references.summary())
+			}
+			Some(DeleteMode::Reassign(target)) => {
+				format!("{} will be moved to project '{}'", references.summary(), target)
+			}
+		};
+		self.output_writer.write_line(&format!("Deleting project '{}'", self.name))?;
+		self.output_writer.write_line(&format!("  {}", effect))?;
+		if !links.is_empty() {
+			self.output_writer.write_line(&format!(
+				"  Links to them from {} are kept and show as broken until they are restored",
+				links.summary()
+			))?;
+		}
+
+		if self.skip_confirmation || self.prompt.confirm("Continue?")? {
+			Ok(())
+		} else {
+			Err(PPMError::NotConfirmed(format!("Deletion of project '{}'", self.name)))
+		}
+	}
+
+	fn trash_references(&self, references: References, now: DateTime<Utc>) -> PPMResult<()> {
+		let mut applied = Vec::new();
+		if let Err(e) = self.apply_cascade(references, now, &mut applied) {
+			self.output_writer.write_line(&format!(
+				"Deletion failed, rolling back {} completed update(s)",
+				applied.len()
+			))?;
+			self.rollback(applied)?;
+			return Err(e);
+		}
+
+		Ok(())
+	}
+
+	fn apply_cascade(
+		&self,
+		references: References,
+		now: DateTime<Utc>,
+		applied: &mut Vec<Applied>,
+	) -> PPMResult<()> {
+		let task_ids: Vec<TaskId> = references.tasks.iter().map(|t| t.id.clone()).collect();
+		let session_ids: Vec<FocusSessionId> =
+			references.sessions.iter().map(|s| s.id.clone()).collect();
+
+		// Trash first: a failure below leaves restorable copies rather than losing items
+		let entries: Vec<TrashEntry> = references
+			.tasks
+			.iter()
+			.cloned()
+			.map(TrashedItem::Task)
+			.chain(references.notes.iter().cloned().map(TrashedItem::Note))
+			.chain(references.sessions.iter().cloned().map(TrashedItem::Session))
+			.map(|item| TrashEntry::new(item, now))
+			.collect();
+		let trashed_ids = entries.iter().map(|e| e.id.clone()).collect();
+		self.trash_repository.add_entries(entries)?;
+		applied.push(Applied::Trashed(trashed_ids));
+
+		if !task_ids.is_empty() {
+			self.task_repository.delete_tasks(&task_ids)?;
+			applied.push(Applied::Tasks(references.tasks));
+		}
+
+		// Sessions share one file, so they are dropped in a single write
+		if !session_ids.is_empty() {
+			let sessions = self.session_repository.list_sessions()?;
+			let kept = sessions.iter().filter(|s| !session_ids.contains(&s.id)).cloned().collect();
+			self.session_repository.replace_sessions(kept)?;
+			applied.push(Applied::Sessions(sessions));
+		}
+
+		// Notes are stored one file each, so each one is its own step
+		for note in references.notes {
+			self.note_repository.delete_note(&note.id)?;
+			applied.push(Applied::DeletedNote(note));
+		}
+
+		Ok(())
+	}
+
+	fn rollback(&self, applied: Vec<Applied>) -> PPMResult<()> {
+		for step in applied.into_iter().rev() {
+			let (description, result) = match step {
+				Applied::Trashed(ids) => (
+					format!("{} trash entries", ids.len()),
+					self.trash_repository.remove_entries(&ids).map(|_| ()),
+				),
+				Applied::Tasks(originals) => (
+					format!("{} task(s)", originals.len()),
+					self.task_repository.upsert_tasks(originals),
+				),
+				Applied::Sessions(originals) => (
+					format!("{} session(s)", originals.len()),
+					self.session_repository.replace_sessions(originals),
+				),
+				Applied::DeletedNote(original) => {
+					(format!("note {}", original.id), self.note_repository.create_note(original))
+				}
+			};
+
+			if let Err(e) = result {
+				self.output_writer
+					.write_line(&format!("Failed to roll back {}: {}", description, e))?;
+			}
+		}
+
+		Ok(())
+	}
+
+	fn move_references(
+		&self,
+		references: References,
+		target: Option<&ProjectName>,
+	) -> PPMResult<()> {
+		if !references.tasks.is_empty() {
+			self.task_repository.upsert_tasks(
+				references
+					.tasks
+					.into_iter()
+					.map(|t| Task {
+						project_name: target.cloned(),
+						..t
+					})
+					.collect(),
+			)?;
+		}
+		if !references.sessions.is_empty() {
+			self.session_repository.update_sessions(
+				references
+					.sessions
+					.into_iter()
+					.map(|s| FocusSession {
+						associated_project_name: target.cloned(),
+						..s
+					})
+					.collect(),
+			)?;
+		}
+		for note in references.notes {
+			self.note_repository.update_note(Note {
+				project_name: target.cloned(),
+				..note
+			})?;
+		}
+
+		Ok(())
+	}
+}
+
+impl Service for DeleteProject {
+	fn run(&self) -> PPMResult<()> {
+		let now = self.clock.now()?;
+		purge_expired(
+			self.trash_repository.as_ref(),
+			self.output_writer.as_ref(),
+			now,
+			self.trash_retention_days,
+		)?;
+
+		let project = self
+			.project_repository
+			.get_project(&self.name)?
+			.ok_or_else(|| PPMError::NotFound(format!("Project '{}' not found", self.name)))?;
+
+		let subprojects: Vec<String> = self
+			.project_repository
+			.list_projects()?
+			.into_iter()
+			.filter(|p| p.name != self.name && self.name.includes(&p.name))
+			.map(|p| p.name.to_string())
+			.collect();
+		if !subprojects.is_empty() {
+			return Err(PPMError::InvalidInput(format!(
+				"Project '{}' has subprojects ({}); delete or rename them first",
+				self.name,
+				subprojects.join(", ")
+			)));
+		}
+
+		let references = self.collect_references()?;
+		let mode = if references.is_empty() {
+			None
+		} else {
+			Some(self.mode.as_ref().ok_or_else(|| {
+				PPMError::InvalidInput(format!(
+					"Project '{}' still has {}; choose whether to cascade, orphan or reassign them",
+					self.name,
+					references.summary()
+				))
+			})?)
+		};
+		if let Some(DeleteMode::Reassign(target)) = &self.mode {
+			self.validate_reassign_target(target)?;
+		}
+
+		let links = match mode {
+			Some(DeleteMode::Cascade) => self.collect_dangling_links(&references)?,
+			_ => DanglingLinks::default(),
+		};
+
+		self.confirm(&references, &links, mode)?;
+
+		let summary = references.summary();
+		match mode {
+			Some(DeleteMode::Cascade) => self.trash_references(references, now)?,
+			Some(DeleteMode::Orphan) => self.move_references(references, None)?,
+			Some(DeleteMode::Reassign(target)) => self.move_references(references, Some(target))?,
+			None => {}
+		}
+
+		self.trash_repository
+			.add_entries(vec![TrashEntry::new(TrashedItem::Project(project), now)])?;
+		self.project_repository.delete_project(&self.name)?;
+
+		self.output_writer.write_line(&format!("Project '{}' moved to trash", self.name))?;
+		if let Some(mode) = mode {
+			let outcome = match mode {
+				DeleteMode::Cascade => "moved to trash".to_string(),
+				DeleteMode::Orphan => "detached".to_string(),
+				DeleteMode::Reassign(target) => format!("moved to project '{}'", target),
+			};
+			self.output_writer.write_line(&format!("{} {}", summary, outcome))?;
+		}
+
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::sync::atomic::Ordering;
+
+	use chrono::NaiveDate;
+
+	use super::*;
+	use crate::clock::FixedClock;
+	use crate::models::Milestone;
+	use crate::output::InMemoryWriter;
+	use crate::prompt::FixedPrompt;
+	use crate::repositories::{
+		InMemoryProjectRepository, InMemorySessionRepository, InMemoryTaskRepository,
+		InMemoryTrashRepository,
+	};
+	use crate::services::trash::RestoreTrash;
+	use crate::test_support::{FailingNoteRepository, note, project, session, task, utc};
+
+	struct Fixture {
+		projects: Arc<InMemoryProjectRepository>,
+		tasks: Arc<InMemoryTaskRepository>,
+		notes: Arc<FailingNoteRepository>,
+		sessions: Arc<InMemorySessionRepository>,
+		trash: Arc<InMemoryTrashRepository>,
+		/// Task of `api`, linked from a session, notes and a `web` milestone.
+		api_task: Task,
+		web_task: Task,
+	}
+
+	impl Fixture {
+		fn new() -> Self {
+			let fixture = Self {
+				projects: Arc::new(InMemoryProjectRepository::new()),
+				tasks: Arc::new(InMemoryTaskRepository::new()),
+				notes: Arc::new(FailingNoteRepository::default()),
+				sessions: Arc::new(InMemorySessionRepository::new()),
+				trash: Arc::new(InMemoryTrashRepository::new()),
+				api_task: Task {
+					project_name: Some(ProjectName::from("api")),
+					..task("Design endpoints")
+				},
+				web_task: Task {
+					project_name: Some(ProjectName::from("web")),
+					..task("Build pages")
+				},
+			};
+
+			fixture.projects.create_project(project("api")).unwrap();
+			fixture
+				.projects
+				.create_project(Project {
+					milestones: vec![Milestone {
+						name: "Launch".to_string(),
+						target: NaiveDate::from_ymd_opt(2026, 3, 1).unwrap(),
+						created_at: utc(2026, 1, 1, 0, 0),
+						task_ids: vec![fixture.api_task.id.clone(), fixture.web_task.id.clone()],
+					}],
+					..project("web")
+				})
+				.unwrap();
+			fixture.tasks.create_task(fixture.api_task.clone()).unwrap();
+			fixture.tasks.create_task(fixture.web_task.clone()).unwrap();
+
+			let api_session = FocusSession {
+				associated_project_name: Some(ProjectName::from("api")),
+				associated_task_id: Some(fixture.api_task.id.clone()),
+				..session(utc(2026, 1, 2, 9, 0), 30)
+			};
+			fixture.sessions.create_session(api_session.clone()).unwrap();
+			fixture
+				.sessions
+				.create_session(FocusSession {
+					associated_project_name: Some(ProjectName::from("web")),
+					associated_task_id: Some(fixture.api_task.id.clone()),
+					..session(utc(2026, 1, 2, 10, 0), 30)
+				})
+				.unwrap();
+
+			for note in [
+				Note {
+					project_name: Some(ProjectName::from("api")),
+					..note("API design")
+				},
+				Note {
+					task_id: Some(fixture.api_task.id.clone()),
+					..note("About the endpoints")
+				},
+				Note {
+					session_id: Some(api_session.id.clone()),
+					..note("Session log")
+				},
+			] {
+				fixture.notes.create_note(note).unwrap();
+			}
+
+			fixture
+		}
+
+		fn delete(&self, output_writer: Arc<InMemoryWriter>) -> PPMResult<()> {
+			DeleteProject {
+				clock: Arc::new(FixedClock::new(utc(2026, 1, 10, 12, 0))),
+				project_repository: self.projects.clone(),
+				task_repository: self.tasks.clone(),
+				note_repository: self.notes.clone(),
+				session_repository: self.sessions.clone(),
+				trash_repository: self.trash.clone(),
+				output_writer,
+				prompt: Arc::new(FixedPrompt::new(true)),
+				trash_retention_days: 30,
+				name: ProjectName::from("api"),
+				mode: Some(DeleteMode::Cascade),
+				skip_confirmation: false,
+			}
+			.run()
+		}
+
+		fn web_milestone_tasks(&self) -> Vec<TaskId> {
+			let web = self.projects.get_project(&ProjectName::from("web")).unwrap().unwrap();
+			web.milestones[0].task_ids.clone()
+		}
+	}
+
+	#[test]
+	fn cascade_keeps_links_so_restore_undoes_it() {
+		let fixture = Fixture::new();
+		let output = Arc::new(InMemoryWriter::new());
+		fixture.delete(output.clone()).unwrap();
+
+		let lines = output.lines().unwrap();
+		assert!(lines.iter().any(|l| {
+			l.contains("Links to them from 2 note(s), 1 session(s) and 1 milestone(s) are kept")
+		}));
+
+		let tasks = fixture.tasks.list_tasks().unwrap();
+		assert_eq!(tasks.len(), 1);
+		assert_eq!(tasks[0].id, fixture.web_task.id);
+		let sessions = fixture.sessions.list_sessions().unwrap();
+		assert_eq!(sessions.len(), 1);
+		assert_eq!(sessions[0].associated_task_id.as_ref(), Some(&fixture.api_task.id));
+		let notes = fixture.notes.list_notes().unwrap();
+		assert_eq!(notes.len(), 2);
+		assert!(notes.iter().all(|n| n.task_id.is_some() || n.session_id.is_some()));
+		assert_eq!(fixture.web_milestone_tasks().len(), 2);
+		assert!(fixture.projects.get_project(&ProjectName::from("api")).unwrap().is_none());
+
+		// The project, its task, session and note; restoring them undoes the delete
+		let entries = fixture.trash.list_entries().unwrap();
+		assert_eq!(entries.len(), 4);
+		RestoreTrash {
+			clock: Arc::new(FixedClock::new(utc(2026, 1, 11, 12, 0))),
+			trash_repository: fixture.trash.clone(),
+			task_repository: fixture.tasks.clone(),
+			project_repository: fixture.projects.clone(),
+			note_repository: fixture.notes.clone(),
+			session_repository: fixture.sessions.clone(),
+			output_writer: Arc::new(InMemoryWriter::new()),
+			retention_days: 30,
+			item_ids: entries.iter().map(|e| e.id.to_string()).collect(),
+		}
+		.run()
+		.unwrap();
+
+		assert_eq!(fixture.tasks.list_tasks().unwrap().len(), 2);
+		let sessions = fixture.sessions.list_sessions().unwrap();
+		assert_eq!(sessions.len(), 2);
+		assert!(
+			sessions.iter().all(|s| s.associated_task_id.as_ref() == Some(&fixture.api_task.id))
+		);
+		assert_eq!(fixture.notes.list_notes().unwrap().len(), 3);
+		assert!(fixture.projects.get_project(&ProjectName::from("api")).unwrap().is_some());
+	}
+
+	#[test]
+	fn failed_cascade_is_rolled_back() {
+		let fixture = Fixture::new();
+		fixture.notes.fail_writes.store(true, Ordering::Relaxed);
+		let output = Arc::new(InMemoryWriter::new());
+		assert!(fixture.delete(output.clone()).is_err());
+
+		let lines = output.lines().unwrap();
+		assert!(lines.iter().any(|l| l.contains("rolling back 3 completed update(s)")));
+		assert!(!lines.iter().any(|l| l.contains("Failed to roll back")));
+
+		assert_eq!(fixture.tasks.list_tasks().unwrap().len(), 2);
+		let sessions = fixture.sessions.list_sessions().unwrap();
+		assert_eq!(sessions.len(), 2);
+		assert!(
+			sessions.iter().all(|s| s.associated_task_id.as_ref() == Some(&fixture.api_task.id))
+		);
+		assert_eq!(fixture.web_milestone_tasks().len(), 2);
+		assert_eq!(fixture.notes.list_notes().unwrap().len(), 3);
+		assert!(fixture.projects.get_project(&ProjectName::from("api")).unwrap().is_some());
+		assert!(fixture.trash.list_entries().unwrap().is_empty());
+	}
+}
@@ -0,0 +1,90 @@
+use std::sync::Arc;
+
+use crate::errors::{PPMError, PPMResult};
+use crate::models::{ProjectName, ProjectStatus};
+use crate::output::OutputWriter;
+use crate::repositories::project::ProjectRepository;
+use crate::services::Service;
+
+/// Service to make a project active again, including archived ones.
+pub struct ActivateProject {
+	pub project_repository: Arc<dyn ProjectRepository>,
+	pub output_writer: Arc<dyn OutputWriter>,
+	pub name: ProjectName,
+}
+
+/// Service to pause a project. New work on it is still allowed, with a warning.
+pub struct DeactivateProject {
+	pub project_repository: Arc<dyn ProjectRepository>,
+	pub output_writer: Arc<dyn OutputWriter>,
+	pub name: ProjectName,
+}
+
+/// Service to archive a finished project.
+pub struct ArchiveProject {
+	pub project_repository: Arc<dyn ProjectRepository>,
+	pub output_writer: Arc<dyn OutputWriter>,
+	pub name: ProjectName,
+}
+
+impl Service for ActivateProject {
+	fn run(&self) -> PPMResult<()> {
+		change_status(
+			self.project_repository.as_ref(),
+			self.output_writer.as_ref(),
+			&self.name,
+			ProjectStatus::Active,
+		)
+	}
+}
+
+impl Service for DeactivateProject {
+	fn run(&self) -> PPMResult<()> {
+		change_status(
+			self.project_repository.as_ref(),
+			self.output_writer.as_ref(),
+			&self.name,
+			ProjectStatus::Inactive,
+		)
+	}
+}
+
+impl Service for ArchiveProject {
+	fn run(&self) -> PPMResult<()> {
+		change_status(
+			self.project_repository.as_ref(),
+			self.output_writer.as_ref(),
+			&self.name,
+			ProjectStatus::Archived,
+		)
+	}
+}
+
+fn change_status(
+	project_repository: &dyn ProjectRepository,
+	output_writer: &dyn OutputWriter,
+	name: &ProjectName,
+	status: ProjectStatus,
+) -> PPMResult<()> {
+	let project = project_repository
+		.get_project(name)?
+		.ok_or_else(|| PPMError::NotFound(format!("Project '{}' not found", name)))?;
+
+	let label = status_label(&status);
+	if project.status == status {
+		return Err(PPMError::InvalidInput(format!("Project '{}' is already {}", name, label)));
+	}
+
+	project_repository.update_project_status(name, status)?;
+	output_writer.write_line(&format!("Project '{}' is now {}", name, label))?;
+
+	Ok(())
+}
+
+fn status_label(status: &ProjectStatus) -> &'static str {
+	match status {
+		ProjectStatus::Active => "active",
+		ProjectStatus::Inactive => "inactive",
+		ProjectStatus::Archived => "archived",
+	}
+}
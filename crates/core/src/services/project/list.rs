@@ -17,6 +17,7 @@ pub enum ProjectFilter {
 	All,
 	Active,
 	Inactive,
+	Archived,
 }
 
 impl Service for ListProjects {
@@ -24,18 +25,21 @@ impl Service for ListProjects {
 		let mut projects = self.project_repository.list_projects()?;
 
 		// Sort by created_at descending (newest first)
-		projects.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+		projects.sort_by_key(|p| std::cmp::Reverse(p.created_at));
 
-		// Apply filter
+		// Apply filter; archived projects are hidden unless asked for
 		let filtered_projects: Vec<Project> = match self.filter {
 			Some(ProjectFilter::Active) => {
-				projects.into_iter().filter(|p| matches!(p.status, ProjectStatus::Active)).collect()
+				projects.into_iter().filter(|p| p.status == ProjectStatus::Active).collect()
 			}
-			Some(ProjectFilter::Inactive) => projects
-				.into_iter()
-				.filter(|p| matches!(p.status, ProjectStatus::Inactive))
-				.collect(),
-			Some(ProjectFilter::All) | None => projects,
+			Some(ProjectFilter::Inactive) => {
+				projects.into_iter().filter(|p| p.status == ProjectStatus::Inactive).collect()
+			}
+			Some(ProjectFilter::Archived) => {
+				projects.into_iter().filter(|p| p.status == ProjectStatus::Archived).collect()
+			}
+			Some(ProjectFilter::All) => projects,
+			None => projects.into_iter().filter(|p| p.status != ProjectStatus::Archived).collect(),
 		};
 
 		if filtered_projects.is_empty() {
@@ -45,18 +49,62 @@ impl Service for ListProjects {
 
 		self.output_writer.write_line(&format!("{} project(s) found:", filtered_projects.len()))?;
 
-		for project in filtered_projects {
-			let status_display = match &project.status {
-				ProjectStatus::Active => "[Active]",
-				ProjectStatus::Inactive => "[Inactive]",
-			};
+		for root in
+			filtered_projects.iter().filter(|p| listed_parent(p, &filtered_projects).is_none())
+		{
+			self.write_tree(root, None, 0, &filtered_projects)?;
+		}
+
+		Ok(())
+	}
+}
+
+impl ListProjects {
+	/// Writes `project` and, indented below it, the listed projects it is the parent of.
+	fn write_tree(
+		&self,
+		project: &Project,
+		parent: Option<&Project>,
+		depth: usize,
+		listed: &[Project],
+	) -> PPMResult<()> {
+		let status_display = match &project.status {
+			ProjectStatus::Active => "[Active]",
+			ProjectStatus::Inactive => "[Inactive]",
+			ProjectStatus::Archived => "[Archived]",
+		};
+
+		// Below a listed parent only the relative part of the name is shown
+		let name = match parent {
+			Some(parent) => &project.name[parent.name.len() + 1..],
+			None => project.name.as_ref(),
+		};
 
-			self.output_writer.write_line(&format!(
-				"  {} {} - {}",
-				status_display, project.name, project.description
-			))?;
+		self.output_writer.write_line(&format!(
+			"  {}{} {} - {}",
+			"  ".repeat(depth),
+			status_display,
+			name,
+			project.description
+		))?;
+
+		for child in listed
+			.iter()
+			.filter(|p| listed_parent(p, listed).is_some_and(|parent| parent.name == project.name))
+		{
+			self.write_tree(child, Some(project), depth + 1, listed)?;
 		}
 
 		Ok(())
 	}
 }
+
+/// Closest ancestor of `project` among the listed projects.
+fn listed_parent<'a>(project: &Project, listed: &'a [Project]) -> Option<&'a Project> {
+	project
+		.name
+		.ancestors()
+		.into_iter()
+		.rev()
+		.find_map(|ancestor| listed.iter().find(|p| p.name == ancestor))
+}
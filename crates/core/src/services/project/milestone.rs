@@ -0,0 +1,156 @@
+use std::sync::Arc;
+
+use chrono::NaiveDate;
+
+use crate::clock::Clock;
+use crate::errors::{PPMError, PPMResult};
+use crate::models::{Milestone, Project, ProjectName, TaskId};
+use crate::output::OutputWriter;
+use crate::repositories::{ProjectRepository, TaskRepository};
+use crate::services::Service;
+
+/// Service to add a milestone to a project.
+pub struct AddMilestone {
+	pub clock: Arc<dyn Clock>,
+	pub project_repository: Arc<dyn ProjectRepository>,
+	pub output_writer: Arc<dyn OutputWriter>,
+	pub project_name: ProjectName,
+	pub name: String,
+	pub target: NaiveDate,
+}
+
+/// Service to remove a milestone from a project. Linked tasks are kept.
+pub struct RemoveMilestone {
+	pub project_repository: Arc<dyn ProjectRepository>,
+	pub output_writer: Arc<dyn OutputWriter>,
+	pub project_name: ProjectName,
+	pub name: String,
+}
+
+/// Service to link tasks to, or unlink them from, a milestone.
+pub struct LinkMilestoneTasks {
+	pub project_repository: Arc<dyn ProjectRepository>,
+	pub task_repository: Arc<dyn TaskRepository>,
+	pub output_writer: Arc<dyn OutputWriter>,
+	pub project_name: ProjectName,
+	pub name: String,
+	/// Task IDs, sequence numbers or unique prefixes/suffixes
+	pub link: Vec<String>,
+	pub unlink: Vec<String>,
+}
+
+impl Service for AddMilestone {
+	fn run(&self) -> PPMResult<()> {
+		let name = self.name.trim();
+		if name.is_empty() {
+			return Err(PPMError::InvalidInput("Milestone name cannot be empty".to_string()));
+		}
+
+		let mut project = get_project(self.project_repository.as_ref(), &self.project_name)?;
+		if project.milestone(name).is_some() {
+			return Err(PPMError::AlreadyExists(format!(
+				"Milestone '{}' already exists in project '{}'",
+				name, self.project_name
+			)));
+		}
+
+		project.milestones.push(Milestone {
+			name: name.to_string(),
+			target: self.target,
+			created_at: self.clock.now()?,
+			task_ids: Vec::new(),
+		});
+		self.project_repository.update_project(&self.project_name, project)?;
+
+		self.output_writer.write_line(&format!(
+			"Milestone '{}' added to project '{}' (target {})",
+			name, self.project_name, self.target
+		))?;
+
+		Ok(())
+	}
+}
+
+impl Service for RemoveMilestone {
+	fn run(&self) -> PPMResult<()> {
+		let mut project = get_project(self.project_repository.as_ref(), &self.project_name)?;
+		let milestone = find_milestone(&project, &self.name)?.name.clone();
+
+		project.milestones.retain(|m| m.name != milestone);
+		self.project_repository.update_project(&self.project_name, project)?;
+
+		self.output_writer.write_line(&format!(
+			"Milestone '{}' removed from project '{}'",
+			milestone, self.project_name
+		))?;
+
+		Ok(())
+	}
+}
+
+impl Service for LinkMilestoneTasks {
+	fn run(&self) -> PPMResult<()> {
+		let mut project = get_project(self.project_repository.as_ref(), &self.project_name)?;
+		let milestone_name = find_milestone(&project, &self.name)?.name.clone();
+
+		let resolve = |inputs: &[String]| -> PPMResult<Vec<TaskId>> {
+			inputs.iter().map(|input| self.task_repository.resolve_task_id(input)).collect()
+		};
+		let link = resolve(&self.link)?;
+		let unlink = resolve(&self.unlink)?;
+
+		for task_id in &link {
+			let task = self.task_repository.get_task(task_id)?;
+			if let Some(task) = task
+				&& !task.project_name.as_ref().is_some_and(|name| self.project_name.includes(name))
+			{
+				self.output_writer.write_line(&format!(
+					"Warning: task {} is not part of project '{}'",
+					task_id, self.project_name
+				))?;
+			}
+		}
+
+		let Some(milestone) = project.milestone_mut(&milestone_name) else {
+			return Err(PPMError::NotFound(format!("Milestone '{}' not found", milestone_name)));
+		};
+		for task_id in &link {
+			if !milestone.task_ids.contains(task_id) {
+				milestone.task_ids.push(task_id.clone());
+			}
+		}
+		milestone.task_ids.retain(|id| !unlink.contains(id));
+
+		self.project_repository.update_project(&self.project_name, project)?;
+
+		for task_id in &link {
+			self.output_writer.write_line(&format!(
+				"Task {} linked to milestone '{}'",
+				task_id, milestone_name
+			))?;
+		}
+		for task_id in &unlink {
+			self.output_writer.write_line(&format!(
+				"Task {} unlinked from milestone '{}'",
+				task_id, milestone_name
+			))?;
+		}
+
+		Ok(())
+	}
+}
+
+fn get_project(
+	project_repository: &dyn ProjectRepository,
+	project_name: &ProjectName,
+) -> PPMResult<Project> {
+	project_repository
+		.get_project(project_name)?
+		.ok_or_else(|| PPMError::NotFound(format!("Project '{}' not found", project_name)))
+}
+
+fn find_milestone<'a>(project: &'a Project, name: &str) -> PPMResult<&'a Milestone> {
+	project.milestone(name).ok_or_else(|| {
+		PPMError::NotFound(format!("Milestone '{}' not found in project '{}'", name, project.name))
+	})
+}
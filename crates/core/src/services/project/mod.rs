@@ -1,5 +1,17 @@
 pub mod create;
+pub mod delete;
+pub mod lifecycle;
 pub mod list;
+pub mod milestone;
+mod reference;
+pub mod rename;
+pub mod show;
 
 pub use create::CreateProject;
+pub use delete::{DeleteMode, DeleteProject};
+pub use lifecycle::{ActivateProject, ArchiveProject, DeactivateProject};
 pub use list::{ListProjects, ProjectFilter};
+pub use milestone::{AddMilestone, LinkMilestoneTasks, RemoveMilestone};
+pub(crate) use reference::ensure_accepts_work;
+pub use rename::RenameProject;
+pub use show::ShowProject;
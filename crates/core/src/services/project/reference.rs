@@ -0,0 +1,86 @@
+use chrono::{DateTime, Utc};
+
+use crate::config::ReferenceMode;
+use crate::errors::{PPMError, PPMResult};
+use crate::models::{ProjectName, ProjectStatus};
+use crate::output::OutputWriter;
+use crate::prompt::Prompt;
+use crate::repositories::project::ProjectRepository;
+use crate::services::project::create::create_with_ancestors;
+
+/// Checks that new tasks, sessions or notes may refer to `project_name`.
+///
+/// Inactive projects only produce a warning and archived projects are refused. For a project
+/// that does not exist, the user is offered to create it; if they decline, `mode` decides
+/// between refusing and warning. Both suggest the closest existing name.
+pub(crate) fn ensure_accepts_work(
+	project_repository: &dyn ProjectRepository,
+	output_writer: &dyn OutputWriter,
+	prompt: &dyn Prompt,
+	mode: ReferenceMode,
+	now: DateTime<Utc>,
+	project_name: &ProjectName,
+) -> PPMResult<()> {
+	if let Some(project) = project_repository.get_project(project_name)? {
+		return match project.status {
+			ProjectStatus::Archived => Err(PPMError::ProjectArchived(project_name.to_string())),
+			ProjectStatus::Inactive => output_writer
+				.write_line(&format!("Warning: project '{}' is inactive", project_name)),
+			ProjectStatus::Active => Ok(()),
+		};
+	}
+
+	let projects = project_repository.list_projects()?;
+	let hint = closest_name(project_name.as_ref(), projects.iter().map(|p| p.name.as_ref()))
+		.map(|name| format!(" (did you mean '{}'?)", name))
+		.unwrap_or_default();
+
+	if project_name.validate().is_ok()
+		&& prompt
+			.confirm(&format!("Project '{}' does not exist{}. Create it?", project_name, hint))?
+	{
+		return create_with_ancestors(project_repository, output_writer, now, project_name, "");
+	}
+
+	match mode {
+		ReferenceMode::Strict => {
+			Err(PPMError::NotFound(format!("Project '{}' does not exist{}", project_name, hint)))
+		}
+		ReferenceMode::Lenient => output_writer
+			.write_line(&format!("Warning: project '{}' does not exist{}", project_name, hint)),
+	}
+}
+
+/// Finds the candidate most likely meant by `input`: the one with the smallest edit distance,
+/// as long as the distance is small for the input's length or one name contains the other.
+fn closest_name<'a>(input: &str, candidates: impl IntoIterator<Item = &'a str>) -> Option<&'a str> {
+	let input = input.to_lowercase();
+	let max_distance = (input.chars().count() / 3).max(1);
+
+	candidates
+		.into_iter()
+		.filter_map(|candidate| {
+			let lowered = candidate.to_lowercase();
+			let distance = levenshtein(&input, &lowered);
+			let related = lowered.contains(&input) || input.contains(&lowered);
+			(distance <= max_distance || related).then_some((distance, candidate))
+		})
+		.min_by_key(|(distance, _)| *distance)
+		.map(|(_, candidate)| candidate)
+}
+
+fn levenshtein(a: &str, b: &str) -> usize {
+	let b: Vec<char> = b.chars().collect();
+	let mut previous: Vec<usize> = (0..=b.len()).collect();
+
+	for (i, ca) in a.chars().enumerate() {
+		let mut current = vec![i + 1; b.len() + 1];
+		for (j, cb) in b.iter().enumerate() {
+			let substitution = previous[j] + usize::from(ca != *cb);
+			current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+		}
+		previous = current;
+	}
+
+	previous[b.len()]
+}
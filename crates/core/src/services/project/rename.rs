@@ -0,0 +1,363 @@
+use std::sync::Arc;
+
+use crate::errors::{PPMError, PPMResult};
+use crate::models::{FocusSession, Note, Project, ProjectName, Task};
+use crate::output::OutputWriter;
+use crate::repositories::{NoteRepository, ProjectRepository, SessionRepository, TaskRepository};
+use crate::services::Service;
+
+/// Service to rename a project and every task, note and session referring to it.
+///
+/// Subprojects move along with it: renaming `work` to `job` turns `work/api` into `job/api`.
+/// The stores are updated one after another. If any update fails, the updates already made
+/// are reverted in reverse order before the error is returned.
+pub struct RenameProject {
+	pub project_repository: Arc<dyn ProjectRepository>,
+	pub task_repository: Arc<dyn TaskRepository>,
+	pub note_repository: Arc<dyn NoteRepository>,
+	pub session_repository: Arc<dyn SessionRepository>,
+	pub output_writer: Arc<dyn OutputWriter>,
+	pub old_name: ProjectName,
+	pub new_name: ProjectName,
+}
+
+/// Update that has been written, holding the original data needed to revert it.
+enum Applied {
+	/// Original project and the name it was stored under.
+	Project(Project, ProjectName),
+	Tasks(Vec<Task>),
+	Sessions(Vec<FocusSession>),
+	Note(Note),
+}
+
+/// Projects and references to rename, as stored before the rename.
+struct Targets {
+	projects: Vec<Project>,
+	tasks: Vec<Task>,
+	sessions: Vec<FocusSession>,
+	notes: Vec<Note>,
+}
+
+impl RenameProject {
+	fn in_subtree(&self, project_name: Option<&ProjectName>) -> bool {
+		project_name.is_some_and(|name| self.old_name.includes(name))
+	}
+
+	fn renamed(&self, project_name: &ProjectName) -> ProjectName {
+		project_name.rebase(&self.old_name, &self.new_name).unwrap_or_else(|| project_name.clone())
+	}
+
+	fn collect_targets(&self) -> PPMResult<Targets> {
+		let projects = self
+			.project_repository
+			.list_projects()?
+			.into_iter()
+			.filter(|p| self.in_subtree(Some(&p.name)))
+			.collect();
+		let tasks = self
+			.task_repository
+			.list_tasks()?
+			.into_iter()
+			.filter(|t| self.in_subtree(t.project_name.as_ref()))
+			.collect();
+		let sessions = self
+			.session_repository
+			.list_sessions()?
+			.into_iter()
+			.filter(|s| self.in_subtree(s.associated_project_name.as_ref()))
+			.collect();
+		let notes = self
+			.note_repository
+			.list_notes()?
+			.into_iter()
+			.filter(|n| self.in_subtree(n.project_name.as_ref()))
+			.collect();
+
+		Ok(Targets {
+			projects,
+			tasks,
+			sessions,
+			notes,
+		})
+	}
+
+	fn apply(&self, targets: &Targets, applied: &mut Vec<Applied>) -> PPMResult<()> {
+		for project in &targets.projects {
+			let new_name = self.renamed(&project.name);
+			self.project_repository.update_project(
+				&project.name,
+				Project {
+					name: new_name.clone(),
+					..project.clone()
+				},
+			)?;
+			applied.push(Applied::Project(project.clone(), new_name));
+		}
+
+		if !targets.tasks.is_empty() {
+			let renamed = targets
+				.tasks
+				.iter()
+				.map(|t| Task {
+					project_name: t.project_name.as_ref().map(|p| self.renamed(p)),
+					..t.clone()
+				})
+				.collect();
+			self.task_repository.upsert_tasks(renamed)?;
+			applied.push(Applied::Tasks(targets.tasks.clone()));
+		}
+
+		if !targets.sessions.is_empty() {
+			let renamed = targets
+				.sessions
+				.iter()
+				.map(|s| FocusSession {
+					associated_project_name: s
+						.associated_project_name
+						.as_ref()
+						.map(|p| self.renamed(p)),
+					..s.clone()
+				})
+				.collect();
+			self.session_repository.update_sessions(renamed)?;
+			applied.push(Applied::Sessions(targets.sessions.clone()));
+		}
+
+		// Notes are stored one file each, so each one is its own step
+		for note in &targets.notes {
+			self.note_repository.update_note(Note {
+				project_name: note.project_name.as_ref().map(|p| self.renamed(p)),
+				..note.clone()
+			})?;
+			applied.push(Applied::Note(note.clone()));
+		}
+
+		Ok(())
+	}
+
+	fn rollback(&self, applied: Vec<Applied>) -> PPMResult<()> {
+		for step in applied.into_iter().rev() {
+			let (description, result) = match step {
+				Applied::Project(original, stored_as) => (
+					format!("project '{}'", original.name),
+					self.project_repository.update_project(&stored_as, original),
+				),
+				Applied::Tasks(originals) => (
+					format!("{} task(s)", originals.len()),
+					self.task_repository.upsert_tasks(originals),
+				),
+				Applied::Sessions(originals) => (
+					format!("{} session(s)", originals.len()),
+					self.session_repository.update_sessions(originals),
+				),
+				Applied::Note(original) => {
+					(format!("note {}", original.id), self.note_repository.update_note(original))
+				}
+			};
+
+			if let Err(e) = result {
+				self.output_writer
+					.write_line(&format!("Failed to roll back {}: {}", description, e))?;
+			}
+		}
+
+		Ok(())
+	}
+}
+
+impl Service for RenameProject {
+	fn run(&self) -> PPMResult<()> {
+		self.new_name.validate()?;
+		if self.new_name == self.old_name {
+			return Err(PPMError::InvalidInput(format!(
+				"Project is already named '{}'",
+				self.old_name
+			)));
+		}
+		if self.old_name.includes(&self.new_name) {
+			return Err(PPMError::InvalidInput(format!(
+				"Cannot move project '{}' into its own subproject '{}'",
+				self.old_name, self.new_name
+			)));
+		}
+
+		if self.project_repository.get_project(&self.old_name)?.is_none() {
+			return Err(PPMError::NotFound(format!("Project '{}' not found", self.old_name)));
+		}
+
+		let targets = self.collect_targets()?;
+		for project in &targets.projects {
+			let new_name = self.renamed(&project.name);
+			if self.project_repository.get_project(&new_name)?.is_some() {
+				return Err(PPMError::AlreadyExists(format!(
+					"Project '{}' already exists",
+					new_name
+				)));
+			}
+		}
+
+		let mut applied = Vec::new();
+		if let Err(e) = self.apply(&targets, &mut applied) {
+			self.output_writer.write_line(&format!(
+				"Rename failed, rolling back {} completed update(s)",
+				applied.len()
+			))?;
+			self.rollback(applied)?;
+			return Err(e);
+		}
+
+		self.output_writer.write_line(&format!(
+			"Project '{}' renamed to '{}' ({} task(s), {} note(s), {} session(s) updated)",
+			self.old_name,
+			self.new_name,
+			targets.tasks.len(),
+			targets.notes.len(),
+			targets.sessions.len()
+		))?;
+		let subprojects = targets.projects.len() - 1;
+		if subprojects > 0 {
+			self.output_writer.write_line(&format!("{} subproject(s) moved along", subprojects))?;
+		}
+
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::sync::atomic::Ordering;
+
+	use super::*;
+	use crate::output::InMemoryWriter;
+	use crate::repositories::{
+		InMemoryProjectRepository, InMemorySessionRepository, InMemoryTaskRepository,
+	};
+	use crate::test_support::{FailingNoteRepository, note, project, session, task, utc};
+
+	struct Fixture {
+		projects: Arc<InMemoryProjectRepository>,
+		tasks: Arc<InMemoryTaskRepository>,
+		notes: Arc<FailingNoteRepository>,
+		sessions: Arc<InMemorySessionRepository>,
+		output: Arc<InMemoryWriter>,
+	}
+
+	fn in_project(name: &str) -> Option<ProjectName> {
+		Some(ProjectName::from(name))
+	}
+
+	impl Fixture {
+		/// `work`, `work/api` and `workshop`, each with a task, a session and a note.
+		fn new() -> Self {
+			let fixture = Self {
+				projects: Arc::new(InMemoryProjectRepository::new()),
+				tasks: Arc::new(InMemoryTaskRepository::new()),
+				notes: Arc::new(FailingNoteRepository::default()),
+				sessions: Arc::new(InMemorySessionRepository::new()),
+				output: Arc::new(InMemoryWriter::new()),
+			};
+
+			for name in ["work", "work/api", "workshop"] {
+				fixture.projects.create_project(project(name)).unwrap();
+				fixture
+					.tasks
+					.create_task(Task {
+						project_name: in_project(name),
+						..task(name)
+					})
+					.unwrap();
+				fixture
+					.sessions
+					.create_session(FocusSession {
+						associated_project_name: in_project(name),
+						..session(utc(2026, 1, 2, 9, 0), 30)
+					})
+					.unwrap();
+				fixture
+					.notes
+					.create_note(Note {
+						project_name: in_project(name),
+						..note(name)
+					})
+					.unwrap();
+			}
+
+			fixture
+		}
+
+		fn rename(&self, old_name: &str, new_name: &str) -> PPMResult<()> {
+			RenameProject {
+				project_repository: self.projects.clone(),
+				task_repository: self.tasks.clone(),
+				note_repository: self.notes.clone(),
+				session_repository: self.sessions.clone(),
+				output_writer: self.output.clone(),
+				old_name: ProjectName::from(old_name),
+				new_name: ProjectName::from(new_name),
+			}
+			.run()
+		}
+
+		/// Project names of every project, task, session and note, sorted.
+		fn names(&self) -> Vec<String> {
+			let mut names: Vec<String> = self
+				.projects
+				.list_projects()
+				.unwrap()
+				.into_iter()
+				.map(|p| Some(p.name))
+				.chain(self.tasks.list_tasks().unwrap().into_iter().map(|t| t.project_name))
+				.chain(
+					self.sessions
+						.list_sessions()
+						.unwrap()
+						.into_iter()
+						.map(|s| s.associated_project_name),
+				)
+				.chain(self.notes.list_notes().unwrap().into_iter().map(|n| n.project_name))
+				.map(|name| name.map(|n| n.to_string()).unwrap_or_default())
+				.collect();
+			names.sort();
+			names
+		}
+	}
+
+	fn expected(names: [&str; 3]) -> Vec<String> {
+		let mut expected: Vec<String> =
+			names.iter().flat_map(|n| std::iter::repeat_n(n.to_string(), 4)).collect();
+		expected.sort();
+		expected
+	}
+
+	#[test]
+	fn rename_moves_the_subtree_and_its_references() {
+		let fixture = Fixture::new();
+		fixture.rename("work", "job").unwrap();
+
+		assert_eq!(fixture.names(), expected(["job", "job/api", "workshop"]));
+		let lines = fixture.output.lines().unwrap();
+		assert!(lines.iter().any(|l| l.contains("2 task(s), 2 note(s), 2 session(s) updated")));
+		assert!(lines.iter().any(|l| l.contains("1 subproject(s) moved along")));
+	}
+
+	#[test]
+	fn rename_rejects_conflicts() {
+		let fixture = Fixture::new();
+		assert!(fixture.rename("work", "workshop").is_err());
+		assert!(fixture.rename("work", "work/api/v2").is_err());
+		assert!(fixture.rename("missing", "other").is_err());
+		assert_eq!(fixture.names(), expected(["work", "work/api", "workshop"]));
+	}
+
+	#[test]
+	fn failed_rename_is_rolled_back() {
+		let fixture = Fixture::new();
+		fixture.notes.fail_writes.store(true, Ordering::Relaxed);
+		assert!(fixture.rename("work", "job").is_err());
+
+		assert_eq!(fixture.names(), expected(["work", "work/api", "workshop"]));
+		let lines = fixture.output.lines().unwrap();
+		assert!(lines.iter().any(|l| l.contains("rolling back 4 completed update(s)")));
+		assert!(!lines.iter().any(|l| l.contains("Failed to roll back")));
+	}
+}
@@ -0,0 +1,201 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Duration, Utc};
+
+use crate::clock::Clock;
+use crate::errors::{PPMError, PPMResult};
+use crate::models::{FocusSession, Milestone, ProjectName, ProjectStatus, Task, TaskStatus};
+use crate::output::OutputWriter;
+use crate::repositories::{ProjectRepository, SessionRepository, TaskRepository};
+use crate::services::{Service, format_duration};
+
+/// Service to show a project with its task counts, focus time and milestone progress.
+///
+/// Task counts and focus time include subprojects. A milestone is on track while the share
+/// of its linked tasks that are done keeps up with the share of time elapsed between its
+/// creation and its target date.
+pub struct ShowProject {
+	pub clock: Arc<dyn Clock>,
+	pub project_repository: Arc<dyn ProjectRepository>,
+	pub task_repository: Arc<dyn TaskRepository>,
+	pub session_repository: Arc<dyn SessionRepository>,
+	pub output_writer: Arc<dyn OutputWriter>,
+	pub name: ProjectName,
+}
+
+/// Progress of a milestone's linked tasks. Canceled tasks do not count.
+struct Progress {
+	done: usize,
+	total: usize,
+	time_spent: Duration,
+}
+
+impl Progress {
+	fn of(
+		milestone: &Milestone,
+		tasks: &[Task],
+		sessions: &[FocusSession],
+		now: DateTime<Utc>,
+	) -> Self {
+		let linked: Vec<&Task> =
+			tasks.iter().filter(|t| milestone.task_ids.contains(&t.id)).collect();
+
+		Self {
+			done: linked.iter().filter(|t| matches!(t.status, TaskStatus::Done(_))).count(),
+			total: linked.iter().filter(|t| !matches!(t.status, TaskStatus::Canceled(_))).count(),
+			time_spent: sessions
+				.iter()
+				.filter(|s| {
+					s.associated_task_id.as_ref().is_some_and(|id| milestone.task_ids.contains(id))
+				})
+				.map(|s| s.elapsed(now))
+				.sum(),
+		}
+	}
+
+	fn fraction(&self) -> f64 {
+		if self.total == 0 {
+			0.0
+		} else {
+			self.done as f64 / self.total as f64
+		}
+	}
+}
+
+impl ShowProject {
+	fn write_milestone(
+		&self,
+		milestone: &Milestone,
+		progress: &Progress,
+		now: DateTime<Utc>,
+	) -> PPMResult<()> {
+		let today = self.clock.local_date(now);
+		let days_left = (milestone.target - today).num_days();
+		let target_display = match days_left {
+			0 => "today".to_string(),
+			d if d > 0 => format!("in {} day(s)", d),
+			d => format!("{} day(s) ago", -d),
+		};
+
+		self.output_writer.write_line(&format!(
+			"  {} - target {} ({})",
+			milestone.name, milestone.target, target_display
+		))?;
+		self.output_writer.write_line(&format!(
+			"    Progress: {}/{} task(s) done ({:.0}%)",
+			progress.done,
+			progress.total,
+			progress.fraction() * 100.0
+		))?;
+		self.output_writer
+			.write_line(&format!("    Time spent: {}", format_duration(&progress.time_spent)))?;
+		self.output_writer.write_line(&format!(
+			"    Status: {}",
+			self.schedule_status(milestone, progress, now)
+		))?;
+
+		Ok(())
+	}
+
+	fn schedule_status(
+		&self,
+		milestone: &Milestone,
+		progress: &Progress,
+		now: DateTime<Utc>,
+	) -> String {
+		if progress.total == 0 {
+			return "no tasks linked".to_string();
+		}
+		if progress.done == progress.total {
+			return "completed".to_string();
+		}
+		let today = self.clock.local_date(now);
+		if today > milestone.target {
+			return "overdue".to_string();
+		}
+
+		let start = self.clock.local_date(milestone.created_at);
+		let planned_days = (milestone.target - start).num_days().max(1) as f64;
+		let elapsed_days = (today - start).num_days().max(0) as f64;
+		let expected = (elapsed_days / planned_days).min(1.0);
+
+		if progress.fraction() >= expected {
+			"on track".to_string()
+		} else {
+			format!("behind schedule (expected {:.0}% by now)", expected * 100.0)
+		}
+	}
+}
+
+impl Service for ShowProject {
+	fn run(&self) -> PPMResult<()> {
+		let now = self.clock.now()?;
+		let project = self
+			.project_repository
+			.get_project(&self.name)?
+			.ok_or_else(|| PPMError::NotFound(format!("Project '{}' not found", self.name)))?;
+		let tasks = self.task_repository.list_tasks()?;
+		let sessions = self.session_repository.list_sessions()?;
+
+		let status_display = match project.status {
+			ProjectStatus::Active => "Active",
+			ProjectStatus::Inactive => "Inactive",
+			ProjectStatus::Archived => "Archived",
+		};
+		self.output_writer
+			.write_line(&format!("Project '{}' [{}]", project.name, status_display))?;
+		if !project.description.is_empty() {
+			self.output_writer.write_line(&format!("  {}", project.description))?;
+		}
+
+		let subprojects: Vec<String> = self
+			.project_repository
+			.list_projects()?
+			.into_iter()
+			.filter(|p| p.name.parent().as_ref() == Some(&project.name))
+			.map(|p| p.name.leaf().to_string())
+			.collect();
+		if !subprojects.is_empty() {
+			self.output_writer.write_line(&format!("  Subprojects: {}", subprojects.join(", ")))?;
+		}
+
+		let in_project =
+			|name: Option<&ProjectName>| name.is_some_and(|n| project.name.includes(n));
+		let project_tasks: Vec<&Task> =
+			tasks.iter().filter(|t| in_project(t.project_name.as_ref())).collect();
+		let count = |predicate: fn(&TaskStatus) -> bool| {
+			project_tasks.iter().filter(|t| predicate(&t.status)).count()
+		};
+		self.output_writer.write_line(&format!(
+			"  Tasks: {} pending, {} done, {} canceled",
+			count(|s| matches!(s, TaskStatus::Pending)),
+			count(|s| matches!(s, TaskStatus::Done(_))),
+			count(|s| matches!(s, TaskStatus::Canceled(_)))
+		))?;
+
+		let focus_time: Duration = sessions
+			.iter()
+			.filter(|s| in_project(s.associated_project_name.as_ref()))
+			.map(|s| s.elapsed(now))
+			.sum();
+		self.output_writer
+			.write_line(&format!("  Focus time: {}", format_duration(&focus_time)))?;
+
+		self.output_writer.write_line(&"")?;
+		if project.milestones.is_empty() {
+			self.output_writer.write_line(&"No milestones")?;
+			return Ok(());
+		}
+
+		let mut milestones = project.milestones.clone();
+		milestones.sort_by_key(|m| m.target);
+
+		self.output_writer.write_line(&format!("Milestones ({}):", milestones.len()))?;
+		for milestone in &milestones {
+			let progress = Progress::of(milestone, &tasks, &sessions, now);
+			self.write_milestone(milestone, &progress, now)?;
+		}
+
+		Ok(())
+	}
+}
@@ -4,6 +4,7 @@ use chrono::{DateTime, Utc};
 
 use crate::clock::Clock;
 use crate::errors::PPMResult;
+use crate::models::{FocusSessionId, ModelId};
 use crate::output::OutputWriter;
 use crate::repositories::SessionRepository;
 use crate::services::Service;
@@ -25,8 +26,12 @@ impl Service for ListSessions {
 			return Ok(());
 		}
 
-		// Sort by start time (most recent first)
-		sessions.sort_by(|a, b| b.start.cmp(&a.start));
+		// Sequence numbers follow start order, independent of the limit
+		sessions.sort_by_key(|s| s.start);
+		let ids: Vec<FocusSessionId> = sessions.iter().map(|s| s.id.clone()).collect();
+
+		// Most recent first
+		sessions.reverse();
 
 		// Apply limit if specified
 		if let Some(limit) = self.limit {
@@ -51,7 +56,11 @@ impl Service for ListSessions {
 				format_datetime(&session.start),
 				duration_minutes
 			))?;
-			self.output_writer.write_line(&format!("  ID: {}", session.id))?;
+			self.output_writer.write_line(&format!(
+				"  ID: {} (#{})",
+				session.id,
+				session.id.sequence_number(&ids).unwrap_or_default()
+			))?;
 		}
 
 		Ok(())
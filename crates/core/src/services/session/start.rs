@@ -3,25 +3,33 @@ use std::sync::Arc;
 use chrono::Duration;
 
 use crate::clock::Clock;
+use crate::config::ReferenceMode;
 use crate::errors::{PPMError, PPMResult};
-use crate::models::{FocusSession, FocusSessionId, ProjectName};
+use crate::models::{FocusSession, FocusSessionId, ProjectName, Task, TaskId};
 use crate::output::OutputWriter;
-use crate::repositories::SessionRepository;
-use crate::services::Service;
+use crate::prompt::Prompt;
+use crate::repositories::{ProjectRepository, SessionRepository, TaskRepository};
+use crate::services::{Service, project};
 
 /// Service to start a new focus session.
 ///
 /// Validates no active session exists, creates a new session,
 /// and outputs confirmation to the user.
+/// When the session is linked to a task, it inherits the task's project unless one is given.
 pub struct StartFocusSession {
 	// dependencies
 	pub clock: Arc<dyn Clock>,
 	pub repository: Arc<dyn SessionRepository>,
+	pub task_repository: Arc<dyn TaskRepository>,
+	pub project_repository: Arc<dyn ProjectRepository>,
 	pub output_writer: Arc<dyn OutputWriter>,
+	pub prompt: Arc<dyn Prompt>,
 
 	// actual configurations
 	pub duration_in_minutes: u32,
 	pub associated_project_name: Option<ProjectName>,
+	pub associated_task_id: Option<TaskId>,
+	pub project_references: ReferenceMode,
 }
 
 impl StartFocusSession {
@@ -32,14 +40,45 @@ impl StartFocusSession {
 		Ok(())
 	}
 
-	fn create_new_focus_session(&self) -> PPMResult<()> {
+	fn find_associated_task(&self) -> PPMResult<Option<Task>> {
+		let Some(ref input) = self.associated_task_id else {
+			return Ok(None);
+		};
+
+		let task_id = self.task_repository.resolve_task_id(input)?;
+		let task = self
+			.task_repository
+			.get_task(&task_id)?
+			.ok_or_else(|| PPMError::NotFound(format!("Task {} not found", task_id)))?;
+
+		Ok(Some(task))
+	}
+
+	fn create_new_focus_session(&self, task: Option<Task>) -> PPMResult<()> {
 		let duration_seconds = self.duration_in_minutes as i64 * 60;
 		let now = self.clock.now()?;
+		let associated_project_name = self
+			.associated_project_name
+			.clone()
+			.or_else(|| task.as_ref().and_then(|t| t.project_name.clone()));
+
+		if let Some(ref project_name) = associated_project_name {
+			project::ensure_accepts_work(
+				self.project_repository.as_ref(),
+				self.output_writer.as_ref(),
+				self.prompt.as_ref(),
+				self.project_references,
+				now,
+				project_name,
+			)?;
+		}
+
 		let session = FocusSession {
 			id: FocusSessionId::new(),
 			start: now,
 			end: now + Duration::seconds(duration_seconds),
-			associated_project_name: self.associated_project_name.clone(),
+			associated_project_name: associated_project_name.clone(),
+			associated_task_id: task.as_ref().map(|t| t.id.clone()),
 		};
 
 		self.repository.create_session(session)?;
@@ -47,8 +86,11 @@ impl StartFocusSession {
 		self.output_writer.write_line(&"Focus session started")?;
 		self.output_writer.write_line(&format!(
 			"Project: {}",
-			self.associated_project_name.as_ref().unwrap_or(&ProjectName("Inbox".to_string()))
+			associated_project_name.as_ref().unwrap_or(&ProjectName("Inbox".to_string()))
 		))?;
+		if let Some(ref task) = task {
+			self.output_writer.write_line(&format!("Task: {} - {}", task.id, task.description))?;
+		}
 		self.output_writer
 			.write_line(&format!("Duration: {} minutes", self.duration_in_minutes))?;
 
@@ -59,7 +101,8 @@ impl StartFocusSession {
 impl Service for StartFocusSession {
 	fn run(&self) -> PPMResult<()> {
 		self.ensure_no_active_focus_session()?;
-		self.create_new_focus_session()?;
+		let task = self.find_associated_task()?;
+		self.create_new_focus_session(task)?;
 		Ok(())
 	}
 }
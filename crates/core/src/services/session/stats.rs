@@ -4,20 +4,28 @@ use chrono::{DateTime, Datelike, Duration, Utc};
 
 use crate::clock::Clock;
 use crate::errors::PPMResult;
+use crate::models::ProjectName;
 use crate::output::OutputWriter;
 use crate::repositories::SessionRepository;
-use crate::services::Service;
+use crate::services::{Service, format_duration};
 
 pub struct GetSessionStats {
 	pub clock: Arc<dyn Clock>,
 	pub repository: Arc<dyn SessionRepository>,
 	pub output_writer: Arc<dyn OutputWriter>,
+	/// Only count sessions of this project and its subprojects
+	pub project_name: Option<ProjectName>,
 }
 
 impl Service for GetSessionStats {
 	fn run(&self) -> PPMResult<()> {
 		let now = self.clock.now()?;
-		let sessions = self.repository.list_sessions()?;
+		let mut sessions = self.repository.list_sessions()?;
+		if let Some(ref project_name) = self.project_name {
+			sessions.retain(|s| {
+				s.associated_project_name.as_ref().is_some_and(|name| project_name.includes(name))
+			});
+		}
 
 		if sessions.is_empty() {
 			self.output_writer.write_line(&"No focus sessions found")?;
@@ -82,15 +90,3 @@ fn is_same_week(dt1: &DateTime<Utc>, dt2: &DateTime<Utc>) -> bool {
 	let diff = (*dt2 - *dt1).num_days();
 	(0..7).contains(&diff) && dt1.year() == dt2.year()
 }
-
-fn format_duration(duration: &Duration) -> String {
-	let total_minutes = duration.num_minutes();
-	let hours = total_minutes / 60;
-	let minutes = total_minutes % 60;
-
-	if hours > 0 {
-		format!("{}h {}m", hours, minutes)
-	} else {
-		format!("{}m", minutes)
-	}
-}
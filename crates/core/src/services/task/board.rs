@@ -0,0 +1,91 @@
+use std::sync::Arc;
+
+use crate::errors::PPMResult;
+use crate::models::{ModelId, ProjectName, StateCategory, Task, TaskId, Workflow};
+use crate::output::OutputWriter;
+use crate::repositories::task::TaskRepository;
+use crate::services::Service;
+
+/// Width of a board column, in characters.
+const COLUMN_WIDTH: usize = 24;
+
+/// Renders tasks as a board with one column per workflow state.
+pub struct TaskBoard {
+	pub task_repository: Arc<dyn TaskRepository>,
+	pub output_writer: Arc<dyn OutputWriter>,
+	pub workflow: Workflow,
+	/// Only show tasks of this project and its subprojects
+	pub project_name: Option<ProjectName>,
+	/// Also show the columns of canceled states
+	pub show_canceled: bool,
+}
+
+impl Service for TaskBoard {
+	fn run(&self) -> PPMResult<()> {
+		let mut tasks = self.task_repository.list_tasks()?;
+
+		// Sequence numbers follow creation order, as in `task list`
+		tasks.sort_by_key(|t| t.created_at);
+		let ids: Vec<TaskId> = tasks.iter().map(|t| t.id.clone()).collect();
+
+		if let Some(ref project_name) = self.project_name {
+			tasks.retain(|t| {
+				t.project_name.as_ref().is_some_and(|name| project_name.includes(name))
+			});
+		}
+
+		let columns: Vec<(String, Vec<String>)> = self
+			.workflow
+			.states
+			.iter()
+			.filter(|s| self.show_canceled || s.category != StateCategory::Canceled)
+			.map(|state| {
+				let cells: Vec<String> = tasks
+					.iter()
+					.filter(|t| self.workflow.state_of(t).is_some_and(|s| s.name == state.name))
+					.map(|t| format_card(t, &ids))
+					.collect();
+				(format!("{} ({})", state.name, cells.len()), cells)
+			})
+			.collect();
+
+		let header: Vec<&str> = columns.iter().map(|(title, _)| title.as_str()).collect();
+		self.output_writer.write_line(&format_row(&header))?;
+		self.output_writer.write_line(
+			&vec!["-".repeat(COLUMN_WIDTH); columns.len()].join("-+-").trim_end().to_string(),
+		)?;
+
+		let height = columns.iter().map(|(_, cells)| cells.len()).max().unwrap_or(0);
+		for row in 0..height {
+			let cells: Vec<&str> = columns
+				.iter()
+				.map(|(_, cells)| cells.get(row).map_or("", String::as_str))
+				.collect();
+			self.output_writer.write_line(&format_row(&cells))?;
+		}
+
+		Ok(())
+	}
+}
+
+fn format_card(task: &Task, ids: &[TaskId]) -> String {
+	format!("#{} {}", task.id.sequence_number(ids).unwrap_or_default(), task.description)
+}
+
+fn format_row(cells: &[&str]) -> String {
+	cells
+		.iter()
+		.map(|cell| format!("{:<width$}", truncate(cell), width = COLUMN_WIDTH))
+		.collect::<Vec<_>>()
+		.join(" | ")
+		.trim_end()
+		.to_string()
+}
+
+fn truncate(text: &str) -> String {
+	if text.chars().count() > COLUMN_WIDTH {
+		format!("{}...", text.chars().take(COLUMN_WIDTH - 3).collect::<String>())
+	} else {
+		text.to_string()
+	}
+}
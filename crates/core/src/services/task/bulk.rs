@@ -0,0 +1,244 @@
+use chrono::NaiveDate;
+
+use crate::errors::{PPMError, PPMResult};
+use crate::models::{ModelId, Task, TaskId};
+use crate::output::OutputWriter;
+use crate::query::TaskQuery;
+use crate::repositories::task::TaskRepository;
+
+/// Tasks targeted by a bulk operation.
+#[derive(Debug, Clone)]
+pub enum TaskSelection {
+	/// IDs in any form accepted by [`ModelId::resolve`].
+	Ids(Vec<String>),
+	/// A [`TaskQuery`] expression.
+	Query(String),
+}
+
+impl TaskSelection {
+	pub fn single(task_id: impl Into<String>) -> Self {
+		Self::Ids(vec![task_id.into()])
+	}
+}
+
+/// Outcome of applying an operation to each selected task.
+#[derive(Default)]
+pub(crate) struct BulkResult {
+	pub succeeded: Vec<TaskId>,
+	/// (item as given by the user or task ID, reason)
+	pub failed: Vec<(String, PPMError)>,
+}
+
+/// Resolves a selection against the stored tasks with a single read.
+///
+/// IDs that cannot be resolved are recorded as failures instead of aborting the whole batch.
+pub(crate) fn select_tasks(
+	task_repository: &dyn TaskRepository,
+	selection: &TaskSelection,
+	today: NaiveDate,
+	result: &mut BulkResult,
+) -> PPMResult<Vec<Task>> {
+	let mut tasks = task_repository.list_tasks()?;
+	tasks.sort_by_key(|t| t.created_at);
+
+	match selection {
+		TaskSelection::Query(query) => Ok(TaskQuery::parse(query, today)?.apply(tasks)),
+		TaskSelection::Ids(inputs) => {
+			let ids: Vec<TaskId> = tasks.iter().map(|t| t.id.clone()).collect();
+			let mut selected: Vec<Task> = Vec::new();
+
+			for input in inputs {
+				match TaskId::resolve(input, &ids) {
+					Ok(id) if selected.iter().any(|t| t.id == id) => {}
+					Ok(id) => {
+						if let Some(task) = tasks.iter().find(|t| t.id == id) {
+							selected.push(task.clone());
+						}
+					}
+					Err(e) => result.failed.push((input.clone(), e)),
+				}
+			}
+
+			Ok(selected)
+		}
+	}
+}
+
+/// Prints a summary and the per-item errors. Services print their own line per changed task.
+///
+/// A selection of exactly one task fails with that task's own error; larger batches fail with
+/// a summary error if any item failed.
+pub(crate) fn report(
+	output_writer: &dyn OutputWriter,
+	verb: &str,
+	mut result: BulkResult,
+) -> PPMResult<()> {
+	let total = result.succeeded.len() + result.failed.len();
+	if total == 0 {
+		output_writer.write_line(&"No tasks found")?;
+		return Ok(());
+	}
+	if total == 1
+		&& let Some((_, error)) = result.failed.pop()
+	{
+		return Err(error);
+	}
+	if total > 1 {
+		output_writer.write_line(&format!(
+			"{} task(s) {}, {} failed",
+			result.succeeded.len(),
+			verb,
+			result.failed.len()
+		))?;
+	}
+
+	for (item, error) in &result.failed {
+		output_writer.write_line(&format!("  {}: {}", item, error))?;
+	}
+
+	if result.failed.is_empty() {
+		Ok(())
+	} else {
+		Err(PPMError::BulkOperationFailed(result.failed.len(), total))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::sync::Arc;
+
+	use super::*;
+	use crate::clock::FixedClock;
+	use crate::models::TaskStatus;
+	use crate::output::InMemoryWriter;
+	use crate::repositories::task::InMemoryTaskRepository;
+	use crate::services::Service;
+	use crate::services::task::CancelTask;
+	use crate::test_support::{task, utc};
+
+	fn today() -> NaiveDate {
+		NaiveDate::from_ymd_opt(2026, 1, 10).unwrap()
+	}
+
+	/// Stores `tasks` created a minute apart, so that `#1`, `#2`, ... follow their order.
+	fn repository(tasks: Vec<Task>) -> (InMemoryTaskRepository, Vec<Task>) {
+		let repository = InMemoryTaskRepository::new();
+		let tasks: Vec<Task> = tasks
+			.into_iter()
+			.enumerate()
+			.map(|(i, t)| Task {
+				created_at: utc(2026, 1, 1, 0, i as u32),
+				..t
+			})
+			.collect();
+		for t in &tasks {
+			repository.create_task(t.clone()).unwrap();
+		}
+		(repository, tasks)
+	}
+
+	fn ids(tasks: &[Task]) -> Vec<TaskId> {
+		tasks.iter().map(|t| t.id.clone()).collect()
+	}
+
+	#[test]
+	fn ids_select_found_tasks_and_record_the_rest() {
+		let (repository, tasks) = repository(vec![task("One"), task("Two"), task("Three")]);
+		let selection = TaskSelection::Ids(
+			["#3", "missing", tasks[0].id.as_ref(), "#3", "#9"].map(String::from).to_vec(),
+		);
+
+		let mut result = BulkResult::default();
+		let selected = select_tasks(&repository, &selection, today(), &mut result).unwrap();
+
+		assert_eq!(ids(&selected), vec![tasks[2].id.clone(), tasks[0].id.clone()]);
+		let failed: Vec<&str> = result.failed.iter().map(|(item, _)| item.as_str()).collect();
+		assert_eq!(failed, vec!["missing", "#9"]);
+	}
+
+	#[test]
+	fn queries_select_matching_tasks() {
+		let (repository, tasks) = repository(vec![
+			Task {
+				due: Some(today()),
+				..task("Due today")
+			},
+			Task {
+				due: NaiveDate::from_ymd_opt(2026, 1, 20),
+				..task("Due later")
+			},
+			task("No due date"),
+		]);
+
+		let mut result = BulkResult::default();
+		let query = TaskSelection::Query("due<=today".to_string());
+		let selected = select_tasks(&repository, &query, today(), &mut result).unwrap();
+		assert_eq!(ids(&selected), vec![tasks[0].id.clone()]);
+
+		let query = TaskSelection::Query("-due:none".to_string());
+		let selected = select_tasks(&repository, &query, today(), &mut result).unwrap();
+		assert_eq!(ids(&selected), ids(&tasks[..2]));
+		assert!(result.failed.is_empty());
+
+		let query = TaskSelection::Query("due<someday".to_string());
+		assert!(select_tasks(&repository, &query, today(), &mut result).is_err());
+	}
+
+	#[test]
+	fn report_summarizes_partial_failures() {
+		let output = InMemoryWriter::new();
+		assert_eq!(report(&output, "done", BulkResult::default()), Ok(()));
+		assert_eq!(output.lines().unwrap(), vec!["[ppm] No tasks found"]);
+
+		// A single item fails with its own error
+		let single = BulkResult {
+			succeeded: Vec::new(),
+			failed: vec![("x".to_string(), PPMError::NotFound("No task matches 'x'".to_string()))],
+		};
+		assert_eq!(
+			report(&output, "done", single),
+			Err(PPMError::NotFound("No task matches 'x'".to_string()))
+		);
+
+		let output = InMemoryWriter::new();
+		let partial = BulkResult {
+			succeeded: vec![TaskId::new(), TaskId::new()],
+			failed: vec![("x".to_string(), PPMError::NotFound("No task matches 'x'".to_string()))],
+		};
+		assert_eq!(report(&output, "done", partial), Err(PPMError::BulkOperationFailed(1, 3)));
+		assert_eq!(
+			output.lines().unwrap(),
+			vec!["[ppm] 2 task(s) done, 1 failed", "[ppm]   x: No task matches 'x'"]
+		);
+	}
+
+	#[test]
+	fn bulk_cancel_changes_what_it_can() {
+		let (repository, tasks) = repository(vec![
+			task("Pending"),
+			Task {
+				status: TaskStatus::Done(utc(2026, 1, 2, 0, 0)),
+				..task("Finished")
+			},
+		]);
+		let repository = Arc::new(repository);
+		let output = Arc::new(InMemoryWriter::new());
+
+		let result = CancelTask {
+			clock: Arc::new(FixedClock::new(utc(2026, 1, 10, 9, 0))),
+			task_repository: repository.clone(),
+			output_writer: output.clone(),
+			selection: TaskSelection::Ids(["#1", "#2", "nope"].map(String::from).to_vec()),
+		}
+		.run();
+
+		assert_eq!(result, Err(PPMError::BulkOperationFailed(2, 3)));
+		let stored = repository.list_tasks().unwrap();
+		let status = |id: &TaskId| stored.iter().find(|t| &t.id == id).unwrap().status.clone();
+		assert!(matches!(status(&tasks[0].id), TaskStatus::Canceled(_)));
+		assert!(matches!(status(&tasks[1].id), TaskStatus::Done(_)));
+		let lines = output.lines().unwrap();
+		assert!(lines.contains(&format!("[ppm] Task {} canceled", tasks[0].id)));
+		assert!(lines.contains(&"[ppm] 1 task(s) canceled, 2 failed".to_string()));
+	}
+}
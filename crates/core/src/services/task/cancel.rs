@@ -0,0 +1,61 @@
+use std::sync::Arc;
+
+use crate::clock::Clock;
+use crate::errors::{PPMError, PPMResult};
+use crate::models::TaskStatus;
+use crate::output::OutputWriter;
+use crate::repositories::task::TaskRepository;
+use crate::services::Service;
+use crate::services::task::bulk::{self, BulkResult, TaskSelection};
+
+/// Marks pending tasks as canceled.
+pub struct CancelTask {
+	pub clock: Arc<dyn Clock>,
+	pub task_repository: Arc<dyn TaskRepository>,
+	pub output_writer: Arc<dyn OutputWriter>,
+	pub selection: TaskSelection,
+}
+
+impl Service for CancelTask {
+	fn run(&self) -> PPMResult<()> {
+		let now = self.clock.now()?;
+		let mut result = BulkResult::default();
+		let tasks = bulk::select_tasks(
+			self.task_repository.as_ref(),
+			&self.selection,
+			self.clock.local_date(now),
+			&mut result,
+		)?;
+
+		let mut changed = Vec::new();
+
+		for mut task in tasks {
+			match task.status {
+				TaskStatus::Pending => {
+					task.status = TaskStatus::Canceled(now);
+					task.workflow_state = None;
+					result.succeeded.push(task.id.clone());
+					changed.push(task);
+				}
+				TaskStatus::Done(_) => result.failed.push((
+					task.id.to_string(),
+					PPMError::NotFound(format!("Task {} is already completed", task.id)),
+				)),
+				TaskStatus::Canceled(_) => result.failed.push((
+					task.id.to_string(),
+					PPMError::NotFound(format!("Task {} is already canceled", task.id)),
+				)),
+			}
+		}
+
+		if !changed.is_empty() {
+			self.task_repository.upsert_tasks(changed)?;
+		}
+
+		for task_id in &result.succeeded {
+			self.output_writer.write_line(&format!("Task {} canceled", task_id))?;
+		}
+
+		bulk::report(self.output_writer.as_ref(), "canceled", result)
+	}
+}
@@ -1,43 +1,139 @@
 use std::sync::Arc;
 
+use chrono::{DateTime, NaiveDate, Utc};
+
 use crate::clock::Clock;
 use crate::errors::{PPMError, PPMResult};
-use crate::models::{TaskId, TaskStatus};
+use crate::models::{Task, TaskId, TaskStatus};
 use crate::output::OutputWriter;
 use crate::repositories::task::TaskRepository;
 use crate::services::Service;
+use crate::services::task::bulk::{self, BulkResult, TaskSelection};
 
+/// Marks pending tasks as done.
+///
+/// If a task has a recurrence rule, the next occurrence is created as a new pending task
+/// whose due date follows the rule.
 pub struct CompleteTask {
 	pub clock: Arc<dyn Clock>,
 	pub task_repository: Arc<dyn TaskRepository>,
 	pub output_writer: Arc<dyn OutputWriter>,
-	pub task_id: TaskId,
+	pub selection: TaskSelection,
 }
 
 impl Service for CompleteTask {
 	fn run(&self) -> PPMResult<()> {
-		// Validate task exists and is pending
-		let task = self
-			.task_repository
-			.get_task(&self.task_id)?
-			.ok_or_else(|| PPMError::NotFound(format!("Task {} not found", self.task_id)))?;
-
-		match task.status {
-			TaskStatus::Pending => {
-				// Update status to Done with current timestamp
-				let completed_at = self.clock.now()?;
-				self.task_repository
-					.update_task_status(&self.task_id, TaskStatus::Done(completed_at))?;
-
-				self.output_writer.write_line(&format!("Task {} completed", self.task_id))?;
-				Ok(())
-			}
-			TaskStatus::Done(_) => {
-				Err(PPMError::NotFound(format!("Task {} is already completed", self.task_id)))
-			}
-			TaskStatus::Canceled(_) => {
-				Err(PPMError::NotFound(format!("Task {} is canceled", self.task_id)))
+		let now = self.clock.now()?;
+		let today = self.clock.local_date(now);
+		let mut result = BulkResult::default();
+		let tasks =
+			bulk::select_tasks(self.task_repository.as_ref(), &self.selection, today, &mut result)?;
+
+		let mut changed = Vec::new();
+		let mut next_occurrences = Vec::new();
+
+		for mut task in tasks {
+			match task.status {
+				TaskStatus::Pending => {
+					next_occurrences.extend(next_occurrence(&task, now, today));
+					task.status = TaskStatus::Done(now);
+					task.workflow_state = None;
+					result.succeeded.push(task.id.clone());
+					changed.push(task);
+				}
+				TaskStatus::Done(_) => result.failed.push((
+					task.id.to_string(),
+					PPMError::NotFound(format!("Task {} is already completed", task.id)),
+				)),
+				TaskStatus::Canceled(_) => result.failed.push((
+					task.id.to_string(),
+					PPMError::NotFound(format!("Task {} is canceled", task.id)),
+				)),
 			}
 		}
+
+		if !changed.is_empty() {
+			self.task_repository.upsert_tasks([changed, next_occurrences.clone()].concat())?;
+		}
+
+		for task_id in &result.succeeded {
+			self.output_writer.write_line(&format!("Task {} completed", task_id))?;
+		}
+		for next in &next_occurrences {
+			write_next_occurrence(self.output_writer.as_ref(), next)?;
+		}
+
+		bulk::report(self.output_writer.as_ref(), "completed", result)
+	}
+}
+
+/// Builds the next occurrence of a recurring task completed at `now`, on local date `today`.
+pub(crate) fn next_occurrence(task: &Task, now: DateTime<Utc>, today: NaiveDate) -> Option<Task> {
+	let recurrence = task.recurrence.as_ref()?;
+
+	// Completing early must not schedule the next occurrence on the same due date.
+	let base = task.due.map_or(today, |due| due.max(today));
+
+	Some(Task {
+		id: TaskId::new(),
+		project_name: task.project_name.clone(),
+		description: task.description.clone(),
+		status: TaskStatus::Pending,
+		created_at: now,
+		due: Some(recurrence.next_after(base)),
+		recurrence: task.recurrence.clone(),
+		estimate: task.estimate,
+		tags: task.tags.clone(),
+		workflow_state: None,
+	})
+}
+
+pub(crate) fn write_next_occurrence(
+	output_writer: &dyn OutputWriter,
+	next: &Task,
+) -> PPMResult<()> {
+	if let (Some(due), Some(recurrence)) = (next.due, &next.recurrence) {
+		output_writer
+			.write_line(&format!("Next occurrence {} due {} ({})", next.id, due, recurrence))?;
+	}
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use chrono::FixedOffset;
+
+	use super::*;
+	use crate::clock::FixedClock;
+	use crate::models::Recurrence;
+	use crate::output::InMemoryWriter;
+	use crate::repositories::task::InMemoryTaskRepository;
+	use crate::test_support::{task, utc};
+
+	#[test]
+	fn next_occurrence_follows_the_local_date() {
+		// 23:30 UTC on the 5th is 08:30 on the 6th at UTC+9
+		let clock = FixedClock::new(utc(2026, 1, 5, 23, 30))
+			.with_offset(FixedOffset::east_opt(9 * 3600).unwrap());
+		let task_repository = Arc::new(InMemoryTaskRepository::new());
+		let daily = Task {
+			recurrence: Some(Recurrence::Daily),
+			..task("Water the plants")
+		};
+		task_repository.create_task(daily.clone()).unwrap();
+
+		CompleteTask {
+			clock: Arc::new(clock),
+			task_repository: task_repository.clone(),
+			output_writer: Arc::new(InMemoryWriter::new()),
+			selection: TaskSelection::single(daily.id.to_string()),
+		}
+		.run()
+		.unwrap();
+
+		let tasks = task_repository.list_tasks().unwrap();
+		let next = tasks.iter().find(|t| t.id != daily.id).unwrap();
+		assert!(matches!(next.status, TaskStatus::Pending));
+		assert_eq!(next.due, NaiveDate::from_ymd_opt(2026, 1, 7));
 	}
 }
@@ -1,28 +1,68 @@
 use std::sync::Arc;
 
+use chrono::{Days, NaiveDate};
+
 use crate::clock::Clock;
+use crate::config::ReferenceMode;
 use crate::errors::PPMResult;
-use crate::models::{ProjectName, Task, TaskId, TaskStatus};
+use crate::models::{Estimate, ProjectName, Recurrence, Task, TaskId, TaskStatus};
 use crate::output::OutputWriter;
+use crate::prompt::Prompt;
+use crate::repositories::project::ProjectRepository;
 use crate::repositories::task::TaskRepository;
-use crate::services::Service;
+use crate::services::{Service, project};
 
 pub struct CreateTask {
 	pub clock: Arc<dyn Clock>,
 	pub task_repository: Arc<dyn TaskRepository>,
+	pub project_repository: Arc<dyn ProjectRepository>,
 	pub output_writer: Arc<dyn OutputWriter>,
+	pub prompt: Arc<dyn Prompt>,
 	pub project_name: Option<ProjectName>,
 	pub description: String,
+	pub due: Option<NaiveDate>,
+	pub recurrence: Option<Recurrence>,
+	pub estimate: Option<Estimate>,
+	pub tags: Vec<String>,
+	pub project_references: ReferenceMode,
 }
 
 impl Service for CreateTask {
 	fn run(&self) -> PPMResult<()> {
+		let now = self.clock.now()?;
+
+		if let Some(ref project_name) = self.project_name {
+			project::ensure_accepts_work(
+				self.project_repository.as_ref(),
+				self.output_writer.as_ref(),
+				self.prompt.as_ref(),
+				self.project_references,
+				now,
+				project_name,
+			)?;
+		}
+
+		// A recurring task without an explicit due date starts at its first occurrence on or
+		// after today.
+		let due = match (self.due, &self.recurrence) {
+			(Some(due), _) => Some(due),
+			(None, Some(recurrence)) => {
+				Some(recurrence.next_after(self.clock.local_date(now) - Days::new(1)))
+			}
+			(None, None) => None,
+		};
+
 		let task = Task {
 			id: TaskId::new(),
 			project_name: self.project_name.clone(),
 			description: self.description.clone(),
 			status: TaskStatus::Pending,
-			created_at: self.clock.now()?,
+			created_at: now,
+			due,
+			recurrence: self.recurrence.clone(),
+			estimate: self.estimate,
+			tags: normalize_tags(&self.tags),
+			workflow_state: None,
 		};
 
 		self.task_repository.create_task(task)?;
@@ -32,3 +72,15 @@ impl Service for CreateTask {
 		Ok(())
 	}
 }
+
+/// Lowercases and trims tags, dropping empty ones and duplicates.
+pub(crate) fn normalize_tags(tags: &[String]) -> Vec<String> {
+	let mut normalized: Vec<String> = Vec::new();
+	for tag in tags {
+		let tag = tag.trim().trim_start_matches('#').to_lowercase();
+		if !tag.is_empty() && !normalized.contains(&tag) {
+			normalized.push(tag);
+		}
+	}
+	normalized
+}
@@ -0,0 +1,60 @@
+use std::sync::Arc;
+
+use crate::clock::Clock;
+use crate::errors::PPMResult;
+use crate::models::{TaskId, TrashEntry, TrashedItem};
+use crate::output::OutputWriter;
+use crate::repositories::task::TaskRepository;
+use crate::repositories::trash::TrashRepository;
+use crate::services::Service;
+use crate::services::task::bulk::{self, BulkResult, TaskSelection};
+use crate::services::trash::purge_expired;
+
+/// Service to move tasks to the trash.
+pub struct DeleteTask {
+	pub clock: Arc<dyn Clock>,
+	pub task_repository: Arc<dyn TaskRepository>,
+	pub trash_repository: Arc<dyn TrashRepository>,
+	pub output_writer: Arc<dyn OutputWriter>,
+	pub trash_retention_days: u32,
+	pub selection: TaskSelection,
+}
+
+impl Service for DeleteTask {
+	fn run(&self) -> PPMResult<()> {
+		let now = self.clock.now()?;
+		purge_expired(
+			self.trash_repository.as_ref(),
+			self.output_writer.as_ref(),
+			now,
+			self.trash_retention_days,
+		)?;
+
+		let mut result = BulkResult::default();
+		let tasks = bulk::select_tasks(
+			self.task_repository.as_ref(),
+			&self.selection,
+			self.clock.local_date(now),
+			&mut result,
+		)?;
+
+		let task_ids: Vec<TaskId> = tasks.iter().map(|t| t.id.clone()).collect();
+		if !task_ids.is_empty() {
+			// Trash first: a failed delete leaves a copy behind rather than losing the tasks
+			self.trash_repository.add_entries(
+				tasks
+					.into_iter()
+					.map(|task| TrashEntry::new(TrashedItem::Task(task), now))
+					.collect(),
+			)?;
+			self.task_repository.delete_tasks(&task_ids)?;
+		}
+
+		for task_id in &task_ids {
+			self.output_writer.write_line(&format!("Task {} moved to trash", task_id))?;
+		}
+		result.succeeded = task_ids;
+
+		bulk::report(self.output_writer.as_ref(), "deleted", result)
+	}
+}
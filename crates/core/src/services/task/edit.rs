@@ -0,0 +1,55 @@
+use std::sync::Arc;
+
+use crate::editor::Editor;
+use crate::errors::{PPMError, PPMResult};
+use crate::models::{ProjectName, TaskId};
+use crate::output::OutputWriter;
+use crate::repositories::task::TaskRepository;
+use crate::services::Service;
+
+/// Service to change a task's description and/or project.
+///
+/// When neither field is given, the current description is opened in the editor.
+pub struct EditTask {
+	pub task_repository: Arc<dyn TaskRepository>,
+	pub output_writer: Arc<dyn OutputWriter>,
+	pub editor: Arc<dyn Editor>,
+	pub task_id: TaskId,
+	pub description: Option<String>,
+	pub project_name: Option<ProjectName>,
+}
+
+impl Service for EditTask {
+	fn run(&self) -> PPMResult<()> {
+		let task_id = self.task_repository.resolve_task_id(&self.task_id)?;
+
+		let mut task = self
+			.task_repository
+			.get_task(&task_id)?
+			.ok_or_else(|| PPMError::NotFound(format!("Task {} not found", task_id)))?;
+
+		if self.description.is_none() && self.project_name.is_none() {
+			match self.editor.open(Some(&task.description))? {
+				Some(description) => task.description = description,
+				None => {
+					self.output_writer
+						.write_line(&"Task edit cancelled (no description provided)")?;
+					return Ok(());
+				}
+			}
+		}
+
+		if let Some(ref description) = self.description {
+			task.description = description.clone();
+		}
+
+		if let Some(ref project_name) = self.project_name {
+			task.project_name = Some(project_name.clone());
+		}
+
+		self.task_repository.update_task(task)?;
+		self.output_writer.write_line(&format!("Task {} updated", task_id))?;
+
+		Ok(())
+	}
+}
@@ -0,0 +1,197 @@
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use chrono::Duration;
+
+use crate::clock::Clock;
+use crate::errors::PPMResult;
+use crate::models::{ProjectName, Task, TaskStatus};
+use crate::output::OutputWriter;
+use crate::repositories::{SessionRepository, TaskRepository};
+use crate::services::{Service, format_duration};
+
+/// Ratio buckets (actual / estimate) used for the distribution, as (upper bound, label).
+const RATIO_BUCKETS: [(f64, &str); 5] = [
+	(0.5, "< 0.5x"),
+	(0.8, "0.5x - 0.8x"),
+	(1.25, "0.8x - 1.25x"),
+	(2.0, "1.25x - 2x"),
+	(f64::INFINITY, ">= 2x"),
+];
+
+/// Service to compare task estimates with the focus time logged through linked sessions.
+///
+/// Only completed tasks that have both an estimate and logged focus time are measured.
+pub struct EstimateReport {
+	pub clock: Arc<dyn Clock>,
+	pub task_repository: Arc<dyn TaskRepository>,
+	pub session_repository: Arc<dyn SessionRepository>,
+	pub output_writer: Arc<dyn OutputWriter>,
+	/// Only measure tasks of this project and its subprojects
+	pub project_name: Option<ProjectName>,
+	/// Number of worst misses to display
+	pub limit: usize,
+}
+
+struct Measurement {
+	task: Task,
+	estimated: Duration,
+	actual: Duration,
+}
+
+impl Measurement {
+	fn ratio(&self) -> f64 {
+		self.actual.num_seconds() as f64 / self.estimated.num_seconds() as f64
+	}
+}
+
+impl EstimateReport {
+	fn collect_measurements(&self) -> PPMResult<(Vec<Measurement>, usize)> {
+		let now = self.clock.now()?;
+		let sessions = self.session_repository.list_sessions()?;
+
+		let mut measurements = Vec::new();
+		let mut without_focus_time = 0;
+
+		for task in self.task_repository.list_tasks()? {
+			if !matches!(task.status, TaskStatus::Done(_)) {
+				continue;
+			}
+			if let Some(ref project_name) = self.project_name
+				&& !task.project_name.as_ref().is_some_and(|name| project_name.includes(name))
+			{
+				continue;
+			}
+			let Some(estimate) = task.estimate.filter(|e| e.minutes() > 0) else {
+				continue;
+			};
+
+			let actual: Duration = sessions
+				.iter()
+				.filter(|s| s.associated_task_id.as_ref() == Some(&task.id))
+				.map(|s| s.elapsed(now))
+				.sum();
+
+			if actual.is_zero() {
+				without_focus_time += 1;
+				continue;
+			}
+
+			measurements.push(Measurement {
+				task,
+				estimated: Duration::minutes(i64::from(estimate.minutes())),
+				actual,
+			});
+		}
+
+		Ok((measurements, without_focus_time))
+	}
+
+	fn write_distribution(&self, measurements: &[Measurement]) -> PPMResult<()> {
+		self.output_writer.write_line(&"Ratio distribution (actual / estimate):")?;
+
+		let mut lower = 0.0;
+		for (upper, label) in RATIO_BUCKETS {
+			let count =
+				measurements.iter().filter(|m| m.ratio() >= lower && m.ratio() < upper).count();
+			self.output_writer.write_line(&format!(
+				"  {:<13} {:>3} {}",
+				label,
+				count,
+				"#".repeat(count)
+			))?;
+			lower = upper;
+		}
+
+		Ok(())
+	}
+
+	fn write_worst_misses(&self, measurements: &[Measurement]) -> PPMResult<()> {
+		// Misses are symmetric in log space: 2x over is as bad as 2x under.
+		let mut by_miss: Vec<&Measurement> = measurements.iter().collect();
+		by_miss.sort_by(|a, b| b.ratio().ln().abs().total_cmp(&a.ratio().ln().abs()));
+
+		self.output_writer.write_line(&"Worst misses:")?;
+		for m in by_miss.into_iter().take(self.limit) {
+			self.output_writer.write_line(&format!(
+				"  {} - {}: estimated {}, actual {} ({:.2}x)",
+				m.task.id,
+				m.task.description,
+				format_duration(&m.estimated),
+				format_duration(&m.actual),
+				m.ratio()
+			))?;
+		}
+
+		Ok(())
+	}
+
+	fn write_project_bias(&self, measurements: &[Measurement]) -> PPMResult<()> {
+		let mut by_project: BTreeMap<String, Vec<f64>> = BTreeMap::new();
+		for m in measurements {
+			let project = m.task.project_name.as_ref().map_or("Inbox", |p| p.as_ref());
+			by_project.entry(project.to_string()).or_default().push(m.ratio());
+		}
+
+		self.output_writer.write_line(&"Bias per project:")?;
+		for (project, ratios) in by_project {
+			let bias = geometric_mean(&ratios);
+			let direction = if bias >= 1.0 {
+				format!("underestimated by {:.0}%", (bias - 1.0) * 100.0)
+			} else {
+				format!("overestimated by {:.0}%", (1.0 - bias) * 100.0)
+			};
+
+			self.output_writer.write_line(&format!(
+				"  {}: {:.2}x, {} ({} task(s))",
+				project,
+				bias,
+				direction,
+				ratios.len()
+			))?;
+		}
+
+		Ok(())
+	}
+}
+
+impl Service for EstimateReport {
+	fn run(&self) -> PPMResult<()> {
+		let (measurements, without_focus_time) = self.collect_measurements()?;
+
+		if measurements.is_empty() {
+			self.output_writer
+				.write_line(&"No completed tasks with both an estimate and logged focus time")?;
+			return Ok(());
+		}
+
+		let ratios: Vec<f64> = measurements.iter().map(Measurement::ratio).collect();
+
+		self.output_writer.write_line(&"Estimation Accuracy")?;
+		self.output_writer.write_line(&format!(
+			"{} task(s) measured, typical ratio {:.2}x",
+			measurements.len(),
+			geometric_mean(&ratios)
+		))?;
+		if without_focus_time > 0 {
+			self.output_writer.write_line(&format!(
+				"{} estimated task(s) skipped (no linked focus time)",
+				without_focus_time
+			))?;
+		}
+		self.output_writer.write_line(&"")?;
+
+		self.write_distribution(&measurements)?;
+		self.output_writer.write_line(&"")?;
+		self.write_worst_misses(&measurements)?;
+		self.output_writer.write_line(&"")?;
+		self.write_project_bias(&measurements)?;
+
+		Ok(())
+	}
+}
+
+fn geometric_mean(values: &[f64]) -> f64 {
+	let log_sum: f64 = values.iter().map(|v| v.ln()).sum();
+	(log_sum / values.len() as f64).exp()
+}
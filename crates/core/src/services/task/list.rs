@@ -1,45 +1,30 @@
 use std::sync::Arc;
 
 use crate::errors::PPMResult;
-use crate::models::{Task, TaskStatus};
+use crate::models::{ModelId, Task, TaskId, TaskStatus};
 use crate::output::OutputWriter;
+use crate::query::TaskQuery;
 use crate::repositories::task::TaskRepository;
 use crate::services::Service;
 
 pub struct ListTasks {
 	pub task_repository: Arc<dyn TaskRepository>,
 	pub output_writer: Arc<dyn OutputWriter>,
-	pub filter: Option<TaskFilter>,
-}
-
-#[derive(Debug, Clone)]
-pub enum TaskFilter {
-	All,
-	Pending,
-	Done,
-	Canceled,
+	pub query: TaskQuery,
 }
 
 impl Service for ListTasks {
 	fn run(&self) -> PPMResult<()> {
 		let mut tasks = self.task_repository.list_tasks()?;
 
-		// Sort by created_at descending (newest first)
-		tasks.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+		// Sequence numbers follow creation order so they stay stable across filters
+		tasks.sort_by_key(|t| t.created_at);
+		let ids: Vec<TaskId> = tasks.iter().map(|t| t.id.clone()).collect();
+
+		// Newest first
+		tasks.reverse();
 
-		// Apply filter
-		let filtered_tasks: Vec<Task> = match self.filter {
-			Some(TaskFilter::Pending) => {
-				tasks.into_iter().filter(|t| matches!(t.status, TaskStatus::Pending)).collect()
-			}
-			Some(TaskFilter::Done) => {
-				tasks.into_iter().filter(|t| matches!(t.status, TaskStatus::Done(_))).collect()
-			}
-			Some(TaskFilter::Canceled) => {
-				tasks.into_iter().filter(|t| matches!(t.status, TaskStatus::Canceled(_))).collect()
-			}
-			Some(TaskFilter::All) | None => tasks,
-		};
+		let filtered_tasks = self.query.apply(tasks);
 
 		if filtered_tasks.is_empty() {
 			self.output_writer.write_line(&"No tasks found")?;
@@ -49,18 +34,42 @@ impl Service for ListTasks {
 		self.output_writer.write_line(&format!("{} task(s) found:", filtered_tasks.len()))?;
 
 		for task in filtered_tasks {
-			let status_display = match &task.status {
-				TaskStatus::Pending => "[ ]",
-				TaskStatus::Done(_) => "[✓]",
-				TaskStatus::Canceled(_) => "[✗]",
-			};
-
-			self.output_writer.write_line(&format!(
-				"  {} {} - {} ({:?})",
-				status_display, task.id, task.description, task.project_name
-			))?;
+			self.output_writer.write_line(&format_task_line(&task, &ids))?;
 		}
 
 		Ok(())
 	}
 }
+
+/// One-line summary of a task, as shown by `task list`.
+pub(crate) fn format_task_line(task: &Task, ids: &[TaskId]) -> String {
+	let status_display = match &task.status {
+		TaskStatus::Pending => "[ ]",
+		TaskStatus::Done(_) => "[✓]",
+		TaskStatus::Canceled(_) => "[✗]",
+	};
+
+	let mut details = String::new();
+	if let Some(due) = task.due {
+		details.push_str(&format!(" [due {}]", due));
+	}
+	if let Some(ref recurrence) = task.recurrence {
+		details.push_str(&format!(" [{}]", recurrence));
+	}
+	if let Some(estimate) = task.estimate {
+		details.push_str(&format!(" [est {}]", estimate));
+	}
+	if !task.tags.is_empty() {
+		details.push_str(&format!(" [tags: {}]", task.tags.join(", ")));
+	}
+
+	format!(
+		"  {} #{} {} - {} ({:?}){}",
+		status_display,
+		task.id.sequence_number(ids).unwrap_or_default(),
+		task.id,
+		task.description,
+		task.project_name,
+		details
+	)
+}
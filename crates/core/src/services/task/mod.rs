@@ -1,7 +1,29 @@
+pub mod board;
+pub mod bulk;
+pub mod cancel;
 pub mod complete;
 pub mod create;
+pub mod delete;
+pub mod edit;
+pub mod estimates;
 pub mod list;
+pub mod move_state;
+pub mod move_task;
+pub mod search;
+pub mod show;
+pub mod tag;
 
+pub use board::TaskBoard;
+pub use bulk::TaskSelection;
+pub use cancel::CancelTask;
 pub use complete::CompleteTask;
 pub use create::CreateTask;
-pub use list::{ListTasks, TaskFilter};
+pub use delete::DeleteTask;
+pub use edit::EditTask;
+pub use estimates::EstimateReport;
+pub use list::ListTasks;
+pub use move_state::MoveTaskState;
+pub use move_task::MoveTask;
+pub use search::SearchTasks;
+pub use show::ShowTask;
+pub use tag::TagTask;
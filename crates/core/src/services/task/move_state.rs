@@ -0,0 +1,91 @@
+use std::sync::Arc;
+
+use crate::clock::Clock;
+use crate::errors::{PPMError, PPMResult};
+use crate::models::{StateCategory, TaskStatus, Workflow};
+use crate::output::OutputWriter;
+use crate::repositories::task::TaskRepository;
+use crate::services::Service;
+use crate::services::task::complete::{next_occurrence, write_next_occurrence};
+
+/// Moves a task to another workflow state, following the configured transitions.
+///
+/// The task status follows the category of the new state; moving a recurring task into a
+/// done state creates its next occurrence, as `task done` does.
+pub struct MoveTaskState {
+	pub clock: Arc<dyn Clock>,
+	pub task_repository: Arc<dyn TaskRepository>,
+	pub output_writer: Arc<dyn OutputWriter>,
+	pub workflow: Workflow,
+	pub task_id: String,
+	pub state: String,
+}
+
+impl Service for MoveTaskState {
+	fn run(&self) -> PPMResult<()> {
+		let now = self.clock.now()?;
+		let task_id = self.task_repository.resolve_task_id(&self.task_id)?;
+		let mut task = self
+			.task_repository
+			.get_task(&task_id)?
+			.ok_or_else(|| PPMError::NotFound(format!("Task {} not found", task_id)))?;
+
+		let to = self.workflow.state(&self.state).ok_or_else(|| {
+			let names: Vec<&str> = self.workflow.states.iter().map(|s| s.name.as_str()).collect();
+			PPMError::InvalidInput(format!(
+				"Unknown state '{}' (expected one of: {})",
+				self.state,
+				names.join(", ")
+			))
+		})?;
+		let from = self.workflow.state_of(&task).ok_or_else(|| {
+			PPMError::ConfigError(format!("workflow has no state for task {}", task_id))
+		})?;
+
+		if from.name == to.name {
+			return Err(PPMError::InvalidInput(format!(
+				"Task {} is already in '{}'",
+				task_id, to.name
+			)));
+		}
+		if !self.workflow.can_move(from, to) {
+			let allowed = self.workflow.next_states(from);
+			return Err(PPMError::InvalidInput(format!(
+				"Cannot move task {} from '{}' to '{}' (allowed: {})",
+				task_id,
+				from.name,
+				to.name,
+				if allowed.is_empty() {
+					"none".to_string()
+				} else {
+					allowed.join(", ")
+				}
+			)));
+		}
+
+		let mut next = None;
+		if to.category != from.category {
+			task.status = match to.category {
+				StateCategory::Pending => TaskStatus::Pending,
+				StateCategory::Done => {
+					next = next_occurrence(&task, now, self.clock.local_date(now));
+					TaskStatus::Done(now)
+				}
+				StateCategory::Canceled => TaskStatus::Canceled(now),
+			};
+		}
+		let from_name = from.name.clone();
+		task.workflow_state = Some(to.name.clone());
+
+		self.task_repository
+			.upsert_tasks([Some(task), next.clone()].into_iter().flatten().collect())?;
+
+		self.output_writer
+			.write_line(&format!("Task {} moved from '{}' to '{}'", task_id, from_name, to.name))?;
+		if let Some(next) = &next {
+			write_next_occurrence(self.output_writer.as_ref(), next)?;
+		}
+
+		Ok(())
+	}
+}
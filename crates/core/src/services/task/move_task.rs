@@ -0,0 +1,62 @@
+use std::sync::Arc;
+
+use crate::clock::Clock;
+use crate::errors::{PPMError, PPMResult};
+use crate::models::ProjectName;
+use crate::output::OutputWriter;
+use crate::repositories::task::TaskRepository;
+use crate::services::Service;
+use crate::services::task::bulk::{self, BulkResult, TaskSelection};
+
+/// Service to move tasks to another project.
+pub struct MoveTask {
+	pub clock: Arc<dyn Clock>,
+	pub task_repository: Arc<dyn TaskRepository>,
+	pub output_writer: Arc<dyn OutputWriter>,
+	pub selection: TaskSelection,
+	pub project_name: ProjectName,
+}
+
+impl Service for MoveTask {
+	fn run(&self) -> PPMResult<()> {
+		let mut result = BulkResult::default();
+		let tasks = bulk::select_tasks(
+			self.task_repository.as_ref(),
+			&self.selection,
+			self.clock.local_date(self.clock.now()?),
+			&mut result,
+		)?;
+
+		let mut changed = Vec::new();
+
+		for mut task in tasks {
+			if task.project_name.as_ref() == Some(&self.project_name) {
+				result.failed.push((
+					task.id.to_string(),
+					PPMError::AlreadyExists(format!(
+						"Task {} is already in project '{}'",
+						task.id, self.project_name
+					)),
+				));
+				continue;
+			}
+
+			task.project_name = Some(self.project_name.clone());
+			result.succeeded.push(task.id.clone());
+			changed.push(task);
+		}
+
+		if !changed.is_empty() {
+			self.task_repository.upsert_tasks(changed)?;
+		}
+
+		for task_id in &result.succeeded {
+			self.output_writer.write_line(&format!(
+				"Task {} moved to project '{}'",
+				task_id, self.project_name
+			))?;
+		}
+
+		bulk::report(self.output_writer.as_ref(), "moved", result)
+	}
+}
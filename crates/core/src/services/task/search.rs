@@ -0,0 +1,31 @@
+use std::sync::Arc;
+
+use crate::clock::Clock;
+use crate::errors::PPMResult;
+use crate::output::OutputWriter;
+use crate::query::TaskQuery;
+use crate::repositories::task::TaskRepository;
+use crate::services::Service;
+use crate::services::task::ListTasks;
+
+/// Service to list the tasks matching a textual query (see [`TaskQuery`] for the syntax).
+pub struct SearchTasks {
+	pub clock: Arc<dyn Clock>,
+	pub task_repository: Arc<dyn TaskRepository>,
+	pub output_writer: Arc<dyn OutputWriter>,
+	pub query: String,
+}
+
+impl Service for SearchTasks {
+	fn run(&self) -> PPMResult<()> {
+		let today = self.clock.local_date(self.clock.now()?);
+		let query = TaskQuery::parse(&self.query, today)?;
+
+		ListTasks {
+			task_repository: self.task_repository.clone(),
+			output_writer: self.output_writer.clone(),
+			query,
+		}
+		.run()
+	}
+}
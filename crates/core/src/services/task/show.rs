@@ -0,0 +1,191 @@
+use std::sync::Arc;
+
+use chrono::Duration;
+
+use crate::clock::Clock;
+use crate::errors::{PPMError, PPMResult};
+use crate::models::{ModelId, NoteId, TaskId, TaskStatus, Workflow};
+use crate::output::OutputWriter;
+use crate::repositories::note::NoteRepository;
+use crate::repositories::session::SessionRepository;
+use crate::repositories::task::TaskRepository;
+use crate::services::{Service, format_duration, format_local_time};
+
+/// Service to show a task with its details, the focus time logged on it and its notes.
+pub struct ShowTask {
+	pub clock: Arc<dyn Clock>,
+	pub task_repository: Arc<dyn TaskRepository>,
+	pub session_repository: Arc<dyn SessionRepository>,
+	pub note_repository: Arc<dyn NoteRepository>,
+	pub output_writer: Arc<dyn OutputWriter>,
+	pub workflow: Workflow,
+	pub task_id: TaskId,
+}
+
+impl Service for ShowTask {
+	fn run(&self) -> PPMResult<()> {
+		let now = self.clock.now()?;
+		let task_id = self.task_repository.resolve_task_id(&self.task_id)?;
+		let mut tasks = self.task_repository.list_tasks()?;
+
+		// Sequence numbers follow creation order, as in `task list`
+		tasks.sort_by_key(|t| t.created_at);
+		let ids: Vec<TaskId> = tasks.iter().map(|t| t.id.clone()).collect();
+		let task = tasks
+			.into_iter()
+			.find(|t| t.id == task_id)
+			.ok_or_else(|| PPMError::NotFound(format!("Task {} not found", task_id)))?;
+
+		self.output_writer.write_line(&format!(
+			"Task #{} {}",
+			task.id.sequence_number(&ids).unwrap_or_default(),
+			task.id
+		))?;
+		self.output_writer.write_line(&format!("  {}", task.description))?;
+
+		let local_time = |time| format_local_time(self.clock.as_ref(), time);
+		let status_display = match task.status {
+			TaskStatus::Pending => "Pending".to_string(),
+			TaskStatus::Done(at) => format!("Done {}", local_time(at)),
+			TaskStatus::Canceled(at) => format!("Canceled {}", local_time(at)),
+		};
+		self.output_writer.write_line(&format!("  Status: {}", status_display))?;
+		if let Some(state) = self.workflow.state_of(&task) {
+			self.output_writer.write_line(&format!("  State: {}", state.name))?;
+		}
+		if let Some(ref project) = task.project_name {
+			self.output_writer.write_line(&format!("  Project: {}", project))?;
+		}
+		if let Some(due) = task.due {
+			self.output_writer.write_line(&format!("  Due: {}", due))?;
+		}
+		if let Some(ref recurrence) = task.recurrence {
+			self.output_writer.write_line(&format!("  Repeats: {}", recurrence))?;
+		}
+		if let Some(estimate) = task.estimate {
+			self.output_writer.write_line(&format!("  Estimate: {}", estimate))?;
+		}
+		if !task.tags.is_empty() {
+			self.output_writer.write_line(&format!("  Tags: {}", task.tags.join(", ")))?;
+		}
+		self.output_writer.write_line(&format!("  Created: {}", local_time(task.created_at)))?;
+
+		let sessions: Vec<Duration> = self
+			.session_repository
+			.list_sessions()?
+			.iter()
+			.filter(|s| s.associated_task_id.as_ref() == Some(&task.id))
+			.map(|s| s.elapsed(now))
+			.collect();
+		self.output_writer.write_line(&format!(
+			"  Focus time: {} in {} session(s)",
+			format_duration(&sessions.iter().sum()),
+			sessions.len()
+		))?;
+
+		let mut notes = self.note_repository.list_notes()?;
+		notes.sort_by_key(|n| n.created_at);
+		let note_ids: Vec<NoteId> = notes.iter().map(|n| n.id.clone()).collect();
+		notes.retain(|n| n.task_id.as_ref() == Some(&task.id));
+
+		self.output_writer.write_line(&"")?;
+		if notes.is_empty() {
+			self.output_writer.write_line(&"No notes attached")?;
+			return Ok(());
+		}
+
+		self.output_writer.write_line(&format!("Notes ({}):", notes.len()))?;
+		for note in notes {
+			self.output_writer.write_line(&format!(
+				"  #{} {} - {} ({})",
+				note.id.sequence_number(&note_ids).unwrap_or_default(),
+				note.id,
+				note.display_title(),
+				local_time(note.created_at)
+			))?;
+		}
+
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use chrono::FixedOffset;
+
+	use super::*;
+	use crate::clock::FixedClock;
+	use crate::models::{Note, Task};
+	use crate::output::InMemoryWriter;
+	use crate::repositories::{
+		InMemoryNoteRepository, InMemorySessionRepository, InMemoryTaskRepository,
+	};
+	use crate::test_support::{note, task, utc};
+
+	fn show(task: &Task, notes: Vec<Note>) -> Vec<String> {
+		let task_repository = Arc::new(InMemoryTaskRepository::new());
+		task_repository.create_task(task.clone()).unwrap();
+		let note_repository = Arc::new(InMemoryNoteRepository::new());
+		for note in notes {
+			note_repository.create_note(note).unwrap();
+		}
+		let output = Arc::new(InMemoryWriter::new());
+
+		ShowTask {
+			clock: Arc::new(
+				FixedClock::new(utc(2026, 1, 10, 9, 0))
+					.with_offset(FixedOffset::east_opt(9 * 3600).unwrap()),
+			),
+			task_repository,
+			session_repository: Arc::new(InMemorySessionRepository::new()),
+			note_repository,
+			output_writer: output.clone(),
+			workflow: Workflow::default(),
+			task_id: task.id.clone(),
+		}
+		.run()
+		.unwrap();
+		output.lines().unwrap()
+	}
+
+	#[test]
+	fn times_are_shown_in_local_time() {
+		let done = Task {
+			created_at: utc(2026, 1, 1, 20, 0),
+			status: TaskStatus::Done(utc(2026, 1, 2, 15, 30)),
+			..task("Finished late")
+		};
+
+		let lines = show(&done, Vec::new());
+		assert!(lines.contains(&"[ppm]   Status: Done 2026-01-03 00:30".to_string()));
+		assert!(lines.contains(&"[ppm]   Created: 2026-01-02 05:00".to_string()));
+		assert_eq!(lines.last().unwrap(), "[ppm] No notes attached");
+	}
+
+	#[test]
+	fn lists_only_the_notes_attached_to_the_task() {
+		let shown = task("Write report");
+		let attached = |content: &str, hour| Note {
+			task_id: Some(shown.id.clone()),
+			created_at: utc(2026, 1, 2, hour, 0),
+			..note(content)
+		};
+		let first = attached("Outline", 1);
+		let second = attached("Draft", 18);
+		let other = Note {
+			task_id: Some(TaskId::new()),
+			created_at: utc(2026, 1, 2, 2, 0),
+			..note("Elsewhere")
+		};
+
+		let lines = show(&shown, vec![second.clone(), other, first.clone()]);
+		let notes = &lines[lines.iter().position(|l| l == "[ppm] Notes (2):").unwrap() + 1..];
+		assert_eq!(
+			notes,
+			[
+				format!("[ppm]   #1 {} - Outline (2026-01-02 10:00)", first.id),
+				format!("[ppm]   #3 {} - Draft (2026-01-03 03:00)", second.id),
+			]
+		);
+	}
+}
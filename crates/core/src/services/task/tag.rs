@@ -0,0 +1,61 @@
+use std::sync::Arc;
+
+use crate::clock::Clock;
+use crate::errors::PPMResult;
+use crate::output::OutputWriter;
+use crate::repositories::task::TaskRepository;
+use crate::services::Service;
+use crate::services::task::bulk::{self, BulkResult, TaskSelection};
+use crate::services::task::create::normalize_tags;
+
+/// Service to add and remove tags on tasks.
+pub struct TagTask {
+	pub clock: Arc<dyn Clock>,
+	pub task_repository: Arc<dyn TaskRepository>,
+	pub output_writer: Arc<dyn OutputWriter>,
+	pub selection: TaskSelection,
+	pub add: Vec<String>,
+	pub remove: Vec<String>,
+}
+
+impl Service for TagTask {
+	fn run(&self) -> PPMResult<()> {
+		let mut result = BulkResult::default();
+		let tasks = bulk::select_tasks(
+			self.task_repository.as_ref(),
+			&self.selection,
+			self.clock.local_date(self.clock.now()?),
+			&mut result,
+		)?;
+
+		let add = normalize_tags(&self.add);
+		let remove = normalize_tags(&self.remove);
+		let mut changed = Vec::new();
+
+		for mut task in tasks {
+			task.tags.retain(|t| !remove.contains(t));
+			for tag in &add {
+				if !task.tags.contains(tag) {
+					task.tags.push(tag.clone());
+				}
+			}
+
+			result.succeeded.push(task.id.clone());
+			changed.push(task);
+		}
+
+		if !changed.is_empty() {
+			self.task_repository.upsert_tasks(changed.clone())?;
+		}
+
+		for task in &changed {
+			self.output_writer.write_line(&format!(
+				"Task {} tagged [{}]",
+				task.id,
+				task.tags.join(", ")
+			))?;
+		}
+
+		bulk::report(self.output_writer.as_ref(), "tagged", result)
+	}
+}
@@ -0,0 +1,85 @@
+use std::sync::Arc;
+
+use crate::errors::PPMResult;
+use crate::output::OutputWriter;
+use crate::repositories::trash::TrashRepository;
+use crate::services::Service;
+use crate::services::trash::resolve_entries;
+
+/// Service to permanently delete items in the trash, or everything when no IDs are given.
+pub struct EmptyTrash {
+	pub trash_repository: Arc<dyn TrashRepository>,
+	pub output_writer: Arc<dyn OutputWriter>,
+	/// Entry or item IDs, in full or as unique prefixes/suffixes
+	pub item_ids: Vec<String>,
+}
+
+impl Service for EmptyTrash {
+	fn run(&self) -> PPMResult<()> {
+		if !self.item_ids.is_empty() {
+			let entries = self.trash_repository.list_entries()?;
+			let selected = resolve_entries(&self.item_ids, &entries)?;
+			let entry_ids: Vec<_> = selected.iter().map(|e| e.id.clone()).collect();
+
+			self.trash_repository.remove_entries(&entry_ids)?;
+			for entry in selected {
+				self.output_writer.write_line(&format!(
+					"Permanently deleted {} {}",
+					entry.item.kind(),
+					entry.item.id()
+				))?;
+			}
+			return Ok(());
+		}
+
+		let count = self.trash_repository.clear()?;
+
+		if count == 0 {
+			self.output_writer.write_line(&"Trash is already empty")?;
+		} else {
+			self.output_writer
+				.write_line(&format!("Trash emptied, {} item(s) permanently deleted", count))?;
+		}
+
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::models::{TrashEntry, TrashedItem};
+	use crate::output::InMemoryWriter;
+	use crate::repositories::InMemoryTrashRepository;
+	use crate::test_support::{project, utc};
+
+	#[test]
+	fn empties_selected_entries_or_everything() {
+		let trash = Arc::new(InMemoryTrashRepository::new());
+		let entries: Vec<TrashEntry> = (1..=3)
+			.map(|day| {
+				TrashEntry::new(TrashedItem::Project(project("api")), utc(2026, 1, day, 0, 0))
+			})
+			.collect();
+		trash.add_entries(entries.clone()).unwrap();
+
+		let empty = |item_ids: Vec<String>| {
+			EmptyTrash {
+				trash_repository: trash.clone(),
+				output_writer: Arc::new(InMemoryWriter::new()),
+				item_ids,
+			}
+			.run()
+		};
+
+		assert!(empty(vec!["api".to_string()]).is_err());
+		assert_eq!(trash.list_entries().unwrap().len(), 3);
+
+		empty(vec![entries[1].id.to_string()]).unwrap();
+		let left: Vec<_> = trash.list_entries().unwrap().into_iter().map(|e| e.id).collect();
+		assert_eq!(left, vec![entries[0].id.clone(), entries[2].id.clone()]);
+
+		empty(Vec::new()).unwrap();
+		assert!(trash.list_entries().unwrap().is_empty());
+	}
+}
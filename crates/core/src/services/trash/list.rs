@@ -0,0 +1,66 @@
+use std::sync::Arc;
+
+use chrono::Duration;
+
+use crate::clock::Clock;
+use crate::errors::PPMResult;
+use crate::output::OutputWriter;
+use crate::repositories::trash::TrashRepository;
+use crate::services::Service;
+use crate::services::trash::purge_expired;
+
+/// Service to list trashed items, most recently deleted first.
+pub struct ListTrash {
+	pub clock: Arc<dyn Clock>,
+	pub trash_repository: Arc<dyn TrashRepository>,
+	pub output_writer: Arc<dyn OutputWriter>,
+	pub retention_days: u32,
+}
+
+impl Service for ListTrash {
+	fn run(&self) -> PPMResult<()> {
+		purge_expired(
+			self.trash_repository.as_ref(),
+			self.output_writer.as_ref(),
+			self.clock.now()?,
+			self.retention_days,
+		)?;
+
+		let mut entries = self.trash_repository.list_entries()?;
+		if entries.is_empty() {
+			self.output_writer.write_line(&"Trash is empty")?;
+			return Ok(());
+		}
+
+		entries.sort_by_key(|e| std::cmp::Reverse(e.deleted_at));
+
+		self.output_writer.write_line(&format!("{} item(s) in trash:", entries.len()))?;
+		for entry in &entries {
+			let purge_display = if self.retention_days > 0 {
+				let purge_date = entry.deleted_at + Duration::days(i64::from(self.retention_days));
+				format!(", purged after {}", purge_date.format("%Y-%m-%d"))
+			} else {
+				String::new()
+			};
+			// Items trashed more than once are told apart by their entry ID
+			let entry_display =
+				if entries.iter().filter(|e| e.item.id() == entry.item.id()).count() > 1 {
+					format!(", entry {}", entry.id)
+				} else {
+					String::new()
+				};
+
+			self.output_writer.write_line(&format!(
+				"  [{}] {} - {} (deleted {}{}{})",
+				entry.item.kind(),
+				entry.item.id(),
+				entry.item.summary(),
+				entry.deleted_at.format("%Y-%m-%d %H:%M"),
+				purge_display,
+				entry_display
+			))?;
+		}
+
+		Ok(())
+	}
+}
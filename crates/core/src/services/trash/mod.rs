@@ -0,0 +1,95 @@
+pub mod empty;
+pub mod list;
+pub mod restore;
+
+use chrono::{DateTime, Duration, Utc};
+pub use empty::EmptyTrash;
+pub use list::ListTrash;
+pub use restore::RestoreTrash;
+
+use crate::errors::{PPMError, PPMResult};
+use crate::models::TrashEntry;
+use crate::output::OutputWriter;
+use crate::repositories::trash::TrashRepository;
+
+/// Permanently removes trash entries older than the retention period.
+///
+/// A retention of 0 days keeps entries until the trash is emptied.
+pub(crate) fn purge_expired(
+	trash_repository: &dyn TrashRepository,
+	output_writer: &dyn OutputWriter,
+	now: DateTime<Utc>,
+	retention_days: u32,
+) -> PPMResult<()> {
+	if retention_days == 0 {
+		return Ok(());
+	}
+
+	let purged = trash_repository.purge_before(now - Duration::days(i64::from(retention_days)))?;
+	if !purged.is_empty() {
+		output_writer.write_line(&format!(
+			"{} item(s) older than {} day(s) purged from trash",
+			purged.len(),
+			retention_days
+		))?;
+	}
+
+	Ok(())
+}
+
+/// Resolves each input to a single trash entry, skipping repeats.
+///
+/// An input is an entry ID or the ID of a trashed item (the name for projects), in full or as
+/// a unique prefix or suffix. An input matching several entries, such as a project name that
+/// was trashed twice, is reported as ambiguous with the entry IDs to choose from.
+pub(crate) fn resolve_entries<'a>(
+	inputs: &[String],
+	entries: &'a [TrashEntry],
+) -> PPMResult<Vec<&'a TrashEntry>> {
+	let mut selected: Vec<&TrashEntry> = Vec::new();
+	for input in inputs {
+		let entry = resolve_entry(input, entries)?;
+		if !selected.iter().any(|e| e.id == entry.id) {
+			selected.push(entry);
+		}
+	}
+
+	Ok(selected)
+}
+
+fn resolve_entry<'a>(input: &str, entries: &'a [TrashEntry]) -> PPMResult<&'a TrashEntry> {
+	let ids = |e: &'a TrashEntry| [e.id.as_ref(), e.item.id()];
+
+	let mut matches: Vec<&TrashEntry> =
+		entries.iter().filter(|e| ids(e).contains(&input)).collect();
+	if matches.is_empty() {
+		matches = entries
+			.iter()
+			.filter(|e| {
+				ids(e).iter().any(|id| {
+					let body = id.split_once('_').map_or(*id, |(_, body)| body);
+					[id, body].iter().any(|s| s.starts_with(input) || s.ends_with(input))
+				})
+			})
+			.collect();
+	}
+
+	match matches.as_slice() {
+		[] => Err(PPMError::NotFound(format!("Nothing in the trash matches '{}'", input))),
+		[entry] => Ok(entry),
+		many => Err(PPMError::AmbiguousId(
+			input.to_string(),
+			many.iter()
+				.map(|e| {
+					format!(
+						"{} ({} {}, deleted {})",
+						e.id,
+						e.item.kind(),
+						e.item.id(),
+						e.deleted_at.format("%Y-%m-%d %H:%M")
+					)
+				})
+				.collect(),
+		)),
+	}
+}
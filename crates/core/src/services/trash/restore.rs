@@ -0,0 +1,170 @@
+use std::sync::Arc;
+
+use crate::clock::Clock;
+use crate::errors::PPMResult;
+use crate::models::TrashedItem;
+use crate::output::OutputWriter;
+use crate::repositories::note::NoteRepository;
+use crate::repositories::project::ProjectRepository;
+use crate::repositories::session::SessionRepository;
+use crate::repositories::task::TaskRepository;
+use crate::repositories::trash::TrashRepository;
+use crate::services::Service;
+use crate::services::trash::{purge_expired, resolve_entries};
+
+/// Service to move trashed items back to their stores.
+///
+/// Items are restored one by one; restoring stops at the first item that cannot be put back,
+/// e.g. because an item with the same ID was created since.
+pub struct RestoreTrash {
+	pub clock: Arc<dyn Clock>,
+	pub trash_repository: Arc<dyn TrashRepository>,
+	pub task_repository: Arc<dyn TaskRepository>,
+	pub project_repository: Arc<dyn ProjectRepository>,
+	pub note_repository: Arc<dyn NoteRepository>,
+	pub session_repository: Arc<dyn SessionRepository>,
+	pub output_writer: Arc<dyn OutputWriter>,
+	pub retention_days: u32,
+	/// Entry or item IDs, in full or as unique prefixes/suffixes
+	pub item_ids: Vec<String>,
+}
+
+impl RestoreTrash {
+	fn restore_item(&self, item: TrashedItem) -> PPMResult<()> {
+		match item {
+			TrashedItem::Task(task) => self.task_repository.create_task(task),
+			TrashedItem::Project(project) => self.project_repository.create_project(project),
+			TrashedItem::Note(note) => self.note_repository.create_note(note),
+			TrashedItem::Session(session) => self.session_repository.create_session(session),
+		}
+	}
+}
+
+impl Service for RestoreTrash {
+	fn run(&self) -> PPMResult<()> {
+		purge_expired(
+			self.trash_repository.as_ref(),
+			self.output_writer.as_ref(),
+			self.clock.now()?,
+			self.retention_days,
+		)?;
+
+		let entries = self.trash_repository.list_entries()?;
+		for entry in resolve_entries(&self.item_ids, &entries)? {
+			self.restore_item(entry.item.clone())?;
+			self.trash_repository.remove_entries(std::slice::from_ref(&entry.id))?;
+			self.output_writer.write_line(&format!(
+				"Restored {} {}",
+				entry.item.kind(),
+				entry.item.id()
+			))?;
+		}
+
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::clock::FixedClock;
+	use crate::errors::PPMError;
+	use crate::models::{Project, ProjectName, TrashEntry};
+	use crate::output::InMemoryWriter;
+	use crate::repositories::{
+		InMemoryNoteRepository, InMemoryProjectRepository, InMemorySessionRepository,
+		InMemoryTaskRepository, InMemoryTrashRepository,
+	};
+	use crate::test_support::{project, task, utc};
+
+	struct Fixture {
+		trash: Arc<InMemoryTrashRepository>,
+		tasks: Arc<InMemoryTaskRepository>,
+		projects: Arc<InMemoryProjectRepository>,
+		output: Arc<InMemoryWriter>,
+	}
+
+	impl Fixture {
+		fn new(entries: Vec<TrashEntry>) -> Self {
+			let fixture = Self {
+				trash: Arc::new(InMemoryTrashRepository::new()),
+				tasks: Arc::new(InMemoryTaskRepository::new()),
+				projects: Arc::new(InMemoryProjectRepository::new()),
+				output: Arc::new(InMemoryWriter::new()),
+			};
+			fixture.trash.add_entries(entries).unwrap();
+			fixture
+		}
+
+		fn restore(&self, item_ids: &[&str]) -> PPMResult<()> {
+			RestoreTrash {
+				clock: Arc::new(FixedClock::new(utc(2026, 1, 20, 0, 0))),
+				trash_repository: self.trash.clone(),
+				task_repository: self.tasks.clone(),
+				project_repository: self.projects.clone(),
+				note_repository: Arc::new(InMemoryNoteRepository::new()),
+				session_repository: Arc::new(InMemorySessionRepository::new()),
+				output_writer: self.output.clone(),
+				retention_days: 10,
+				item_ids: item_ids.iter().map(|s| s.to_string()).collect(),
+			}
+			.run()
+		}
+	}
+
+	fn project_entry(description: &str, day: u32) -> TrashEntry {
+		let project = Project {
+			description: description.to_string(),
+			..project("api")
+		};
+		TrashEntry::new(TrashedItem::Project(project), utc(2026, 1, day, 0, 0))
+	}
+
+	#[test]
+	fn restores_items_by_id_or_prefix() {
+		let first = task("First");
+		let second = task("Second");
+		let fixture = Fixture::new(vec![
+			TrashEntry::new(TrashedItem::Task(first.clone()), utc(2026, 1, 15, 0, 0)),
+			TrashEntry::new(TrashedItem::Task(second.clone()), utc(2026, 1, 15, 0, 0)),
+			TrashEntry::new(TrashedItem::Task(task("Expired")), utc(2026, 1, 1, 0, 0)),
+		]);
+
+		let suffix = &second.id.as_ref()[second.id.as_ref().len() - 6..];
+		fixture.restore(&[first.id.as_ref(), suffix, first.id.as_ref()]).unwrap();
+
+		assert_eq!(fixture.tasks.list_tasks().unwrap().len(), 2);
+		assert!(fixture.trash.list_entries().unwrap().is_empty());
+		let lines = fixture.output.lines().unwrap();
+		assert!(lines.iter().any(|l| l.contains("1 item(s) older than 10 day(s) purged")));
+		assert_eq!(lines.iter().filter(|l| l.contains("Restored task")).count(), 2);
+	}
+
+	#[test]
+	fn same_named_projects_need_their_entry_id() {
+		let older = project_entry("Older", 15);
+		let newer = project_entry("Newer", 16);
+		let fixture = Fixture::new(vec![older.clone(), newer.clone()]);
+
+		match fixture.restore(&["api"]) {
+			Err(PPMError::AmbiguousId(input, candidates)) => {
+				assert_eq!(input, "api");
+				assert_eq!(candidates.len(), 2);
+				assert!(candidates[0].starts_with(older.id.as_ref()));
+			}
+			other => panic!("expected an ambiguous ID, got {:?}", other),
+		}
+		assert!(fixture.projects.list_projects().unwrap().is_empty());
+
+		fixture.restore(&[newer.id.as_ref()]).unwrap();
+		let restored = fixture.projects.get_project(&ProjectName::from("api")).unwrap().unwrap();
+		assert_eq!(restored.description, "Newer");
+		let left = fixture.trash.list_entries().unwrap();
+		assert_eq!(left.len(), 1);
+		assert_eq!(left[0].id, older.id);
+
+		// The older one now clashes with the restored project and stays in the trash
+		assert!(fixture.restore(&["api"]).is_err());
+		assert_eq!(fixture.trash.list_entries().unwrap().len(), 1);
+	}
+}
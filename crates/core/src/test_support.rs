@@ -0,0 +1,141 @@
+//! Builders for the models used across unit tests.
+
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use chrono::{DateTime, Duration, TimeZone, Utc};
+
+use crate::errors::{PPMError, PPMResult};
+use crate::models::{
+	FocusSession, FocusSessionId, Note, NoteId, Project, ProjectName, ProjectStatus, Task, TaskId,
+	TaskStatus,
+};
+use crate::repositories::{InMemoryNoteRepository, NoteRepository};
+
+pub fn utc(year: i32, month: u32, day: u32, hour: u32, minute: u32) -> DateTime<Utc> {
+	Utc.with_ymd_and_hms(year, month, day, hour, minute, 0).unwrap()
+}
+
+/// Pending task without a project, created at 2026-01-01 00:00 UTC.
+pub fn task(description: &str) -> Task {
+	Task {
+		id: TaskId::new(),
+		project_name: None,
+		description: description.to_string(),
+		status: TaskStatus::Pending,
+		created_at: utc(2026, 1, 1, 0, 0),
+		due: None,
+		recurrence: None,
+		estimate: None,
+		tags: Vec::new(),
+		workflow_state: None,
+	}
+}
+
+/// Session of `minutes` minutes without a project or task.
+pub fn session(start: DateTime<Utc>, minutes: i64) -> FocusSession {
+	FocusSession {
+		id: FocusSessionId::new(),
+		associated_project_name: None,
+		associated_task_id: None,
+		start,
+		end: start + Duration::minutes(minutes),
+	}
+}
+
+/// Note without title or links, created at 2026-01-01 00:00 UTC.
+pub fn note(content: &str) -> Note {
+	Note {
+		id: NoteId::new(),
+		title: None,
+		project_name: None,
+		task_id: None,
+		session_id: None,
+		content: content.to_string(),
+		created_at: utc(2026, 1, 1, 0, 0),
+		updated_at: None,
+		journal: None,
+		extra_front_matter: Vec::new(),
+	}
+}
+
+/// Active project without milestones.
+pub fn project(name: &str) -> Project {
+	Project {
+		name: ProjectName::from(name),
+		description: String::new(),
+		created_at: utc(2026, 1, 1, 0, 0),
+		status: ProjectStatus::Active,
+		milestones: Vec::new(),
+	}
+}
+
+/// Fresh directory under the system temp directory, removed on drop.
+pub struct TempDir(PathBuf);
+
+impl TempDir {
+	pub fn new() -> Self {
+		static COUNT: AtomicUsize = AtomicUsize::new(0);
+		let path = std::env::temp_dir().join(format!(
+			"ppm-test-{}-{}",
+			std::process::id(),
+			COUNT.fetch_add(1, Ordering::Relaxed)
+		));
+		std::fs::create_dir_all(&path).unwrap();
+		Self(path)
+	}
+
+	pub fn path(&self) -> &Path {
+		&self.0
+	}
+}
+
+impl Drop for TempDir {
+	fn drop(&mut self) {
+		let _ = std::fs::remove_dir_all(&self.0);
+	}
+}
+
+/// In-memory note repository whose updates and deletes fail once `fail_writes` is set.
+#[derive(Default)]
+pub struct FailingNoteRepository {
+	pub inner: InMemoryNoteRepository,
+	pub fail_writes: std::sync::atomic::AtomicBool,
+}
+
+impl FailingNoteRepository {
+	fn check(&self) -> PPMResult<()> {
+		if self.fail_writes.load(Ordering::Relaxed) {
+			return Err(PPMError::IoError(std::io::Error::other("disk full")));
+		}
+		Ok(())
+	}
+}
+
+impl NoteRepository for FailingNoteRepository {
+	fn create_note(&self, note: Note) -> PPMResult<()> {
+		self.inner.create_note(note)
+	}
+
+	fn get_note(&self, note_id: &NoteId) -> PPMResult<Option<Note>> {
+		self.inner.get_note(note_id)
+	}
+
+	fn list_notes(&self) -> PPMResult<Vec<Note>> {
+		self.inner.list_notes()
+	}
+
+	fn list_notes_by_project(&self, project_name: &ProjectName) -> PPMResult<Vec<Note>> {
+		self.inner.list_notes_by_project(project_name)
+	}
+
+	fn update_note(&self, note: Note) -> PPMResult<()> {
+		self.check()?;
+		self.inner.update_note(note)
+	}
+
+	fn delete_note(&self, note_id: &NoteId) -> PPMResult<()> {
+		self.check()?;
+		self.inner.delete_note(note_id)
+	}
+}
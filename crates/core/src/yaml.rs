@@ -0,0 +1,580 @@
+//! A small YAML subset for markdown front matter.
+//!
+//! Supports a top-level mapping whose values are plain, quoted or block (`|`, `>`) scalars,
+//! flow (`[a, b]`) or block (`- a`) lists of scalars, and nested mappings. Every entry keeps
+//! its source text, so entries a caller does not understand can be written back unchanged.
+
+use model_macros::model;
+
+use crate::errors::{PPMError, PPMResult};
+
+/// A `key: value` entry of a mapping.
+#[model]
+#[derive(PartialEq)]
+pub struct YamlEntry {
+	pub key: String,
+	pub value: YamlValue,
+	/// Source lines of the entry, including the comments directly above it
+	pub source: String,
+}
+
+#[model]
+#[derive(PartialEq)]
+pub enum YamlValue {
+	Null,
+	Scalar(String),
+	List(Vec<String>),
+	Mapping(Vec<YamlEntry>),
+}
+
+impl YamlEntry {
+	/// Creates an entry whose source is rendered from `value`.
+	pub fn new(key: &str, value: YamlValue) -> Self {
+		Self {
+			key: key.to_string(),
+			source: render_entry(key, &value),
+			value,
+		}
+	}
+}
+
+impl YamlValue {
+	pub fn as_scalar(&self) -> Option<&str> {
+		match self {
+			YamlValue::Scalar(value) => Some(value),
+			_ => None,
+		}
+	}
+}
+
+/// Parses a block mapping, e.g. the text between the `---` lines of a front matter block.
+pub fn parse_mapping(text: &str) -> PPMResult<Vec<YamlEntry>> {
+	let lines: Vec<&str> = text.lines().collect();
+	let mut entries: Vec<YamlEntry> = Vec::new();
+	let mut leading: Vec<&str> = Vec::new();
+	let mut i = 0;
+
+	while i < lines.len() {
+		let line = lines[i];
+		let trimmed = line.trim();
+
+		if trimmed.is_empty() || trimmed.starts_with('#') {
+			leading.push(line);
+			i += 1;
+			continue;
+		}
+		if line.starts_with(char::is_whitespace) || trimmed.starts_with('-') {
+			return Err(invalid(i + 1, "unexpected indentation"));
+		}
+
+		let (key, inline) =
+			split_key(trimmed).ok_or_else(|| invalid(i + 1, "expected 'key: value'"))?;
+
+		// Indented lines, blank lines and block list items belong to this entry
+		let mut end = i + 1;
+		while end < lines.len() {
+			let next = lines[end];
+			if next.trim().is_empty()
+				|| next.starts_with(char::is_whitespace)
+				|| next.starts_with('-')
+			{
+				end += 1;
+			} else {
+				break;
+			}
+		}
+		// Trailing blank lines separate this entry from the next one
+		while end > i + 1 && lines[end - 1].trim().is_empty() {
+			end -= 1;
+		}
+
+		let value =
+			parse_value(inline, &lines[i + 1..end]).map_err(|reason| invalid(i + 1, &reason))?;
+		let source: Vec<&str> = leading.drain(..).chain(lines[i..end].iter().copied()).collect();
+
+		entries.push(YamlEntry {
+			key,
+			value,
+			source: source.join("\n"),
+		});
+		i = end;
+	}
+
+	// Keep comments at the end of the block with the last entry
+	if let Some(last) = entries.last_mut() {
+		for line in leading.into_iter().filter(|l| !l.trim().is_empty()) {
+			last.source.push('\n');
+			last.source.push_str(line);
+		}
+	}
+
+	Ok(entries)
+}
+
+/// Renders a scalar, quoting it when it would otherwise be read back differently.
+pub fn render_scalar(value: &str) -> String {
+	if needs_quotes(value) {
+		quote(value)
+	} else {
+		value.to_string()
+	}
+}
+
+/// Renders `key: value` lines in the style [`parse_mapping`] reads back.
+pub fn render_entry(key: &str, value: &YamlValue) -> String {
+	let key = render_scalar(key);
+
+	match value {
+		YamlValue::Null => format!("{}:", key),
+		YamlValue::Scalar(scalar) => format!("{}: {}", key, render_scalar(scalar)),
+		YamlValue::List(items) => {
+			let items: Vec<String> = items
+				.iter()
+				.map(|item| {
+					if item.contains([',', '[', ']', '{', '}']) {
+						quote(item)
+					} else {
+						render_scalar(item)
+					}
+				})
+				.collect();
+			format!("{}: [{}]", key, items.join(", "))
+		}
+		YamlValue::Mapping(entries) => {
+			let mut rendered = format!("{}:", key);
+			for entry in entries {
+				for line in entry.source.lines() {
+					rendered.push_str("\n  ");
+					rendered.push_str(line);
+				}
+			}
+			rendered
+		}
+	}
+}
+
+fn parse_value(inline: &str, block: &[&str]) -> Result<YamlValue, String> {
+	match inline.chars().next() {
+		None | Some('#') => parse_block(block),
+		Some('|') | Some('>') => Ok(YamlValue::Scalar(parse_block_scalar(inline, block))),
+		Some('[') => {
+			let text = fold(inline, block);
+			let text = strip_comment_after(&text, ']');
+			let inner = text
+				.strip_prefix('[')
+				.and_then(|t| t.strip_suffix(']'))
+				.ok_or_else(|| "unterminated list".to_string())?;
+			Ok(YamlValue::List(
+				split_flow_items(inner).into_iter().map(parse_scalar).collect::<Result<_, _>>()?,
+			))
+		}
+		Some('"') | Some('\'') => parse_scalar(&fold(inline, block)).map(YamlValue::Scalar),
+		_ => {
+			let text = fold(strip_comment(inline), block);
+			match text.as_str() {
+				"~" | "null" | "Null" | "NULL" => Ok(YamlValue::Null),
+				_ => Ok(YamlValue::Scalar(text)),
+			}
+		}
+	}
+}
+
+/// Parses the indented lines under a key with no inline value.
+fn parse_block(block: &[&str]) -> Result<YamlValue, String> {
+	let lines: Vec<&str> = dedent(block)
+		.into_iter()
+		.filter(|l| !l.trim().is_empty() && !l.trim_start().starts_with('#'))
+		.collect();
+	let Some(first) = lines.first() else {
+		return Ok(YamlValue::Null);
+	};
+
+	if first.starts_with("- ") || *first == "-" {
+		let mut items: Vec<String> = Vec::new();
+		for line in &lines {
+			if let Some(item) = line.strip_prefix('-').filter(|_| !line.starts_with(' ')) {
+				items.push(item.trim().to_string());
+			} else if let Some(current) = items.last_mut() {
+				current.push(' ');
+				current.push_str(line.trim());
+			}
+		}
+		return Ok(YamlValue::List(
+			items.iter().map(|i| parse_scalar(i)).collect::<Result<_, _>>()?,
+		));
+	}
+
+	if split_key(first).is_some() {
+		return parse_mapping(&dedent(block).join("\n"))
+			.map(YamlValue::Mapping)
+			.map_err(|e| e.to_string());
+	}
+
+	Ok(YamlValue::Scalar(fold("", &lines)))
+}
+
+/// Parses a `|` (literal) or `>` (folded) block scalar with an optional `-`/`+` chomping
+/// indicator.
+fn parse_block_scalar(header: &str, block: &[&str]) -> String {
+	let lines = dedent(block);
+
+	let mut text = if header.starts_with('|') {
+		lines.join("\n")
+	} else {
+		let mut folded = String::new();
+		for line in &lines {
+			if line.is_empty() {
+				folded.push('\n');
+			} else {
+				if !folded.is_empty() && !folded.ends_with('\n') {
+					folded.push(' ');
+				}
+				folded.push_str(line);
+			}
+		}
+		folded
+	};
+
+	if !strip_comment(header).contains('-') {
+		text.push('\n');
+	}
+	text
+}
+
+/// Parses a plain or quoted scalar, dropping a trailing comment.
+fn parse_scalar(text: &str) -> Result<String, String> {
+	let text = text.trim();
+
+	let Some(quote) = text.chars().next().filter(|c| *c == '"' || *c == '\'') else {
+		return Ok(strip_comment(text).to_string());
+	};
+
+	let mut value = String::new();
+	let mut chars = text[1..].chars();
+	loop {
+		match chars.next() {
+			None => return Err("unterminated quoted value".to_string()),
+			Some('\'') if quote == '\'' => {
+				// '' is an escaped quote in single-quoted scalars
+				if chars.clone().next() == Some('\'') {
+					chars.next();
+					value.push('\'');
+				} else {
+					break;
+				}
+			}
+			Some('"') if quote == '"' => break,
+			Some('\\') if quote == '"' => match chars.next() {
+				Some('n') => value.push('\n'),
+				Some('t') => value.push('\t'),
+				Some('r') => value.push('\r'),
+				Some('0') => value.push('\0'),
+				Some('u') => {
+					let code: String = chars.by_ref().take(4).collect();
+					let c = u32::from_str_radix(&code, 16)
+						.ok()
+						.and_then(char::from_u32)
+						.ok_or_else(|| format!("invalid escape '\\u{}'", code))?;
+					value.push(c);
+				}
+				Some(c) => value.push(c),
+				None => return Err("unterminated quoted value".to_string()),
+			},
+			Some(c) => value.push(c),
+		}
+	}
+
+	let rest = chars.as_str().trim();
+	if !rest.is_empty() && !rest.starts_with('#') {
+		return Err(format!("unexpected text after quoted value: '{}'", rest));
+	}
+	Ok(value)
+}
+
+/// Splits `key: rest` into the (unquoted) key and the trimmed rest of the line.
+fn split_key(line: &str) -> Option<(String, &str)> {
+	if line.starts_with(['"', '\'']) {
+		let quote = line.chars().next()?;
+		let close = line[1..].find(quote)? + 1;
+		let rest = line[close + 1..].trim_start().strip_prefix(':')?;
+		let key = parse_scalar(&line[..=close]).ok()?;
+		return Some((key, rest.trim()));
+	}
+
+	let colon = line
+		.char_indices()
+		.find(|&(i, c)| c == ':' && line[i + 1..].chars().next().is_none_or(char::is_whitespace))
+		.map(|(i, _)| i)?;
+	let key = line[..colon].trim();
+	(!key.is_empty()).then(|| (key.to_string(), line[colon + 1..].trim()))
+}
+
+/// Splits the inside of a flow list on commas outside quotes and brackets.
+fn split_flow_items(inner: &str) -> Vec<&str> {
+	let mut items = Vec::new();
+	let mut depth = 0;
+	let mut quote: Option<char> = None;
+	let mut start = 0;
+
+	for (i, c) in inner.char_indices() {
+		match (quote, c) {
+			(Some(q), c) if c == q => quote = None,
+			(Some(_), _) => {}
+			(None, '"' | '\'') => quote = Some(c),
+			(None, '[' | '{') => depth += 1,
+			(None, ']' | '}') => depth -= 1,
+			(None, ',') if depth == 0 => {
+				items.push(&inner[start..i]);
+				start = i + 1;
+			}
+			_ => {}
+		}
+	}
+	items.push(&inner[start..]);
+
+	items.into_iter().filter(|item| !item.trim().is_empty()).collect()
+}
+
+/// Joins an inline value and its continuation lines with single spaces.
+fn fold(inline: &str, block: &[&str]) -> String {
+	std::iter::once(inline)
+		.chain(block.iter().map(|l| l.trim()))
+		.filter(|l| !l.is_empty())
+		.collect::<Vec<_>>()
+		.join(" ")
+}
+
+/// Removes the common indentation of the non-blank lines; blank lines become empty.
+///
+/// Only ASCII spaces and tabs count as indentation, so other whitespace (e.g. U+3000) stays
+/// part of the line and slicing never splits a character.
+fn dedent<'a>(block: &[&'a str]) -> Vec<&'a str> {
+	let indent = block
+		.iter()
+		.filter(|l| !l.trim().is_empty())
+		.map(|l| l.bytes().take_while(|b| matches!(b, b' ' | b'\t')).count())
+		.min()
+		.unwrap_or(0);
+
+	block
+		.iter()
+		.map(|l| {
+			if l.trim().is_empty() {
+				""
+			} else {
+				&l[indent..]
+			}
+		})
+		.collect()
+}
+
+/// Drops a ` # comment` from a plain value.
+fn strip_comment(text: &str) -> &str {
+	if text.starts_with('#') {
+		return "";
+	}
+	match text.find(" #") {
+		Some(i) => text[..i].trim_end(),
+		None => text.trim_end(),
+	}
+}
+
+/// Drops a comment following the closing `end` character of a flow value.
+fn strip_comment_after(text: &str, end: char) -> &str {
+	match text.rfind(end) {
+		Some(i) if text[i + 1..].trim().is_empty() || text[i + 1..].trim().starts_with('#') => {
+			&text[..=i]
+		}
+		_ => text,
+	}
+}
+
+fn needs_quotes(value: &str) -> bool {
+	value.is_empty()
+		|| value != value.trim()
+		|| value.contains(": ")
+		|| value.contains(" #")
+		|| value.ends_with(':')
+		|| value.contains(['\n', '\r', '\t'])
+		|| value.starts_with([
+			'-', '?', ':', ',', '[', ']', '{', '}', '#', '&', '*', '!', '|', '>', '\'', '"', '%',
+			'@', '`',
+		]) || matches!(
+		value.to_lowercase().as_str(),
+		"~" | "null" | "true" | "false" | "yes" | "no" | "on" | "off"
+	)
+}
+
+fn quote(value: &str) -> String {
+	let mut quoted = String::from("\"");
+	for c in value.chars() {
+		match c {
+			'"' => quoted.push_str("\\\""),
+			'\\' => quoted.push_str("\\\\"),
+			'\n' => quoted.push_str("\\n"),
+			'\r' => quoted.push_str("\\r"),
+			'\t' => quoted.push_str("\\t"),
+			c => quoted.push(c),
+		}
+	}
+	quoted.push('"');
+	quoted
+}
+
+fn invalid(line: usize, reason: &str) -> PPMError {
+	PPMError::InvalidInput(format!("Invalid YAML on line {}: {}", line, reason))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn scalar(value: &str) -> YamlValue {
+		YamlValue::Scalar(value.to_string())
+	}
+
+	fn list(items: &[&str]) -> YamlValue {
+		YamlValue::List(items.iter().map(|i| i.to_string()).collect())
+	}
+
+	fn values(text: &str) -> Vec<(String, YamlValue)> {
+		parse_mapping(text).unwrap().into_iter().map(|e| (e.key, e.value)).collect()
+	}
+
+	#[test]
+	fn parses_plain_and_quoted_scalars() {
+		let parsed = values(concat!(
+			"plain: hello world # comment\n",
+			"double: \"a: b # not a comment\\n\"\n",
+			"single: 'it''s'\n",
+			"empty:\n",
+			"tilde: ~\n",
+			"url: https://example.com/a#b\n",
+			"\"quoted key\": 1\n",
+			"'single key': 2",
+		));
+
+		assert_eq!(
+			parsed,
+			vec![
+				("plain".to_string(), scalar("hello world")),
+				("double".to_string(), scalar("a: b # not a comment\n")),
+				("single".to_string(), scalar("it's")),
+				("empty".to_string(), YamlValue::Null),
+				("tilde".to_string(), YamlValue::Null),
+				("url".to_string(), scalar("https://example.com/a#b")),
+				("quoted key".to_string(), scalar("1")),
+				("single key".to_string(), scalar("2")),
+			]
+		);
+	}
+
+	#[test]
+	fn parses_flow_and_block_lists() {
+		let parsed = values(concat!(
+			"flow: [a, \"b, c\", 'd'] # comment\n",
+			"multiline: [one,\n  two]\n",
+			"block:\n",
+			"  - x\n",
+			"  # comment\n",
+			"  - \"y: z\"\n",
+			"unindented:\n",
+			"- p\n",
+			"- q",
+		));
+
+		assert_eq!(
+			parsed,
+			vec![
+				("flow".to_string(), list(&["a", "b, c", "d"])),
+				("multiline".to_string(), list(&["one", "two"])),
+				("block".to_string(), list(&["x", "y: z"])),
+				("unindented".to_string(), list(&["p", "q"])),
+			]
+		);
+	}
+
+	#[test]
+	fn parses_block_scalars() {
+		let parsed = values(concat!(
+			"literal: |\n",
+			"  line one\n",
+			"    indented\n",
+			"\n",
+			"  line three\n",
+			"folded: >-\n",
+			"  folded\n",
+			"  text\n",
+			"next: value",
+		));
+
+		assert_eq!(
+			parsed,
+			vec![
+				("literal".to_string(), scalar("line one\n  indented\n\nline three\n")),
+				("folded".to_string(), scalar("folded text")),
+				("next".to_string(), scalar("value")),
+			]
+		);
+	}
+
+	#[test]
+	fn parses_nested_mappings() {
+		let parsed = values("meta:\n  author: me\n  tags: [a]\ntop: 1");
+
+		assert_eq!(parsed[0].0, "meta");
+		let YamlValue::Mapping(ref nested) = parsed[0].1 else {
+			panic!("expected a mapping, got {:?}", parsed[0].1);
+		};
+		assert_eq!(nested[0].value, scalar("me"));
+		assert_eq!(nested[1].value, list(&["a"]));
+		assert_eq!(parsed[1], ("top".to_string(), scalar("1")));
+	}
+
+	#[test]
+	fn keeps_source_with_leading_comments() {
+		let text = "# about a\na: 1\n\n# about b\nb:\n  - x\n  - y\n# trailing";
+		let entries = parse_mapping(text).unwrap();
+
+		assert_eq!(entries[0].source, "# about a\na: 1");
+		assert_eq!(entries[1].source, "\n# about b\nb:\n  - x\n  - y\n# trailing");
+		let joined: Vec<&str> = entries.iter().map(|e| e.source.as_str()).collect();
+		assert_eq!(joined.join("\n"), text);
+	}
+
+	#[test]
+	fn non_ascii_indentation_does_not_panic() {
+		let parsed = values("note:\n\u{3000}\u{3000}text\n  more");
+
+		assert_eq!(parsed, vec![("note".to_string(), scalar("text more"))]);
+	}
+
+	#[test]
+	fn rejects_malformed_input() {
+		assert!(parse_mapping("  indented: 1").is_err());
+		assert!(parse_mapping("no colon here").is_err());
+		assert!(parse_mapping("a: \"open").is_err());
+		assert!(parse_mapping("a: [1, 2").is_err());
+	}
+
+	#[test]
+	fn rendered_entries_read_back() {
+		let cases = [
+			scalar("plain"),
+			scalar("needs: quotes"),
+			scalar("#hash"),
+			scalar("yes"),
+			scalar(" padded "),
+			scalar("multi\nline"),
+			scalar("say \"hi\" \\ bye"),
+			list(&["a", "b, c", "[x]", "- dash"]),
+			YamlValue::Null,
+		];
+
+		for value in cases {
+			let rendered = render_entry("key", &value);
+			let parsed = parse_mapping(&rendered).unwrap();
+			assert_eq!(parsed[0].value, value, "rendered as {:?}", rendered);
+		}
+	}
+}
@@ -7,6 +7,9 @@ use syn::{
 };
 
 /// #[model_id(prefix = "task_", gen = crate::models::gen_id)]
+///
+/// The generated type also implements `crate::models::ModelId`, so it must be expanded inside
+/// a crate that defines that trait.
 struct ModelIdArgs {
 	prefix: LitStr,
 	r#gen: Path,
@@ -85,6 +88,10 @@ fn expand_impl(args: ModelIdArgs, st: ItemStruct) -> Result<proc_macro2::TokenSt
 			}
 		}
 
+		impl crate::models::ModelId for #ident {
+			const PREFIX: &'static str = #prefix;
+		}
+
 		impl ::core::default::Default for #ident {
 			#[inline]
 			fn default() -> Self {
@@ -0,0 +1,30 @@
+use clap::Args;
+use ppm_core::context::PPMContext;
+use ppm_core::models::ProjectName;
+use ppm_core::services::Service;
+use ppm_core::services::task::TaskBoard;
+
+use crate::commands::CommandHandler;
+
+#[derive(Args, Debug)]
+pub struct BoardCommand {
+	/// Only show tasks of this project
+	#[arg(short, long)]
+	pub project: Option<ProjectName>,
+
+	/// Also show canceled tasks
+	#[arg(short, long)]
+	pub all: bool,
+}
+
+impl CommandHandler for BoardCommand {
+	fn build_service(self, context: PPMContext) -> Box<dyn Service> {
+		Box::new(TaskBoard {
+			task_repository: context.task_repository.clone(),
+			output_writer: context.output_writer.clone(),
+			workflow: context.config.workflow.clone(),
+			project_name: self.project,
+			show_canceled: self.all,
+		})
+	}
+}
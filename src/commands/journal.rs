@@ -0,0 +1,22 @@
+use clap::Args;
+use ppm_core::context::PPMContext;
+use ppm_core::services::Service;
+use ppm_core::services::note::OpenJournal;
+
+use crate::commands::CommandHandler;
+
+#[derive(Args, Debug)]
+pub struct JournalCommand {}
+
+impl CommandHandler for JournalCommand {
+	fn build_service(self, context: PPMContext) -> Box<dyn Service> {
+		Box::new(OpenJournal {
+			clock: context.clock.clone(),
+			note_repository: context.note_repository.clone(),
+			session_repository: context.session_repository.clone(),
+			task_repository: context.task_repository.clone(),
+			output_writer: context.output_writer.clone(),
+			editor: context.editor.clone(),
+		})
+	}
+}
@@ -0,0 +1,26 @@
+use clap::Args;
+use ppm_core::context::PPMContext;
+use ppm_core::services::Service;
+use ppm_core::services::migrate::MigrateIds;
+
+use crate::commands::CommandHandler;
+
+#[derive(Args, Debug)]
+pub struct MigrateCommand {
+	/// Only report what would change
+	#[arg(long)]
+	pub dry_run: bool,
+}
+
+impl CommandHandler for MigrateCommand {
+	fn build_service(self, context: PPMContext) -> Box<dyn Service> {
+		Box::new(MigrateIds {
+			task_repository: context.task_repository.clone(),
+			session_repository: context.session_repository.clone(),
+			project_repository: context.project_repository.clone(),
+			note_repository: context.note_repository.clone(),
+			output_writer: context.output_writer.clone(),
+			dry_run: self.dry_run,
+		})
+	}
+}
@@ -1,9 +1,13 @@
+mod board;
+mod journal;
+mod migrate;
 mod note;
 mod project;
 mod session;
 mod task;
+mod trash;
 
-use clap::{Subcommand, command};
+use clap::Subcommand;
 use ppm_core::context::PPMContext;
 use ppm_core::services::Service;
 
@@ -17,6 +21,12 @@ pub enum PPMCommand {
 	#[command(subcommand)]
 	Task(task::TaskCommand),
 
+	/// Show tasks as columns of workflow states
+	Board(board::BoardCommand),
+
+	/// Open today's journal note, creating it if needed
+	Journal(journal::JournalCommand),
+
 	/// Note management
 	#[command(subcommand)]
 	Note(note::NoteCommand),
@@ -24,6 +34,13 @@ pub enum PPMCommand {
 	/// Project management
 	#[command(subcommand)]
 	Project(project::ProjectCommand),
+
+	/// Deleted tasks, projects and notes
+	#[command(subcommand)]
+	Trash(trash::TrashCommand),
+
+	/// Give duplicate task and session IDs from older versions fresh ones
+	Migrate(migrate::MigrateCommand),
 }
 
 impl CommandHandler for PPMCommand {
@@ -31,8 +48,12 @@ impl CommandHandler for PPMCommand {
 		match self {
 			Self::Sess(c) => c.build_service(context),
 			Self::Task(c) => c.build_service(context),
+			Self::Board(c) => c.build_service(context),
+			Self::Journal(c) => c.build_service(context),
 			Self::Note(c) => c.build_service(context),
 			Self::Project(c) => c.build_service(context),
+			Self::Trash(c) => c.build_service(context),
+			Self::Migrate(c) => c.build_service(context),
 		}
 	}
 }
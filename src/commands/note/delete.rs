@@ -15,8 +15,11 @@ pub struct DeleteCommand {
 impl CommandHandler for DeleteCommand {
 	fn build_service(self, context: PPMContext) -> Box<dyn Service> {
 		Box::new(DeleteNote {
+			clock: context.clock.clone(),
 			note_repository: context.note_repository.clone(),
+			trash_repository: context.trash_repository.clone(),
 			output_writer: context.output_writer.clone(),
+			trash_retention_days: context.config.trash_retention_days,
 			note_id: self.note_id,
 		})
 	}
@@ -0,0 +1,25 @@
+use clap::Args;
+use ppm_core::context::PPMContext;
+use ppm_core::models::NoteId;
+use ppm_core::services::Service;
+use ppm_core::services::note::EditNote;
+
+use crate::commands::CommandHandler;
+
+#[derive(Args, Debug)]
+pub struct EditCommand {
+	/// Note ID to edit
+	pub note_id: NoteId,
+}
+
+impl CommandHandler for EditCommand {
+	fn build_service(self, context: PPMContext) -> Box<dyn Service> {
+		Box::new(EditNote {
+			clock: context.clock.clone(),
+			note_repository: context.note_repository.clone(),
+			output_writer: context.output_writer.clone(),
+			editor: context.editor.clone(),
+			note_id: self.note_id,
+		})
+	}
+}
@@ -0,0 +1,26 @@
+use clap::Args;
+use ppm_core::context::PPMContext;
+use ppm_core::models::NoteId;
+use ppm_core::services::Service;
+use ppm_core::services::note::NoteLinks;
+
+use crate::commands::CommandHandler;
+
+#[derive(Args, Debug)]
+pub struct LinksCommand {
+	/// Note ID to show links of (reports broken links across all notes when omitted)
+	pub note_id: Option<NoteId>,
+}
+
+impl CommandHandler for LinksCommand {
+	fn build_service(self, context: PPMContext) -> Box<dyn Service> {
+		Box::new(NoteLinks {
+			note_repository: context.note_repository.clone(),
+			task_repository: context.task_repository.clone(),
+			project_repository: context.project_repository.clone(),
+			session_repository: context.session_repository.clone(),
+			output_writer: context.output_writer.clone(),
+			note_id: self.note_id,
+		})
+	}
+}
@@ -1,5 +1,6 @@
 use clap::Args;
 use ppm_core::context::PPMContext;
+use ppm_core::models::ProjectName;
 use ppm_core::services::Service;
 use ppm_core::services::note::ListNotes;
 
@@ -10,6 +11,10 @@ pub struct ListCommand {
 	/// Limit the number of notes to display
 	#[arg(short, long)]
 	pub limit: Option<usize>,
+
+	/// Only list notes of this project and its subprojects
+	#[arg(short, long)]
+	pub project: Option<ProjectName>,
 }
 
 impl CommandHandler for ListCommand {
@@ -18,6 +23,7 @@ impl CommandHandler for ListCommand {
 			note_repository: context.note_repository.clone(),
 			output_writer: context.output_writer.clone(),
 			limit: self.limit,
+			project_name: self.project,
 		})
 	}
 }
@@ -3,8 +3,12 @@ use clap::Subcommand;
 use crate::commands::CommandHandler;
 
 pub mod delete;
+pub mod edit;
+pub mod links;
 pub mod list;
 pub mod new;
+pub mod search;
+pub mod show;
 
 #[derive(Debug, Subcommand)]
 pub enum NoteCommand {
@@ -12,7 +16,15 @@ pub enum NoteCommand {
 	New(new::NewCommand),
 	/// List notes
 	List(list::ListCommand),
-	/// Delete a note
+	/// Search notes by content, ranked by relevance
+	Search(search::SearchCommand),
+	/// Show a note in full
+	Show(show::ShowCommand),
+	/// Edit a note in the editor
+	Edit(edit::EditCommand),
+	/// Show a note's [[links]] and backlinks, or every broken link
+	Links(links::LinksCommand),
+	/// Move a note to the trash
 	Delete(delete::DeleteCommand),
 }
 
@@ -24,6 +36,10 @@ impl CommandHandler for NoteCommand {
 		match self {
 			NoteCommand::New(c) => c.build_service(context),
 			NoteCommand::List(c) => c.build_service(context),
+			NoteCommand::Search(c) => c.build_service(context),
+			NoteCommand::Show(c) => c.build_service(context),
+			NoteCommand::Edit(c) => c.build_service(context),
+			NoteCommand::Links(c) => c.build_service(context),
 			NoteCommand::Delete(c) => c.build_service(context),
 		}
 	}
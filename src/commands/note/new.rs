@@ -1,8 +1,8 @@
 use clap::Args;
 use ppm_core::context::PPMContext;
-use ppm_core::models::ProjectName;
+use ppm_core::models::{ProjectName, TaskId};
 use ppm_core::services::Service;
-use ppm_core::services::note::CreateNote;
+use ppm_core::services::note::{CreateNote, NoteSource};
 
 use crate::commands::CommandHandler;
 
@@ -11,6 +11,26 @@ pub struct NewCommand {
 	/// Optional project name to associate with (if not provided, uses active session's project)
 	#[arg(long, short)]
 	pub project_name: Option<ProjectName>,
+
+	/// Task to attach the note to (if not provided, uses active session's task)
+	#[arg(long)]
+	pub task: Option<TaskId>,
+
+	/// Note title (defaults to the first heading of the note)
+	#[arg(long, short)]
+	pub title: Option<String>,
+
+	/// Start from a template in the templates directory, e.g. meeting
+	#[arg(long)]
+	pub template: Option<String>,
+
+	/// Note text; skips the editor
+	#[arg(long, short, conflicts_with = "stdin")]
+	pub message: Option<String>,
+
+	/// Read the note text from stdin; skips the editor
+	#[arg(long)]
+	pub stdin: bool,
 }
 
 impl CommandHandler for NewCommand {
@@ -19,9 +39,23 @@ impl CommandHandler for NewCommand {
 			clock: context.clock.clone(),
 			note_repository: context.note_repository.clone(),
 			session_repository: context.session_repository.clone(),
+			project_repository: context.project_repository.clone(),
+			task_repository: context.task_repository.clone(),
+			template_repository: context.template_repository.clone(),
 			output_writer: context.output_writer.clone(),
+			prompt: context.prompt.clone(),
 			editor: context.editor.clone(),
+			input_reader: context.input_reader.clone(),
 			project_name: self.project_name,
+			task_id: self.task,
+			title: self.title,
+			template: self.template,
+			source: match self.message {
+				Some(message) => NoteSource::Message(message),
+				None if self.stdin => NoteSource::Stdin,
+				None => NoteSource::Editor,
+			},
+			project_references: context.config.project_references,
 		})
 	}
 }
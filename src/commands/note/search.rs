@@ -0,0 +1,45 @@
+use clap::Args;
+use ppm_core::context::PPMContext;
+use ppm_core::models::ProjectName;
+use ppm_core::services::Service;
+use ppm_core::services::note::SearchNotes;
+
+use crate::commands::CommandHandler;
+
+#[derive(Args, Debug)]
+pub struct SearchCommand {
+	/// Words or quoted phrases that must all appear in the note
+	#[arg(required = true, num_args = 1..)]
+	pub terms: Vec<String>,
+
+	/// Only search notes of this project and its subprojects
+	#[arg(short, long)]
+	pub project: Option<ProjectName>,
+
+	/// Only search notes created on or after this date (YYYY-MM-DD, today, yesterday or a weekday)
+	#[arg(long)]
+	pub since: Option<String>,
+
+	/// Only search notes created on or before this date
+	#[arg(long)]
+	pub until: Option<String>,
+
+	/// Limit the number of results
+	#[arg(short, long)]
+	pub limit: Option<usize>,
+}
+
+impl CommandHandler for SearchCommand {
+	fn build_service(self, context: PPMContext) -> Box<dyn Service> {
+		Box::new(SearchNotes {
+			clock: context.clock.clone(),
+			note_repository: context.note_repository.clone(),
+			output_writer: context.output_writer.clone(),
+			terms: self.terms,
+			project_name: self.project,
+			since: self.since,
+			until: self.until,
+			limit: self.limit,
+		})
+	}
+}
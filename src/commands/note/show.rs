@@ -0,0 +1,24 @@
+use clap::Args;
+use ppm_core::context::PPMContext;
+use ppm_core::models::NoteId;
+use ppm_core::services::Service;
+use ppm_core::services::note::ShowNote;
+
+use crate::commands::CommandHandler;
+
+#[derive(Args, Debug)]
+pub struct ShowCommand {
+	/// Note ID to show
+	pub note_id: NoteId,
+}
+
+impl CommandHandler for ShowCommand {
+	fn build_service(self, context: PPMContext) -> Box<dyn Service> {
+		Box::new(ShowNote {
+			clock: context.clock.clone(),
+			note_repository: context.note_repository.clone(),
+			output_writer: context.output_writer.clone(),
+			note_id: self.note_id,
+		})
+	}
+}
@@ -0,0 +1,56 @@
+use clap::Args;
+use ppm_core::context::PPMContext;
+use ppm_core::models::ProjectName;
+use ppm_core::services::Service;
+use ppm_core::services::project::{DeleteMode, DeleteProject};
+
+use crate::commands::CommandHandler;
+
+#[derive(Args, Debug)]
+pub struct DeleteCommand {
+	/// Project name
+	pub name: String,
+
+	/// Also move the project's tasks, notes and sessions to the trash
+	#[arg(long, group = "mode")]
+	pub cascade: bool,
+
+	/// Keep the project's tasks, notes and sessions without a project
+	#[arg(long, group = "mode")]
+	pub orphan: bool,
+
+	/// Move the project's tasks, notes and sessions to another project
+	#[arg(long, group = "mode", value_name = "PROJECT")]
+	pub reassign: Option<ProjectName>,
+
+	/// Skip the confirmation
+	#[arg(short, long)]
+	pub yes: bool,
+}
+
+impl CommandHandler for DeleteCommand {
+	fn build_service(self, context: PPMContext) -> Box<dyn Service> {
+		let mode = if self.cascade {
+			Some(DeleteMode::Cascade)
+		} else if self.orphan {
+			Some(DeleteMode::Orphan)
+		} else {
+			self.reassign.map(DeleteMode::Reassign)
+		};
+
+		Box::new(DeleteProject {
+			clock: context.clock.clone(),
+			project_repository: context.project_repository.clone(),
+			task_repository: context.task_repository.clone(),
+			note_repository: context.note_repository.clone(),
+			session_repository: context.session_repository.clone(),
+			trash_repository: context.trash_repository.clone(),
+			output_writer: context.output_writer.clone(),
+			prompt: context.prompt.clone(),
+			trash_retention_days: context.config.trash_retention_days,
+			name: ProjectName::from(self.name),
+			mode,
+			skip_confirmation: self.yes,
+		})
+	}
+}
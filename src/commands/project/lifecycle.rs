@@ -0,0 +1,55 @@
+use clap::Args;
+use ppm_core::context::PPMContext;
+use ppm_core::models::ProjectName;
+use ppm_core::services::Service;
+use ppm_core::services::project::{ActivateProject, ArchiveProject, DeactivateProject};
+
+use crate::commands::CommandHandler;
+
+#[derive(Args, Debug)]
+pub struct ActivateCommand {
+	/// Project name
+	pub name: String,
+}
+
+#[derive(Args, Debug)]
+pub struct DeactivateCommand {
+	/// Project name
+	pub name: String,
+}
+
+#[derive(Args, Debug)]
+pub struct ArchiveCommand {
+	/// Project name
+	pub name: String,
+}
+
+impl CommandHandler for ActivateCommand {
+	fn build_service(self, context: PPMContext) -> Box<dyn Service> {
+		Box::new(ActivateProject {
+			project_repository: context.project_repository.clone(),
+			output_writer: context.output_writer.clone(),
+			name: ProjectName::from(self.name),
+		})
+	}
+}
+
+impl CommandHandler for DeactivateCommand {
+	fn build_service(self, context: PPMContext) -> Box<dyn Service> {
+		Box::new(DeactivateProject {
+			project_repository: context.project_repository.clone(),
+			output_writer: context.output_writer.clone(),
+			name: ProjectName::from(self.name),
+		})
+	}
+}
+
+impl CommandHandler for ArchiveCommand {
+	fn build_service(self, context: PPMContext) -> Box<dyn Service> {
+		Box::new(ArchiveProject {
+			project_repository: context.project_repository.clone(),
+			output_writer: context.output_writer.clone(),
+			name: ProjectName::from(self.name),
+		})
+	}
+}
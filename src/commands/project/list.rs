@@ -5,7 +5,7 @@ use crate::commands::CommandHandler;
 
 #[derive(Args, Debug)]
 pub struct ListCommand {
-	/// Filter projects by status
+	/// Filter projects by status (archived projects are hidden by default)
 	#[arg(short, long, value_enum)]
 	pub filter: Option<FilterOption>,
 }
@@ -15,6 +15,7 @@ pub enum FilterOption {
 	All,
 	Active,
 	Inactive,
+	Archived,
 }
 
 impl ListCommand {
@@ -34,6 +35,7 @@ impl CommandHandler for ListCommand {
 			FilterOption::All => ProjectFilter::All,
 			FilterOption::Active => ProjectFilter::Active,
 			FilterOption::Inactive => ProjectFilter::Inactive,
+			FilterOption::Archived => ProjectFilter::Archived,
 		});
 
 		Box::new(ListProjects {
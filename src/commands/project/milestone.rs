@@ -0,0 +1,107 @@
+use chrono::NaiveDate;
+use clap::{Args, Subcommand};
+use ppm_core::context::PPMContext;
+use ppm_core::models::ProjectName;
+use ppm_core::services::Service;
+use ppm_core::services::project::{AddMilestone, LinkMilestoneTasks, RemoveMilestone};
+
+use crate::commands::CommandHandler;
+
+#[derive(Subcommand, Debug)]
+pub enum MilestoneCommand {
+	/// Add a milestone with a target date
+	Add(AddCommand),
+	/// Link tasks to a milestone
+	Link(LinkCommand),
+	/// Unlink tasks from a milestone
+	Unlink(UnlinkCommand),
+	/// Remove a milestone; its tasks are kept
+	Remove(RemoveCommand),
+}
+
+#[derive(Args, Debug)]
+pub struct AddCommand {
+	/// Project name
+	pub project: String,
+
+	/// Milestone name
+	pub name: String,
+
+	/// Target date (YYYY-MM-DD)
+	#[arg(long, short)]
+	pub target: NaiveDate,
+}
+
+#[derive(Args, Debug)]
+pub struct LinkCommand {
+	/// Project name
+	pub project: String,
+
+	/// Milestone name
+	pub name: String,
+
+	/// Task IDs
+	#[arg(required = true)]
+	pub task_ids: Vec<String>,
+}
+
+#[derive(Args, Debug)]
+pub struct UnlinkCommand {
+	/// Project name
+	pub project: String,
+
+	/// Milestone name
+	pub name: String,
+
+	/// Task IDs
+	#[arg(required = true)]
+	pub task_ids: Vec<String>,
+}
+
+#[derive(Args, Debug)]
+pub struct RemoveCommand {
+	/// Project name
+	pub project: String,
+
+	/// Milestone name
+	pub name: String,
+}
+
+impl CommandHandler for MilestoneCommand {
+	fn build_service(self, context: PPMContext) -> Box<dyn Service> {
+		match self {
+			MilestoneCommand::Add(c) => Box::new(AddMilestone {
+				clock: context.clock.clone(),
+				project_repository: context.project_repository.clone(),
+				output_writer: context.output_writer.clone(),
+				project_name: ProjectName::from(c.project),
+				name: c.name,
+				target: c.target,
+			}),
+			MilestoneCommand::Link(c) => Box::new(LinkMilestoneTasks {
+				project_repository: context.project_repository.clone(),
+				task_repository: context.task_repository.clone(),
+				output_writer: context.output_writer.clone(),
+				project_name: ProjectName::from(c.project),
+				name: c.name,
+				link: c.task_ids,
+				unlink: Vec::new(),
+			}),
+			MilestoneCommand::Unlink(c) => Box::new(LinkMilestoneTasks {
+				project_repository: context.project_repository.clone(),
+				task_repository: context.task_repository.clone(),
+				output_writer: context.output_writer.clone(),
+				project_name: ProjectName::from(c.project),
+				name: c.name,
+				link: Vec::new(),
+				unlink: c.task_ids,
+			}),
+			MilestoneCommand::Remove(c) => Box::new(RemoveMilestone {
+				project_repository: context.project_repository.clone(),
+				output_writer: context.output_writer.clone(),
+				project_name: ProjectName::from(c.project),
+				name: c.name,
+			}),
+		}
+	}
+}
@@ -1,7 +1,12 @@
 use clap::Subcommand;
 
+mod delete;
+mod lifecycle;
 mod list;
+mod milestone;
 mod new;
+mod rename;
+mod show;
 
 use crate::commands::CommandHandler;
 
@@ -9,6 +14,21 @@ use crate::commands::CommandHandler;
 pub enum ProjectCommand {
 	New(new::NewCommand),
 	List(list::ListCommand),
+	/// Show a project's tasks, focus time and milestone progress
+	Show(show::ShowCommand),
+	/// Manage project milestones
+	#[command(subcommand)]
+	Milestone(milestone::MilestoneCommand),
+	/// Rename a project and update every task, note and session referring to it
+	Rename(rename::RenameCommand),
+	/// Make a project active again
+	Activate(lifecycle::ActivateCommand),
+	/// Pause a project; new work on it triggers a warning
+	Deactivate(lifecycle::DeactivateCommand),
+	/// Archive a finished project; it is hidden and closed to new work
+	Archive(lifecycle::ArchiveCommand),
+	/// Move a project to the trash
+	Delete(delete::DeleteCommand),
 }
 
 impl CommandHandler for ProjectCommand {
@@ -19,6 +39,13 @@ impl CommandHandler for ProjectCommand {
 		match self {
 			ProjectCommand::New(new_command) => new_command.build_service(context),
 			ProjectCommand::List(list_command) => list_command.build_service(context),
+			ProjectCommand::Show(c) => c.build_service(context),
+			ProjectCommand::Milestone(c) => c.build_service(context),
+			ProjectCommand::Rename(c) => c.build_service(context),
+			ProjectCommand::Activate(c) => c.build_service(context),
+			ProjectCommand::Deactivate(c) => c.build_service(context),
+			ProjectCommand::Archive(c) => c.build_service(context),
+			ProjectCommand::Delete(delete_command) => delete_command.build_service(context),
 		}
 	}
 }
@@ -0,0 +1,30 @@
+use clap::Args;
+use ppm_core::context::PPMContext;
+use ppm_core::models::ProjectName;
+use ppm_core::services::Service;
+use ppm_core::services::project::RenameProject;
+
+use crate::commands::CommandHandler;
+
+#[derive(Args, Debug)]
+pub struct RenameCommand {
+	/// Current project name
+	pub old_name: String,
+
+	/// New project name
+	pub new_name: String,
+}
+
+impl CommandHandler for RenameCommand {
+	fn build_service(self, context: PPMContext) -> Box<dyn Service> {
+		Box::new(RenameProject {
+			project_repository: context.project_repository.clone(),
+			task_repository: context.task_repository.clone(),
+			note_repository: context.note_repository.clone(),
+			session_repository: context.session_repository.clone(),
+			output_writer: context.output_writer.clone(),
+			old_name: ProjectName::from(self.old_name),
+			new_name: ProjectName::from(self.new_name),
+		})
+	}
+}
@@ -0,0 +1,26 @@
+use clap::Args;
+use ppm_core::context::PPMContext;
+use ppm_core::models::ProjectName;
+use ppm_core::services::Service;
+use ppm_core::services::project::ShowProject;
+
+use crate::commands::CommandHandler;
+
+#[derive(Args, Debug)]
+pub struct ShowCommand {
+	/// Project name
+	pub name: String,
+}
+
+impl CommandHandler for ShowCommand {
+	fn build_service(self, context: PPMContext) -> Box<dyn Service> {
+		Box::new(ShowProject {
+			clock: context.clock.clone(),
+			project_repository: context.project_repository.clone(),
+			task_repository: context.task_repository.clone(),
+			session_repository: context.session_repository.clone(),
+			output_writer: context.output_writer.clone(),
+			name: ProjectName::from(self.name),
+		})
+	}
+}
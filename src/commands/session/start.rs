@@ -1,6 +1,6 @@
 use clap::Args;
 use ppm_core::context::PPMContext;
-use ppm_core::models::ProjectName;
+use ppm_core::models::{ProjectName, TaskId};
 use ppm_core::services::Service;
 use ppm_core::services::session::StartFocusSession;
 
@@ -13,6 +13,10 @@ pub struct StartCommand {
 
 	#[arg(long, short)]
 	pub associated_project_name: Option<ProjectName>,
+
+	/// Task to log this session's focus time against
+	#[arg(long, short)]
+	pub task: Option<TaskId>,
 }
 
 impl CommandHandler for StartCommand {
@@ -20,11 +24,16 @@ impl CommandHandler for StartCommand {
 		Box::new(StartFocusSession {
 			clock: context.clock.clone(),
 			repository: context.session_repository.clone(),
+			task_repository: context.task_repository.clone(),
+			project_repository: context.project_repository.clone(),
 			output_writer: context.output_writer.clone(),
+			prompt: context.prompt.clone(),
 			duration_in_minutes: self
 				.duration
 				.unwrap_or(context.config.default_focus_duration_in_minutes),
 			associated_project_name: self.associated_project_name,
+			associated_task_id: self.task,
+			project_references: context.config.project_references,
 		})
 	}
 }
@@ -1,16 +1,21 @@
 use clap::Args;
 use ppm_core::context::PPMContext;
+use ppm_core::models::ProjectName;
 use ppm_core::services::Service;
 use ppm_core::services::session::GetSessionStats;
 
 use crate::commands::CommandHandler;
 
 #[derive(Args, Debug, Default)]
-pub struct StatsCommand {}
+pub struct StatsCommand {
+	/// Only count sessions of this project and its subprojects
+	#[arg(short, long)]
+	pub project: Option<ProjectName>,
+}
 
 impl StatsCommand {
 	pub fn new() -> Self {
-		Self {}
+		Self::default()
 	}
 }
 
@@ -20,6 +25,7 @@ impl CommandHandler for StatsCommand {
 			clock: context.clock.clone(),
 			repository: context.session_repository.clone(),
 			output_writer: context.output_writer.clone(),
+			project_name: self.project,
 		})
 	}
 }
@@ -0,0 +1,24 @@
+use clap::Args;
+use ppm_core::context::PPMContext;
+use ppm_core::services::Service;
+use ppm_core::services::task::CancelTask;
+
+use crate::commands::CommandHandler;
+use crate::commands::task::selection::SelectionArgs;
+
+#[derive(Args, Debug)]
+pub struct CancelCommand {
+	#[command(flatten)]
+	pub selection: SelectionArgs,
+}
+
+impl CommandHandler for CancelCommand {
+	fn build_service(self, context: PPMContext) -> Box<dyn Service> {
+		Box::new(CancelTask {
+			clock: context.clock.clone(),
+			task_repository: context.task_repository.clone(),
+			output_writer: context.output_writer.clone(),
+			selection: self.selection.into(),
+		})
+	}
+}
@@ -0,0 +1,26 @@
+use clap::Args;
+use ppm_core::context::PPMContext;
+use ppm_core::services::Service;
+use ppm_core::services::task::DeleteTask;
+
+use crate::commands::CommandHandler;
+use crate::commands::task::selection::SelectionArgs;
+
+#[derive(Args, Debug)]
+pub struct DeleteCommand {
+	#[command(flatten)]
+	pub selection: SelectionArgs,
+}
+
+impl CommandHandler for DeleteCommand {
+	fn build_service(self, context: PPMContext) -> Box<dyn Service> {
+		Box::new(DeleteTask {
+			clock: context.clock.clone(),
+			task_repository: context.task_repository.clone(),
+			trash_repository: context.trash_repository.clone(),
+			output_writer: context.output_writer.clone(),
+			trash_retention_days: context.config.trash_retention_days,
+			selection: self.selection.into(),
+		})
+	}
+}
@@ -1,15 +1,15 @@
 use clap::Args;
 use ppm_core::context::PPMContext;
-use ppm_core::models::TaskId;
 use ppm_core::services::Service;
 use ppm_core::services::task::CompleteTask;
 
 use crate::commands::CommandHandler;
+use crate::commands::task::selection::SelectionArgs;
 
 #[derive(Args, Debug)]
 pub struct DoneCommand {
-	/// Task ID to mark as done
-	pub task_id: TaskId,
+	#[command(flatten)]
+	pub selection: SelectionArgs,
 }
 
 impl CommandHandler for DoneCommand {
@@ -18,7 +18,7 @@ impl CommandHandler for DoneCommand {
 			clock: context.clock.clone(),
 			task_repository: context.task_repository.clone(),
 			output_writer: context.output_writer.clone(),
-			task_id: self.task_id,
+			selection: self.selection.into(),
 		})
 	}
 }
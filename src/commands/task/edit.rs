@@ -0,0 +1,34 @@
+use clap::Args;
+use ppm_core::context::PPMContext;
+use ppm_core::models::{ProjectName, TaskId};
+use ppm_core::services::Service;
+use ppm_core::services::task::EditTask;
+
+use crate::commands::CommandHandler;
+
+#[derive(Args, Debug)]
+pub struct EditCommand {
+	/// Task ID to edit
+	pub task_id: TaskId,
+
+	/// New description (opens the editor when neither flag is given)
+	#[arg(long, short)]
+	pub description: Option<String>,
+
+	/// New project name
+	#[arg(long, short)]
+	pub project: Option<ProjectName>,
+}
+
+impl CommandHandler for EditCommand {
+	fn build_service(self, context: PPMContext) -> Box<dyn Service> {
+		Box::new(EditTask {
+			task_repository: context.task_repository.clone(),
+			output_writer: context.output_writer.clone(),
+			editor: context.editor.clone(),
+			task_id: self.task_id,
+			description: self.description,
+			project_name: self.project,
+		})
+	}
+}
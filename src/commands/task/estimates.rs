@@ -0,0 +1,31 @@
+use clap::Args;
+use ppm_core::context::PPMContext;
+use ppm_core::models::ProjectName;
+use ppm_core::services::Service;
+use ppm_core::services::task::EstimateReport;
+
+use crate::commands::CommandHandler;
+
+#[derive(Args, Debug)]
+pub struct EstimatesCommand {
+	/// Only measure tasks of this project
+	#[arg(long, short)]
+	pub project: Option<ProjectName>,
+
+	/// Number of worst misses to display
+	#[arg(short, long, default_value_t = 5)]
+	pub limit: usize,
+}
+
+impl CommandHandler for EstimatesCommand {
+	fn build_service(self, context: PPMContext) -> Box<dyn Service> {
+		Box::new(EstimateReport {
+			clock: context.clock.clone(),
+			task_repository: context.task_repository.clone(),
+			session_repository: context.session_repository.clone(),
+			output_writer: context.output_writer.clone(),
+			project_name: self.project,
+			limit: self.limit,
+		})
+	}
+}
@@ -1,7 +1,9 @@
 use clap::Args;
 use ppm_core::context::PPMContext;
+use ppm_core::models::ProjectName;
+use ppm_core::query::{StatusFilter, TaskPredicate, TaskQuery};
 use ppm_core::services::Service;
-use ppm_core::services::task::{ListTasks, TaskFilter};
+use ppm_core::services::task::ListTasks;
 
 use crate::commands::CommandHandler;
 
@@ -18,24 +20,36 @@ pub struct ListCommand {
 	/// Show only canceled tasks
 	#[arg(long)]
 	pub canceled: bool,
+
+	/// Show only tasks of this project and its subprojects
+	#[arg(short, long)]
+	pub project: Option<ProjectName>,
 }
 
 impl CommandHandler for ListCommand {
 	fn build_service(self, context: PPMContext) -> Box<dyn Service> {
-		let filter = if self.pending {
-			Some(TaskFilter::Pending)
+		let status = if self.pending {
+			Some(StatusFilter::Pending)
 		} else if self.done {
-			Some(TaskFilter::Done)
+			Some(StatusFilter::Done)
 		} else if self.canceled {
-			Some(TaskFilter::Canceled)
+			Some(StatusFilter::Canceled)
 		} else {
-			Some(TaskFilter::All)
+			None
 		};
 
+		let mut query = TaskQuery::all();
+		if let Some(status) = status {
+			query = query.with(TaskPredicate::Status(status));
+		}
+		if let Some(project) = self.project {
+			query = query.with(TaskPredicate::Project(Some(project)));
+		}
+
 		Box::new(ListTasks {
 			task_repository: context.task_repository.clone(),
 			output_writer: context.output_writer.clone(),
-			filter,
+			query,
 		})
 	}
 }
@@ -2,9 +2,19 @@ use clap::Subcommand;
 
 use crate::commands::CommandHandler;
 
+pub mod cancel;
+pub mod delete;
 pub mod done;
+pub mod edit;
+pub mod estimates;
 pub mod list;
+pub mod move_state;
+pub mod move_task;
 pub mod new;
+pub mod search;
+mod selection;
+pub mod show;
+pub mod tag;
 
 #[derive(Debug, Subcommand)]
 pub enum TaskCommand {
@@ -12,8 +22,26 @@ pub enum TaskCommand {
 	New(new::NewCommand),
 	/// List tasks
 	List(list::ListCommand),
-	/// Mark a task as done
+	/// Show a task with its focus time and notes
+	Show(show::ShowCommand),
+	/// Mark tasks as done
 	Done(done::DoneCommand),
+	/// Cancel tasks
+	Cancel(cancel::CancelCommand),
+	/// Edit a task's description or project
+	Edit(edit::EditCommand),
+	/// Move tasks to another project
+	Move(move_task::MoveCommand),
+	/// Move a task to another workflow state
+	MoveState(move_state::MoveStateCommand),
+	/// Add or remove tags on tasks
+	Tag(tag::TagCommand),
+	/// Move tasks to the trash
+	Delete(delete::DeleteCommand),
+	/// Compare estimates with the focus time logged on completed tasks
+	Estimates(estimates::EstimatesCommand),
+	/// Search tasks with a query
+	Search(search::SearchCommand),
 }
 
 impl CommandHandler for TaskCommand {
@@ -24,7 +52,16 @@ impl CommandHandler for TaskCommand {
 		match self {
 			TaskCommand::New(c) => c.build_service(context),
 			TaskCommand::List(c) => c.build_service(context),
+			TaskCommand::Show(c) => c.build_service(context),
 			TaskCommand::Done(c) => c.build_service(context),
+			TaskCommand::Cancel(c) => c.build_service(context),
+			TaskCommand::Edit(c) => c.build_service(context),
+			TaskCommand::Move(c) => c.build_service(context),
+			TaskCommand::MoveState(c) => c.build_service(context),
+			TaskCommand::Tag(c) => c.build_service(context),
+			TaskCommand::Delete(c) => c.build_service(context),
+			TaskCommand::Estimates(c) => c.build_service(context),
+			TaskCommand::Search(c) => c.build_service(context),
 		}
 	}
 }
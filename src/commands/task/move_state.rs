@@ -0,0 +1,28 @@
+use clap::Args;
+use ppm_core::context::PPMContext;
+use ppm_core::services::Service;
+use ppm_core::services::task::MoveTaskState;
+
+use crate::commands::CommandHandler;
+
+#[derive(Args, Debug)]
+pub struct MoveStateCommand {
+	/// Task ID, sequence number, or unique prefix/suffix
+	pub task_id: String,
+
+	/// Destination workflow state
+	pub state: String,
+}
+
+impl CommandHandler for MoveStateCommand {
+	fn build_service(self, context: PPMContext) -> Box<dyn Service> {
+		Box::new(MoveTaskState {
+			clock: context.clock.clone(),
+			task_repository: context.task_repository.clone(),
+			output_writer: context.output_writer.clone(),
+			workflow: context.config.workflow.clone(),
+			task_id: self.task_id,
+			state: self.state,
+		})
+	}
+}
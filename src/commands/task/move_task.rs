@@ -0,0 +1,30 @@
+use clap::Args;
+use ppm_core::context::PPMContext;
+use ppm_core::models::ProjectName;
+use ppm_core::services::Service;
+use ppm_core::services::task::MoveTask;
+
+use crate::commands::CommandHandler;
+use crate::commands::task::selection::SelectionArgs;
+
+#[derive(Args, Debug)]
+pub struct MoveCommand {
+	#[command(flatten)]
+	pub selection: SelectionArgs,
+
+	/// Destination project name
+	#[arg(long, short)]
+	pub project: ProjectName,
+}
+
+impl CommandHandler for MoveCommand {
+	fn build_service(self, context: PPMContext) -> Box<dyn Service> {
+		Box::new(MoveTask {
+			clock: context.clock.clone(),
+			task_repository: context.task_repository.clone(),
+			output_writer: context.output_writer.clone(),
+			selection: self.selection.into(),
+			project_name: self.project,
+		})
+	}
+}
@@ -1,6 +1,7 @@
+use chrono::NaiveDate;
 use clap::Args;
 use ppm_core::context::PPMContext;
-use ppm_core::models::ProjectName;
+use ppm_core::models::{Estimate, ProjectName, Recurrence};
 use ppm_core::services::Service;
 use ppm_core::services::task::CreateTask;
 
@@ -14,6 +15,22 @@ pub struct NewCommand {
 	/// Project name
 	#[arg(long, short)]
 	pub project: Option<ProjectName>,
+
+	/// Due date (YYYY-MM-DD)
+	#[arg(long)]
+	pub due: Option<NaiveDate>,
+
+	/// Repeat the task: daily, weekdays, weekly:<day>, monthly:<1-31> or every:<days>
+	#[arg(long)]
+	pub repeat: Option<Recurrence>,
+
+	/// Estimated effort, e.g. 2h, 45m or 1h30m
+	#[arg(long, short)]
+	pub estimate: Option<Estimate>,
+
+	/// Tag to attach (repeatable)
+	#[arg(long = "tag", short)]
+	pub tags: Vec<String>,
 }
 
 impl CommandHandler for NewCommand {
@@ -21,9 +38,16 @@ impl CommandHandler for NewCommand {
 		Box::new(CreateTask {
 			clock: context.clock.clone(),
 			task_repository: context.task_repository.clone(),
+			project_repository: context.project_repository.clone(),
 			output_writer: context.output_writer.clone(),
+			prompt: context.prompt.clone(),
 			project_name: self.project,
 			description: self.description,
+			due: self.due,
+			recurrence: self.repeat,
+			estimate: self.estimate,
+			tags: self.tags,
+			project_references: context.config.project_references,
 		})
 	}
 }
@@ -0,0 +1,38 @@
+use clap::Args;
+use ppm_core::context::PPMContext;
+use ppm_core::services::Service;
+use ppm_core::services::task::SearchTasks;
+
+use crate::commands::CommandHandler;
+
+#[derive(Args, Debug)]
+pub struct SearchCommand {
+	/// Query, e.g. project:api status:pending tag:bug due<fri "login"
+	#[arg(required = true, num_args = 1.., allow_hyphen_values = true)]
+	pub query: Vec<String>,
+}
+
+impl CommandHandler for SearchCommand {
+	fn build_service(self, context: PPMContext) -> Box<dyn Service> {
+		// The shell strips quotes, so re-quote arguments that were phrases
+		let query = self
+			.query
+			.iter()
+			.map(|term| {
+				if term.contains(char::is_whitespace) && !term.contains('"') {
+					format!("\"{}\"", term)
+				} else {
+					term.clone()
+				}
+			})
+			.collect::<Vec<_>>()
+			.join(" ");
+
+		Box::new(SearchTasks {
+			clock: context.clock.clone(),
+			task_repository: context.task_repository.clone(),
+			output_writer: context.output_writer.clone(),
+			query,
+		})
+	}
+}
@@ -0,0 +1,23 @@
+use clap::Args;
+use ppm_core::services::task::TaskSelection;
+
+/// Task IDs or a query selecting the tasks a command applies to.
+#[derive(Args, Debug)]
+#[group(required = true, multiple = false)]
+pub struct SelectionArgs {
+	/// Task IDs (full ID, sequence number, or unique prefix/suffix)
+	pub task_ids: Vec<String>,
+
+	/// Select tasks with a query instead, e.g. "project:api status:pending"
+	#[arg(long, short)]
+	pub query: Option<String>,
+}
+
+impl From<SelectionArgs> for TaskSelection {
+	fn from(args: SelectionArgs) -> Self {
+		match args.query {
+			Some(query) => TaskSelection::Query(query),
+			None => TaskSelection::Ids(args.task_ids),
+		}
+	}
+}
@@ -0,0 +1,27 @@
+use clap::Args;
+use ppm_core::context::PPMContext;
+use ppm_core::models::TaskId;
+use ppm_core::services::Service;
+use ppm_core::services::task::ShowTask;
+
+use crate::commands::CommandHandler;
+
+#[derive(Args, Debug)]
+pub struct ShowCommand {
+	/// Task ID to show
+	pub task_id: TaskId,
+}
+
+impl CommandHandler for ShowCommand {
+	fn build_service(self, context: PPMContext) -> Box<dyn Service> {
+		Box::new(ShowTask {
+			clock: context.clock.clone(),
+			task_repository: context.task_repository.clone(),
+			session_repository: context.session_repository.clone(),
+			note_repository: context.note_repository.clone(),
+			output_writer: context.output_writer.clone(),
+			workflow: context.config.workflow.clone(),
+			task_id: self.task_id,
+		})
+	}
+}
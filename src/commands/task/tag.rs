@@ -0,0 +1,35 @@
+use clap::Args;
+use ppm_core::context::PPMContext;
+use ppm_core::services::Service;
+use ppm_core::services::task::TagTask;
+
+use crate::commands::CommandHandler;
+use crate::commands::task::selection::SelectionArgs;
+
+#[derive(Args, Debug)]
+#[command(group = clap::ArgGroup::new("changes").required(true).multiple(true))]
+pub struct TagCommand {
+	#[command(flatten)]
+	pub selection: SelectionArgs,
+
+	/// Tag to add (repeatable)
+	#[arg(long, short, group = "changes")]
+	pub add: Vec<String>,
+
+	/// Tag to remove (repeatable)
+	#[arg(long, short, group = "changes")]
+	pub remove: Vec<String>,
+}
+
+impl CommandHandler for TagCommand {
+	fn build_service(self, context: PPMContext) -> Box<dyn Service> {
+		Box::new(TagTask {
+			clock: context.clock.clone(),
+			task_repository: context.task_repository.clone(),
+			output_writer: context.output_writer.clone(),
+			selection: self.selection.into(),
+			add: self.add,
+			remove: self.remove,
+		})
+	}
+}
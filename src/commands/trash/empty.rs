@@ -0,0 +1,22 @@
+use clap::Args;
+use ppm_core::context::PPMContext;
+use ppm_core::services::Service;
+use ppm_core::services::trash::EmptyTrash;
+
+use crate::commands::CommandHandler;
+
+#[derive(Args, Debug)]
+pub struct EmptyCommand {
+	/// Entry or item IDs (or unique prefixes/suffixes) to delete; everything when omitted
+	pub item_ids: Vec<String>,
+}
+
+impl CommandHandler for EmptyCommand {
+	fn build_service(self, context: PPMContext) -> Box<dyn Service> {
+		Box::new(EmptyTrash {
+			trash_repository: context.trash_repository.clone(),
+			output_writer: context.output_writer.clone(),
+			item_ids: self.item_ids,
+		})
+	}
+}
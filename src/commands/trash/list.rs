@@ -0,0 +1,20 @@
+use clap::Args;
+use ppm_core::context::PPMContext;
+use ppm_core::services::Service;
+use ppm_core::services::trash::ListTrash;
+
+use crate::commands::CommandHandler;
+
+#[derive(Args, Debug)]
+pub struct ListCommand {}
+
+impl CommandHandler for ListCommand {
+	fn build_service(self, context: PPMContext) -> Box<dyn Service> {
+		Box::new(ListTrash {
+			clock: context.clock.clone(),
+			trash_repository: context.trash_repository.clone(),
+			output_writer: context.output_writer.clone(),
+			retention_days: context.config.trash_retention_days,
+		})
+	}
+}
@@ -0,0 +1,30 @@
+use clap::Subcommand;
+
+use crate::commands::CommandHandler;
+
+pub mod empty;
+pub mod list;
+pub mod restore;
+
+#[derive(Debug, Subcommand)]
+pub enum TrashCommand {
+	/// List deleted items
+	List(list::ListCommand),
+	/// Restore deleted items
+	Restore(restore::RestoreCommand),
+	/// Permanently delete items in the trash, or everything
+	Empty(empty::EmptyCommand),
+}
+
+impl CommandHandler for TrashCommand {
+	fn build_service(
+		self,
+		context: ppm_core::context::PPMContext,
+	) -> Box<dyn ppm_core::services::Service> {
+		match self {
+			TrashCommand::List(c) => c.build_service(context),
+			TrashCommand::Restore(c) => c.build_service(context),
+			TrashCommand::Empty(c) => c.build_service(context),
+		}
+	}
+}
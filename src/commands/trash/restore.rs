@@ -0,0 +1,30 @@
+use clap::Args;
+use ppm_core::context::PPMContext;
+use ppm_core::services::Service;
+use ppm_core::services::trash::RestoreTrash;
+
+use crate::commands::CommandHandler;
+
+#[derive(Args, Debug)]
+pub struct RestoreCommand {
+	/// IDs (or unique prefixes/suffixes) of tasks, notes, sessions or trash entries, or project
+	/// names
+	#[arg(required = true)]
+	pub item_ids: Vec<String>,
+}
+
+impl CommandHandler for RestoreCommand {
+	fn build_service(self, context: PPMContext) -> Box<dyn Service> {
+		Box::new(RestoreTrash {
+			clock: context.clock.clone(),
+			trash_repository: context.trash_repository.clone(),
+			task_repository: context.task_repository.clone(),
+			project_repository: context.project_repository.clone(),
+			note_repository: context.note_repository.clone(),
+			session_repository: context.session_repository.clone(),
+			output_writer: context.output_writer.clone(),
+			retention_days: context.config.trash_retention_days,
+			item_ids: self.item_ids,
+		})
+	}
+}